@@ -0,0 +1,1119 @@
+//! Command-line interface definitions
+
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use domain_forge::domain::tld_presets::expand_presets;
+use domain_forge::snipe::{Charset, PatternGenerator, ScanChunk, ScanMode, ScanState, SnipeConfig};
+use domain_forge::types::GenerationStyle;
+use std::path::PathBuf;
+
+const DEFAULT_GENERATE_TLDS: &[&str] = &["com", "org", "io", "ai", "tech", "dev", "app"];
+
+const EXAMPLES: &str = "\
+EXAMPLES:
+    domain-forge \"AI productivity app\"              # AI-generated domains
+    domain-forge snipe -l 3 --tld ai               # 3-letter domains on .ai
+    domain-forge snipe -w --tld com,io             # 5-letter words on .com/.io
+    domain-forge snipe -w -c 30                    # 5-letter words, 30 concurrent
+    domain-forge snipe recheck output/snipe_results_*.json
+    domain-forge snipe merge com.json io.json -o merged.json
+    domain-forge check example.com example.io
+    domain-forge check --json < domains.txt         # exit 0 if any is available
+    domain-forge watchlist add abcd.com && domain-forge watchlist check";
+
+const SNIPE_CONTROL: &str = "\
+PAUSE AND STOP:
+    Checked between batches in the state file's directory (output/ by default):
+    output/snipe.pause    hold the scan while this file exists, resume once it is deleted
+    output/snipe.stop     save state and stop (resume later with -r)
+    domain-forge snipe pause creates the pause file.
+    On Windows, <state file>.stop and <state file>.checkpoint (e.g. output/snipe_4letter.stop)
+    stop or checkpoint the scan saving to that state file.";
+
+/// Domain Forge - AI-powered domain name generation
+#[derive(Debug, Parser)]
+#[command(name = "domain-forge", version, about, after_help = EXAMPLES)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Description to generate domains for (shorthand for `generate`)
+    #[arg(value_name = "DESCRIPTION")]
+    pub description: Vec<String>,
+
+    /// Disable colored output
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// TOML config file (default: ./domain-forge.toml or ~/.config/domain-forge/config.toml)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config_file: Option<PathBuf>,
+
+    /// Suppress progress bars and informational output
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Print results as JSON to stdout (non-interactive)
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Don't verify LLM API keys before starting a generation session
+    #[arg(long, global = true)]
+    pub skip_health_check: bool,
+
+    /// Print a detailed metrics table (to stderr) when the command finishes
+    #[arg(long, global = true)]
+    pub metrics: bool,
+}
+
+/// File format for saved results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Txt,
+    Json,
+    Csv,
+    /// Markdown report
+    Markdown,
+    /// HTML report
+    Html,
+    /// YAML (same fields as JSON)
+    Yaml,
+}
+
+impl OutputFormat {
+    /// File extension for this format
+    pub fn extension(self) -> &'static str {
+        match self {
+            OutputFormat::Txt => "txt",
+            OutputFormat::Json => "json",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Markdown => "md",
+            OutputFormat::Html => "html",
+            OutputFormat::Yaml => "yaml",
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Generate domain names with AI and check their availability
+    Generate(GenerateArgs),
+    /// Scan for available short domains
+    #[command(after_help = SNIPE_CONTROL)]
+    Snipe(Box<SnipeArgs>),
+    /// Check availability of specific domains
+    Check(CheckArgs),
+    /// Generate and check variations of a domain (prefixes, suffixes, TLDs, hyphens)
+    Variations(VariationsArgs),
+    /// Validate domain name format
+    Validate(ValidateArgs),
+    /// Show a domain's raw RDAP record from its registry
+    Rdap(RdapArgs),
+    /// Keep an eye on specific domains between scans
+    Watchlist(WatchlistArgs),
+    /// Show or initialize the config file
+    Config(ConfigArgs),
+    /// List configured LLM providers
+    Providers(ProvidersArgs),
+    /// List the TLDs availability checks support
+    Tlds(TldsArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct GenerateArgs {
+    /// Description of the project or brand
+    #[arg(value_name = "DESCRIPTION")]
+    pub description: Vec<String>,
+
+    /// Number of domains to generate per round
+    #[arg(short = 'n', long, default_value_t = 20)]
+    pub count: usize,
+
+    /// Generation style: creative, professional, brandable, descriptive, short, tech,
+    /// acronym (abbreviations such as aicr.io) or portmanteau (blends such as brunch.com)
+    #[arg(short, long, default_value = "creative", value_parser = parse_style)]
+    pub style: GenerationStyle,
+
+    /// TLDs or presets such as `gaming` to generate for (comma-separated, or `auto` to pick them from the description)
+    #[arg(short, long, value_delimiter = ',', default_values_t = DEFAULT_GENERATE_TLDS.iter().map(|s| s.to_string()))]
+    pub tlds: Vec<String>,
+
+    /// Directory for saved result files
+    #[arg(short, long, default_value = "output")]
+    pub output_dir: PathBuf,
+
+    /// Format for saved result files [default: txt]
+    #[arg(short, long, value_enum)]
+    pub format: Option<OutputFormat>,
+
+    /// Save results as CSV (shorthand for `--format csv`)
+    #[arg(long, conflicts_with = "format")]
+    pub csv: bool,
+
+    /// Continue a saved session (taken names are not suggested or checked again)
+    #[arg(short, long, visible_alias = "resume-session", value_name = "SESSION_FILE")]
+    pub resume: Option<PathBuf>,
+
+    /// Minimum characters before the TLD
+    #[arg(long, value_name = "N", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=63))]
+    pub min_length: Option<usize>,
+
+    /// Maximum characters before the TLD
+    #[arg(long, value_name = "N", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=63))]
+    pub max_length: Option<usize>,
+
+    /// Response token limit for the LLM [default: provider's, scaled for counts over 20]
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_tokens: Option<u32>,
+}
+
+impl GenerateArgs {
+    /// Generate args for a bare `domain-forge [DESCRIPTION]` invocation
+    pub fn from_description(description: Vec<String>) -> Self {
+        Self {
+            description,
+            count: 20,
+            style: GenerationStyle::Creative,
+            tlds: DEFAULT_GENERATE_TLDS.iter().map(|s| s.to_string()).collect(),
+            output_dir: PathBuf::from("output"),
+            format: None,
+            csv: false,
+            resume: None,
+            min_length: None,
+            max_length: None,
+            max_tokens: None,
+        }
+    }
+
+    /// Selected save format
+    pub fn output_format(&self, default: OutputFormat) -> OutputFormat {
+        resolve_format(self.format, self.csv, default)
+    }
+
+    /// TLDs to generate for, with presets expanded
+    pub fn tld_list(&self) -> Vec<String> {
+        expand_presets(&self.tlds)
+    }
+
+    /// Joined description (empty if none was given)
+    pub fn description(&self) -> String {
+        self.description.join(" ")
+    }
+}
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("mode").args(["words", "pronounceable", "six", "readable", "three", "pattern"])))]
+pub struct SnipeArgs {
+    #[command(subcommand)]
+    pub command: Option<SnipeCommand>,
+
+    /// Domain length to scan
+    #[arg(short, long, default_value_t = 4, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(2..=10))]
+    pub length: usize,
+
+    /// Scan 5-letter meaningful words (recommended!)
+    #[arg(short, long)]
+    pub words: bool,
+
+    /// Scan 5-letter words from a file instead of the built-in lists
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["pronounceable", "six", "readable", "three", "pattern"])]
+    pub words_from: Option<PathBuf>,
+
+    /// Scan 4-letter pronounceable patterns
+    #[arg(short, long)]
+    pub pronounceable: bool,
+
+    /// With -p, skip names whose pronounceability score (0.0-1.0) is below SCORE
+    #[arg(long, value_name = "SCORE", requires = "pronounceable", value_parser = parse_unit_score)]
+    pub min_quality: Option<f32>,
+
+    /// Scan 6-letter pronounceable patterns
+    #[arg(short = '6', long)]
+    pub six: bool,
+
+    /// Scan 5-letter readable/brandable names
+    #[arg(short = 'R', long)]
+    pub readable: bool,
+
+    /// Scan all 3-letter names (a-z)
+    #[arg(long, conflicts_with_all = ["length", "alphanumeric", "charset"])]
+    pub three: bool,
+
+    /// Scan names matching a pattern, `?` being any character (e.g. `?oo?`, `ai??`)
+    #[arg(long, value_name = "PATTERN", conflicts_with = "length", value_parser = parse_pattern)]
+    pub pattern: Option<String>,
+
+    /// TLDs or presets such as `fintech` to scan (comma-separated) [default: com]
+    #[arg(short, long = "tld", value_delimiter = ',')]
+    pub tlds: Vec<String>,
+
+    /// Include digits (a-z, 0-9)
+    #[arg(short, long)]
+    pub alphanumeric: bool,
+
+    /// Scan only these characters, e.g. `aeiou` or `0123456789abcdef`
+    #[arg(long, value_name = "CHARS", conflicts_with = "alphanumeric", value_parser = parse_charset)]
+    pub charset: Option<Charset>,
+
+    /// Concurrent checks [default: 20]
+    #[arg(short, long)]
+    pub concurrency: Option<usize>,
+
+    /// Starting delay between batches in ms (backs off per RDAP server on HTTP 429)
+    #[arg(long = "rate", value_name = "MS", default_value_t = 500)]
+    pub rate_limit_ms: u64,
+
+    /// Resume previous scan
+    #[arg(short, long)]
+    pub resume: bool,
+
+    /// Scan only part I of N of the range, e.g. `1/4` on the first of four machines
+    /// (full and 3-letter scans; combine the state files with `snipe merge`)
+    #[arg(long, value_name = "I/N", value_parser = parse_chunk,
+          conflicts_with_all = ["words", "words_from", "pronounceable", "six", "readable", "pattern"])]
+    pub chunk: Option<ScanChunk>,
+
+    /// Leave names matching this regex unchecked, e.g. `^x` or `[qxz]$` (repeatable)
+    #[arg(long = "skip-pattern", value_name = "REGEX", value_parser = parse_skip_pattern)]
+    pub skip_patterns: Vec<String>,
+
+    #[command(flatten)]
+    pub filter: ResultFilterArgs,
+
+    /// Label this scan (repeatable or comma-separated); see `snipe list --tag`
+    #[arg(long = "tag", value_name = "TAG", value_delimiter = ',')]
+    pub tags: Vec<String>,
+
+    /// Print the first 100 domains and the estimated scan size, then exit
+    /// without checking anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Skip domains already found in these result files (repeatable)
+    #[arg(long, value_name = "RESULT_JSON")]
+    pub skip_checked: Vec<PathBuf>,
+
+    /// Stop after finding N available domains (resume with a higher N to find more)
+    #[arg(long, value_name = "N", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub max_found: Option<usize>,
+
+    /// Days threshold for expiring soon [default: 7]
+    #[arg(short, long = "expiring", value_name = "DAYS")]
+    pub expiring_days: Option<u32>,
+
+    /// Directory for saved result files
+    #[arg(short, long, default_value = "output")]
+    pub output_dir: PathBuf,
+
+    /// Format for saved result files [default: json]
+    #[arg(short, long, value_enum)]
+    pub format: Option<OutputFormat>,
+
+    /// Save results as CSV (shorthand for `--format csv`)
+    #[arg(long, conflicts_with = "format")]
+    pub csv: bool,
+}
+
+impl SnipeArgs {
+    /// Selected save format
+    pub fn output_format(&self, default: OutputFormat) -> OutputFormat {
+        resolve_format(self.format, self.csv, default)
+    }
+
+    /// Build the scanner configuration, with flags overriding `base`
+    pub fn to_config(&self, base: SnipeConfig) -> SnipeConfig {
+        let mode = if self.words || self.words_from.is_some() {
+            ScanMode::Words
+        } else if self.pronounceable {
+            ScanMode::Pronounceable
+        } else if self.six {
+            ScanMode::Six
+        } else if self.readable {
+            ScanMode::Readable
+        } else if self.three {
+            ScanMode::ThreeLetter
+        } else if let Some(pattern) = &self.pattern {
+            ScanMode::Pattern(pattern.clone())
+        } else {
+            ScanMode::Full
+        };
+
+        let tlds = if self.tlds.is_empty() {
+            base.tlds.clone()
+        } else {
+            expand_presets(&self.tlds)
+        };
+
+        let length = match &self.pattern {
+            _ if self.three => 3,
+            Some(pattern) => pattern.chars().count(),
+            None => self.length,
+        };
+
+        let state_file = self.resume.then(|| match self.chunk {
+            Some(chunk) => ScanState::chunk_path_for(length, &mode, chunk),
+            None => ScanState::default_path_for(length, &mode),
+        });
+
+        SnipeConfig {
+            mode,
+            length,
+            tlds,
+            charset: match &self.charset {
+                Some(charset) => charset.clone(),
+                None if self.alphanumeric => Charset::Alphanumeric,
+                None => Charset::Letters,
+            },
+            min_pronounceable_score: self.min_quality.or(base.min_pronounceable_score),
+            concurrency: self.concurrency.unwrap_or(base.concurrency),
+            expiring_days: self.expiring_days.unwrap_or(base.expiring_days),
+            state_file,
+            chunk: self.chunk.or(base.chunk),
+            skip_patterns: base.skip_patterns.into_iter().chain(self.skip_patterns.iter().cloned()).collect(),
+            rate_limit_ms: self.rate_limit_ms,
+            dry_run: self.dry_run || base.dry_run,
+            max_found: self.max_found.or(base.max_found),
+            tags: if self.tags.is_empty() { base.tags } else { self.tags.clone() },
+            ..base
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SnipeCommand {
+    /// Recheck & update saved results in-place
+    Recheck(RecheckArgs),
+    /// Show what changed between two result files
+    Diff {
+        /// Older result file
+        #[arg(value_name = "OLD_JSON")]
+        old: PathBuf,
+        /// Newer result file
+        #[arg(value_name = "NEW_JSON")]
+        new: PathBuf,
+    },
+    /// List saved scans (output/snipe_*.json) with their tags and progress
+    List {
+        /// Only show scans with this tag
+        #[arg(long, value_name = "TAG")]
+        tag: Option<String>,
+        /// Directory to look in
+        #[arg(short, long, default_value = "output")]
+        dir: PathBuf,
+    },
+    /// Combine two state files of the same length and mode
+    Merge {
+        /// First state file (its scan id is kept)
+        #[arg(value_name = "FIRST_JSON")]
+        first: PathBuf,
+        /// Second state file
+        #[arg(value_name = "SECOND_JSON")]
+        second: PathBuf,
+        /// Where to write the merged state
+        #[arg(short, long, value_name = "FILE")]
+        output: PathBuf,
+    },
+    /// Pause running scans by creating the pause file (delete it to resume)
+    Pause {
+        /// Directory holding the scans' state files
+        #[arg(short, long, default_value = "output")]
+        dir: PathBuf,
+    },
+}
+
+/// Format of the `snipe recheck` summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RecheckFormat {
+    /// Summary panel per file
+    Txt,
+    /// One JSON object per file (JSON Lines)
+    Json,
+    /// One row per file
+    Csv,
+}
+
+#[derive(Debug, Args)]
+pub struct RecheckArgs {
+    /// Result files to update
+    #[arg(value_name = "RESULT_JSON", required = true)]
+    pub files: Vec<PathBuf>,
+
+    /// Concurrent checks [default: 20]
+    #[arg(short, long)]
+    pub concurrency: Option<usize>,
+
+    /// Days threshold for expiring soon [default: 7]
+    #[arg(short, long = "expiring", value_name = "DAYS")]
+    pub expiring_days: Option<u32>,
+
+    /// Format of the summary [default: txt, json with --json]
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub output_format: Option<RecheckFormat>,
+
+    #[command(flatten)]
+    pub filter: ResultFilterArgs,
+}
+
+impl RecheckArgs {
+    /// Recheck settings: these flags, then the same flags given to `snipe`
+    /// itself (`snipe -c 5 recheck ...`), then `base`
+    pub fn to_config(&self, snipe: &SnipeArgs, base: SnipeConfig) -> SnipeConfig {
+        SnipeConfig {
+            concurrency: self.concurrency.or(snipe.concurrency).unwrap_or(base.concurrency),
+            expiring_days: self.expiring_days.or(snipe.expiring_days).unwrap_or(base.expiring_days),
+            ..base
+        }
+    }
+
+    /// Selected summary format
+    pub fn format(&self, json: bool) -> RecheckFormat {
+        match self.output_format {
+            Some(format) => format,
+            None if json => RecheckFormat::Json,
+            None => RecheckFormat::Txt,
+        }
+    }
+}
+
+/// Filters applied to expiring-soon results before they are shown or saved
+#[derive(Debug, Clone, Default, Args)]
+pub struct ResultFilterArgs {
+    /// Only keep expiring domains whose registrar contains NAME (case-insensitive)
+    #[arg(long, value_name = "NAME")]
+    pub filter_registrar: Option<String>,
+
+    /// Only keep expiring domains at least N days from expiry
+    #[arg(long, value_name = "N")]
+    pub filter_min_days: Option<i64>,
+}
+
+#[derive(Debug, Args)]
+pub struct CheckArgs {
+    /// Fully-qualified domains to check (read from stdin when none are given)
+    #[arg(value_name = "DOMAIN")]
+    pub domains: Vec<String>,
+
+    /// Read domains from a file, one per line (`#` starts a comment)
+    #[arg(short, long, value_name = "PATH")]
+    pub file: Option<PathBuf>,
+
+    /// Print each domain's raw RDAP record instead of the results table
+    #[arg(long)]
+    pub rdap_raw: bool,
+
+    /// Add registrar, expiry and registration age columns to the results table
+    #[arg(long, conflicts_with = "rdap_raw")]
+    pub detailed: bool,
+}
+
+/// Domains listed in a file or stdin: one per line, ignoring blank lines and
+/// `#` comments
+pub fn domain_lines(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+#[derive(Debug, Args)]
+pub struct VariationsArgs {
+    /// Domain to vary, e.g. sparkfire.io
+    #[arg(value_name = "DOMAIN")]
+    pub domain: String,
+
+    /// Number of variations to request
+    #[arg(short = 'n', long, default_value_t = 12)]
+    pub count: usize,
+
+    /// Extra TLDs or presets to try besides the domain's own (comma-separated)
+    #[arg(short, long, value_delimiter = ',', default_values_t = ["com".to_string(), "io".to_string(), "app".to_string()])]
+    pub tlds: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct RdapArgs {
+    /// Fully-qualified domain to look up
+    #[arg(value_name = "DOMAIN")]
+    pub domain: String,
+
+    /// Print the response body verbatim instead of pretty-printed JSON
+    #[arg(long)]
+    pub raw: bool,
+}
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("input").required(true).multiple(true).args(["domains", "batch_file"])))]
+pub struct ValidateArgs {
+    /// Domains to validate
+    #[arg(value_name = "DOMAIN")]
+    pub domains: Vec<String>,
+
+    /// Suggest corrections for invalid domains
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Validate domains from a file, one per line, and print a summary
+    #[arg(long, value_name = "PATH")]
+    pub batch_file: Option<PathBuf>,
+
+    /// Only accept these TLDs (comma-separated)
+    #[arg(long, value_name = "TLDS", value_delimiter = ',')]
+    pub allowed_tlds: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct WatchlistArgs {
+    #[command(subcommand)]
+    pub command: WatchlistCommand,
+
+    /// Watchlist file
+    #[arg(long, value_name = "PATH", default_value = "output/watchlist.json")]
+    pub file: PathBuf,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum WatchlistCommand {
+    /// Start watching domains
+    Add {
+        #[arg(value_name = "DOMAIN", required = true)]
+        domains: Vec<String>,
+    },
+    /// Stop watching domains
+    Remove {
+        #[arg(value_name = "DOMAIN", required = true)]
+        domains: Vec<String>,
+    },
+    /// Check all watched domains now and report status changes
+    Check,
+    /// List watched domains with their last known status
+    Show,
+}
+
+#[derive(Debug, Args)]
+pub struct ProvidersArgs {
+    #[command(subcommand)]
+    pub command: Option<ProvidersCommand>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ProvidersCommand {
+    /// List configured providers with their models (the default)
+    List,
+    /// Verify each provider's API key and model (exits 1 if any fails)
+    Test,
+}
+
+#[derive(Debug, Args)]
+pub struct TldsArgs {
+    #[command(subcommand)]
+    pub command: Option<TldsCommand>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TldsCommand {
+    /// List TLDs with a known RDAP (or, with the whois feature, WHOIS) server (the default)
+    List,
+}
+
+#[derive(Debug, Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Print the effective configuration
+    Show,
+    /// Interactively write an initial config file
+    Init {
+        /// Write ./domain-forge.toml instead of the per-user config
+        #[arg(long)]
+        local: bool,
+        /// Overwrite an existing file
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+fn resolve_format(format: Option<OutputFormat>, csv: bool, default: OutputFormat) -> OutputFormat {
+    if csv {
+        OutputFormat::Csv
+    } else {
+        format.unwrap_or(default)
+    }
+}
+
+fn parse_pattern(value: &str) -> Result<String, String> {
+    PatternGenerator::new(value, Charset::Letters).map_err(|e| e.to_string())?;
+    Ok(value.trim().to_lowercase())
+}
+
+fn parse_unit_score(value: &str) -> Result<f32, String> {
+    match value.parse::<f32>() {
+        Ok(score) if (0.0..=1.0).contains(&score) => Ok(score),
+        _ => Err(format!("'{}' is not a score between 0.0 and 1.0", value)),
+    }
+}
+
+fn parse_chunk(value: &str) -> Result<ScanChunk, String> {
+    value.parse().map_err(|e: domain_forge::DomainForgeError| e.to_string())
+}
+
+fn parse_skip_pattern(value: &str) -> Result<String, String> {
+    regex::Regex::new(value).map_err(|e| e.to_string())?;
+    Ok(value.to_string())
+}
+
+fn parse_charset(value: &str) -> Result<Charset, String> {
+    Charset::custom(value).map_err(|e| e.to_string())
+}
+
+fn parse_style(value: &str) -> Result<GenerationStyle, String> {
+    match value.to_lowercase().as_str() {
+        "creative" => Ok(GenerationStyle::Creative),
+        "professional" => Ok(GenerationStyle::Professional),
+        "brandable" => Ok(GenerationStyle::Brandable),
+        "descriptive" => Ok(GenerationStyle::Descriptive),
+        "short" => Ok(GenerationStyle::Short),
+        "tech" => Ok(GenerationStyle::Tech),
+        "acronym" => Ok(GenerationStyle::Acronym),
+        "portmanteau" => Ok(GenerationStyle::Portmanteau),
+        other => Err(format!(
+            "unknown style '{}' (expected creative, professional, brandable, descriptive, short, tech, acronym or portmanteau)",
+            other
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_bare_description() {
+        let cli = Cli::parse_from(["domain-forge", "AI", "productivity", "app"]);
+        assert!(cli.command.is_none());
+        assert_eq!(cli.description.join(" "), "AI productivity app");
+    }
+
+    #[test]
+    fn test_snipe_flags() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "-w", "--tld", "com,IO", "-c", "30", "-r"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let config = args.to_config(SnipeConfig::default());
+        assert_eq!(config.mode, ScanMode::Words);
+        assert_eq!(config.tlds, vec!["com", "io"]);
+        assert_eq!(config.concurrency, 30);
+        assert!(config.state_file.is_some());
+    }
+
+    #[test]
+    fn test_snipe_flags_override_config() {
+        let base = SnipeConfig {
+            tlds: vec!["ai".to_string()],
+            concurrency: 5,
+            expiring_days: 30,
+            ..Default::default()
+        };
+
+        let cli = Cli::parse_from(["domain-forge", "snipe", "-e", "3"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let config = args.to_config(base);
+        assert_eq!(config.tlds, vec!["ai"]);
+        assert_eq!(config.concurrency, 5);
+        assert_eq!(config.expiring_days, 3);
+    }
+
+    #[test]
+    fn test_tld_presets() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "--tld", "ai,legal"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        assert_eq!(args.to_config(SnipeConfig::default()).tlds, vec!["ai", "com", "legal", "law", "attorney"]);
+
+        let cli = Cli::parse_from(["domain-forge", "generate", "-t", "gaming", "esports"]);
+        let Some(Command::Generate(args)) = cli.command else {
+            panic!("expected generate command");
+        };
+        assert_eq!(args.tld_list(), vec!["com", "gg", "game", "play", "xyz"]);
+    }
+
+    #[test]
+    fn test_providers_test() {
+        let cli = Cli::parse_from(["domain-forge", "providers"]);
+        assert!(matches!(cli.command, Some(Command::Providers(ProvidersArgs { command: None }))));
+
+        let cli = Cli::parse_from(["domain-forge", "providers", "test"]);
+        assert!(matches!(cli.command, Some(Command::Providers(ProvidersArgs { command: Some(ProvidersCommand::Test) }))));
+
+        let cli = Cli::parse_from(["domain-forge", "providers", "list", "--json"]);
+        assert!(matches!(cli.command, Some(Command::Providers(ProvidersArgs { command: Some(ProvidersCommand::List) }))));
+        assert!(cli.json);
+
+        let cli = Cli::parse_from(["domain-forge", "tlds", "list"]);
+        assert!(matches!(cli.command, Some(Command::Tlds(TldsArgs { command: Some(TldsCommand::List) }))));
+        let cli = Cli::parse_from(["domain-forge", "tlds"]);
+        assert!(matches!(cli.command, Some(Command::Tlds(TldsArgs { command: None }))));
+
+        let cli = Cli::parse_from(["domain-forge", "--skip-health-check", "coffee", "shop"]);
+        assert!(cli.skip_health_check);
+        assert!(!cli.metrics);
+
+        let cli = Cli::parse_from(["domain-forge", "check", "forge.com", "--metrics"]);
+        assert!(cli.metrics);
+    }
+
+    #[test]
+    fn test_snipe_tags() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "-w", "--tag", "q3,brand", "--tag", "client-x"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        assert_eq!(args.to_config(SnipeConfig::default()).tags, vec!["q3", "brand", "client-x"]);
+
+        let cli = Cli::parse_from(["domain-forge", "snipe", "list", "--tag", "q3"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        assert!(matches!(args.command, Some(SnipeCommand::List { tag: Some(ref tag), .. }) if tag == "q3"));
+    }
+
+    #[test]
+    fn test_variations_args() {
+        let cli = Cli::parse_from(["domain-forge", "variations", "sparkfire.io", "-n", "5", "-t", "com,ai"]);
+        let Some(Command::Variations(args)) = cli.command else {
+            panic!("expected variations command");
+        };
+        assert_eq!(args.domain, "sparkfire.io");
+        assert_eq!(args.count, 5);
+        assert_eq!(args.tlds, vec!["com", "ai"]);
+    }
+
+    #[test]
+    fn test_snipe_three_letter() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "--three", "-t", "io", "-r"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let config = args.to_config(SnipeConfig::default());
+        assert_eq!(config.mode, ScanMode::ThreeLetter);
+        assert_eq!(config.length, 3);
+        assert_eq!(config.state_file, Some(ScanState::default_path(3)));
+
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "--three", "-l", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_snipe_words_from() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "--words-from", "mine.txt"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        assert_eq!(args.words_from.as_deref(), Some(std::path::Path::new("mine.txt")));
+        assert_eq!(args.to_config(SnipeConfig::default()).mode, ScanMode::Words);
+
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "--words-from", "mine.txt", "-6"]).is_err());
+    }
+
+    #[test]
+    fn test_snipe_skip_checked() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "--skip-checked", "a.json", "--skip-checked", "b.json"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        assert_eq!(args.skip_checked, vec![PathBuf::from("a.json"), PathBuf::from("b.json")]);
+    }
+
+    #[test]
+    fn test_snipe_chunk() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "--three", "--chunk", "2/4", "-r"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let config = args.to_config(SnipeConfig::default());
+        let chunk = ScanChunk::new(2, 4).unwrap();
+        assert_eq!(config.chunk, Some(chunk));
+        assert_eq!(config.state_file, Some(ScanState::chunk_path(3, chunk)));
+
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "--chunk", "5/4"]).is_err());
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "-w", "--chunk", "1/2"]).is_err());
+    }
+
+    #[test]
+    fn test_snipe_skip_patterns() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "--skip-pattern", "^x", "--skip-pattern", "q$"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let base = SnipeConfig { skip_patterns: vec!["zz".to_string()], ..SnipeConfig::default() };
+        assert_eq!(args.to_config(base).skip_patterns, vec!["zz", "^x", "q$"]);
+
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "--skip-pattern", "[a-"]).is_err());
+    }
+
+    #[test]
+    fn test_snipe_dry_run() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "--pattern", "?oo?", "--dry-run"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        assert!(args.to_config(SnipeConfig::default()).dry_run);
+    }
+
+    #[test]
+    fn test_snipe_custom_charset() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "-l", "3", "--charset", "AEIOU"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let config = args.to_config(SnipeConfig::default());
+        assert_eq!(config.charset, Charset::Custom(vec!['a', 'e', 'i', 'o', 'u']));
+        assert_eq!(config.charset.total_combinations(config.length), 125);
+
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "--charset", "ab_"]).is_err());
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "--charset", "abc", "-a"]).is_err());
+    }
+
+    #[test]
+    fn test_snipe_pattern() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "--pattern", "AI??", "-t", "io", "-r"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let config = args.to_config(SnipeConfig::default());
+        assert_eq!(config.mode, ScanMode::Pattern("ai??".to_string()));
+        assert_eq!(config.length, 4);
+        assert_eq!(config.state_file, Some(ScanState::default_path_for(4, &ScanMode::Pattern("ai??".to_string()))));
+
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "--pattern", "a_b?"]).is_err());
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "--pattern", "?oo?", "-w"]).is_err());
+    }
+
+    #[test]
+    fn test_snipe_min_quality() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "-p", "--min-quality", "0.7"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let config = args.to_config(SnipeConfig::default());
+        assert_eq!(config.mode, ScanMode::Pronounceable);
+        assert_eq!(config.min_pronounceable_score, Some(0.7));
+
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "--min-quality", "0.5"]).is_err());
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "-p", "--min-quality", "1.5"]).is_err());
+    }
+
+    #[test]
+    fn test_snipe_rejects_conflicting_modes_and_bad_length() {
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "-w", "-p"]).is_err());
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "-l", "11"]).is_err());
+    }
+
+    #[test]
+    fn test_output_format_flags() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "--csv"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        assert_eq!(args.output_format(OutputFormat::Json), OutputFormat::Csv);
+
+        let cli = Cli::parse_from(["domain-forge", "generate", "--format", "json", "idea"]);
+        let Some(Command::Generate(args)) = cli.command else {
+            panic!("expected generate command");
+        };
+        assert_eq!(args.output_format(OutputFormat::Txt), OutputFormat::Json);
+
+        let cli = Cli::parse_from(["domain-forge", "snipe", "--format", "yaml"]);
+        let Some(Command::Snipe(args)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        assert_eq!(args.output_format(OutputFormat::Json).extension(), "yaml");
+
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "--csv", "--format", "txt"]).is_err());
+    }
+
+    #[test]
+    fn test_generate_style_flag() {
+        let cli = Cli::parse_from(["domain-forge", "generate", "--style", "Portmanteau", "idea"]);
+        let Some(Command::Generate(args)) = cli.command else {
+            panic!("expected generate command");
+        };
+        assert_eq!(args.style, GenerationStyle::Portmanteau);
+        assert!(Cli::try_parse_from(["domain-forge", "generate", "--style", "fancy", "idea"]).is_err());
+    }
+
+    #[test]
+    fn test_generate_resume_session() {
+        for flag in ["--resume", "--resume-session"] {
+            let cli = Cli::parse_from(["domain-forge", "generate", flag, "output/session_1.json", "idea"]);
+            let Some(Command::Generate(args)) = cli.command else {
+                panic!("expected generate command");
+            };
+            assert_eq!(args.resume, Some(PathBuf::from("output/session_1.json")));
+        }
+    }
+
+    #[test]
+    fn test_generate_length_flags() {
+        let cli = Cli::parse_from(["domain-forge", "generate", "--min-length", "4", "--max-length", "8", "idea"]);
+        let Some(Command::Generate(args)) = cli.command else {
+            panic!("expected generate command");
+        };
+        assert_eq!((args.min_length, args.max_length), (Some(4), Some(8)));
+        assert!(Cli::try_parse_from(["domain-forge", "generate", "--max-length", "0", "idea"]).is_err());
+    }
+
+    #[test]
+    fn test_snipe_recheck() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "recheck", "a.json", "b.json"]);
+        let Some(Command::Snipe(snipe)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let Some(SnipeCommand::Recheck(args)) = snipe.command else {
+            panic!("expected snipe recheck");
+        };
+        assert_eq!(args.files.len(), 2);
+        assert!(args.filter.filter_registrar.is_none());
+        assert_eq!(args.format(false), RecheckFormat::Txt);
+        assert_eq!(args.format(true), RecheckFormat::Json);
+
+        let cli = Cli::parse_from([
+            "domain-forge", "snipe", "recheck", "a.json", "--filter-registrar", "godaddy", "--filter-min-days", "3",
+        ]);
+        let Some(Command::Snipe(snipe)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let Some(SnipeCommand::Recheck(args)) = snipe.command else {
+            panic!("expected snipe recheck");
+        };
+        assert_eq!(args.filter.filter_registrar.as_deref(), Some("godaddy"));
+        assert_eq!(args.filter.filter_min_days, Some(3));
+    }
+
+    #[test]
+    fn test_snipe_recheck_overrides() {
+        let cli = Cli::parse_from([
+            "domain-forge", "snipe", "-e", "30", "recheck", "a.json", "-c", "4", "--output-format", "csv",
+        ]);
+        let Some(Command::Snipe(snipe)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let Some(SnipeCommand::Recheck(args)) = &snipe.command else {
+            panic!("expected snipe recheck");
+        };
+        let config = args.to_config(&snipe, SnipeConfig::default());
+        assert_eq!(config.concurrency, 4);
+        assert_eq!(config.expiring_days, 30);
+        assert_eq!(args.format(true), RecheckFormat::Csv);
+
+        let cli = Cli::parse_from(["domain-forge", "snipe", "recheck", "a.json"]);
+        let Some(Command::Snipe(snipe)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let Some(SnipeCommand::Recheck(args)) = &snipe.command else {
+            panic!("expected snipe recheck");
+        };
+        let config = args.to_config(&snipe, SnipeConfig::default());
+        assert_eq!((config.concurrency, config.expiring_days), (20, 7));
+    }
+
+    #[test]
+    fn test_watchlist_commands() {
+        let cli = Cli::parse_from(["domain-forge", "watchlist", "add", "abcd.com", "efgh.io"]);
+        let Some(Command::Watchlist(WatchlistArgs { command: WatchlistCommand::Add { domains }, file })) = cli.command else {
+            panic!("expected watchlist add");
+        };
+        assert_eq!(domains, vec!["abcd.com", "efgh.io"]);
+        assert_eq!(file, PathBuf::from("output/watchlist.json"));
+
+        let cli = Cli::parse_from(["domain-forge", "watchlist", "--file", "w.json", "check"]);
+        assert!(matches!(cli.command, Some(Command::Watchlist(WatchlistArgs { command: WatchlistCommand::Check, .. }))));
+        assert!(Cli::try_parse_from(["domain-forge", "watchlist", "remove"]).is_err());
+    }
+
+    #[test]
+    fn test_check_args() {
+        let cli = Cli::parse_from(["domain-forge", "check", "--file", "list.txt", "--rdap-raw", "forge.io"]);
+        let Some(Command::Check(args)) = cli.command else {
+            panic!("expected check command");
+        };
+        assert_eq!(args.domains, vec!["forge.io"]);
+        assert_eq!(args.file, Some(PathBuf::from("list.txt")));
+        assert!(args.rdap_raw);
+        assert!(!args.detailed);
+
+        let cli = Cli::parse_from(["domain-forge", "check", "--detailed", "forge.io"]);
+        assert!(matches!(cli.command, Some(Command::Check(CheckArgs { detailed: true, .. }))));
+        assert!(Cli::try_parse_from(["domain-forge", "check", "--detailed", "--rdap-raw", "forge.io"]).is_err());
+
+        // Domains may come from stdin instead
+        assert!(Cli::try_parse_from(["domain-forge", "check"]).is_ok());
+
+        let listed: Vec<String> = domain_lines("forge.com\n\n# ideas\n  anvil.io  # short\n").collect();
+        assert_eq!(listed, vec!["forge.com", "anvil.io"]);
+    }
+
+    #[test]
+    fn test_validate_args() {
+        let cli = Cli::parse_from(["domain-forge", "validate", "--fix", "--allowed-tlds", "com,io", "forge.com"]);
+        let Some(Command::Validate(args)) = cli.command else {
+            panic!("expected validate command");
+        };
+        assert!(args.fix);
+        assert_eq!(args.allowed_tlds, vec!["com", "io"]);
+        assert_eq!(args.domains, vec!["forge.com"]);
+
+        let cli = Cli::parse_from(["domain-forge", "validate", "--batch-file", "list.txt"]);
+        assert!(matches!(cli.command, Some(Command::Validate(ValidateArgs { batch_file: Some(_), .. }))));
+        assert!(Cli::try_parse_from(["domain-forge", "validate"]).is_err());
+    }
+
+    #[test]
+    fn test_rdap_args() {
+        let cli = Cli::parse_from(["domain-forge", "rdap", "forge.io", "--raw"]);
+        let Some(Command::Rdap(RdapArgs { domain, raw })) = cli.command else {
+            panic!("expected rdap command");
+        };
+        assert_eq!(domain, "forge.io");
+        assert!(raw);
+        assert!(Cli::try_parse_from(["domain-forge", "rdap"]).is_err());
+    }
+
+    #[test]
+    fn test_snipe_merge() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "merge", "com.json", "io.json", "--output", "merged.json"]);
+        let Some(Command::Snipe(snipe)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let Some(SnipeCommand::Merge { first, second, output }) = snipe.command else {
+            panic!("expected snipe merge");
+        };
+        assert_eq!((first, second), (PathBuf::from("com.json"), PathBuf::from("io.json")));
+        assert_eq!(output, PathBuf::from("merged.json"));
+        assert!(Cli::try_parse_from(["domain-forge", "snipe", "merge", "com.json", "io.json"]).is_err());
+    }
+
+    #[test]
+    fn test_snipe_pause() {
+        let cli = Cli::parse_from(["domain-forge", "snipe", "pause"]);
+        let Some(Command::Snipe(snipe)) = cli.command else {
+            panic!("expected snipe command");
+        };
+        let Some(SnipeCommand::Pause { dir }) = snipe.command else {
+            panic!("expected snipe pause");
+        };
+        assert_eq!(dir, PathBuf::from("output"));
+    }
+}
@@ -0,0 +1,317 @@
+//! Persistent configuration file support
+//!
+//! Settings are layered: CLI flags override environment variables, which
+//! override the config file, which overrides values from `.env`.
+
+use crate::error::{DomainForgeError, Result};
+use crate::snipe::{SmtpConfig, SnipeConfig, WebhookConfig};
+use crate::types::{CheckConfig, LlmConfig};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// File name used for project-local configuration
+pub const LOCAL_CONFIG_FILE: &str = "domain-forge.toml";
+
+static CURRENT: OnceLock<Config> = OnceLock::new();
+
+/// Top-level configuration file layout
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub llm: LlmSettings,
+    pub domain_check: CheckSettings,
+    pub snipe: SnipeSettings,
+}
+
+/// `[llm]` section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LlmSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    /// Set to false to parse plain-text JSON even on models with structured output
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub structured_output: Option<bool>,
+    /// Response token limit for generation requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+}
+
+/// `[domain_check]` section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CheckSettings {
+    #[serde(alias = "concurrency", skip_serializing_if = "Option::is_none")]
+    pub concurrent_checks: Option<usize>,
+    #[serde(alias = "timeout", skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_rdap: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_whois: Option<bool>,
+    /// HTTP(S) proxy for RDAP lookups, also used by `snipe`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    /// User agent for RDAP lookups, also used by `snipe`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    /// Headers added to every RDAP lookup, e.g. for a private RDAP server
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub extra_headers: HashMap<String, String>,
+}
+
+/// `[snipe]` section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SnipeSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tlds: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiring_days: Option<u32>,
+    /// `[snipe.webhook]` table
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<WebhookConfig>,
+}
+
+impl Config {
+    /// Parse configuration from TOML text
+    pub fn from_toml(content: &str) -> Result<Self> {
+        toml::from_str(content)
+            .map_err(|e| DomainForgeError::config(format!("Invalid config file: {}", e)))
+    }
+
+    /// Load configuration from a file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            DomainForgeError::io(e.to_string(), Some(path.display().to_string()))
+        })?;
+        Self::from_toml(&content).map_err(|e| {
+            DomainForgeError::config(format!("{} ({})", e, path.display()))
+        })
+    }
+
+    /// Save configuration to a file, creating parent directories
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                DomainForgeError::io(e.to_string(), Some(parent.display().to_string()))
+            })?;
+        }
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| DomainForgeError::internal(format!("Failed to serialize config: {}", e)))?;
+        std::fs::write(path, content)
+            .map_err(|e| DomainForgeError::io(e.to_string(), Some(path.display().to_string())))
+    }
+
+    /// Per-user config path (`$XDG_CONFIG_HOME/domain-forge/config.toml` or `~/.config/...`)
+    pub fn user_path() -> Option<PathBuf> {
+        let base = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .or_else(|| std::env::var_os("USERPROFILE"))
+                    .map(|home| PathBuf::from(home).join(".config"))
+            })?;
+        Some(base.join("domain-forge").join("config.toml"))
+    }
+
+    /// Find the config file to use: project-local first, then per-user
+    pub fn discover() -> Option<PathBuf> {
+        let local = PathBuf::from(LOCAL_CONFIG_FILE);
+        if local.is_file() {
+            return Some(local);
+        }
+        Self::user_path().filter(|p| p.is_file())
+    }
+
+    /// Override file values with environment variables
+    pub fn apply_env(&mut self) {
+        self.merge_env(true);
+    }
+
+    /// Fill only unset values from environment variables (used after loading `.env`)
+    pub fn fill_from_env(&mut self) {
+        self.merge_env(false);
+    }
+
+    fn merge_env(&mut self, overwrite: bool) {
+        fn merge(slot: &mut Option<String>, key: &str, overwrite: bool) {
+            if slot.is_some() && !overwrite {
+                return;
+            }
+            if let Some(value) = std::env::var(key).ok().filter(|v| !v.is_empty()) {
+                *slot = Some(value);
+            }
+        }
+
+        merge(&mut self.llm.provider, "DOMAIN_FORGE_PROVIDER", overwrite);
+        if let Some(prefix) = self.llm.provider.as_ref().map(|p| p.to_uppercase()) {
+            merge(&mut self.llm.api_key, &format!("{}_API_KEY", prefix), overwrite);
+            merge(&mut self.llm.model, &format!("{}_MODEL", prefix), overwrite);
+            merge(&mut self.llm.base_url, &format!("{}_BASE_URL", prefix), overwrite);
+        }
+    }
+
+    /// LLM configuration from the `[llm]` section, if a provider is set
+    pub fn llm_config(&self) -> Option<LlmConfig> {
+        let provider = self.llm.provider.clone()?;
+        let defaults = LlmConfig::default();
+        Some(LlmConfig {
+            model: self.llm.model.clone().unwrap_or(defaults.model),
+            api_key: self.llm.api_key.clone().unwrap_or_default(),
+            base_url: self.llm.base_url.clone(),
+            use_structured_output: self.llm.structured_output.unwrap_or(defaults.use_structured_output),
+            max_tokens: self.llm.max_tokens,
+            provider,
+            ..defaults
+        })
+    }
+
+    /// Domain check configuration with file values applied over defaults
+    pub fn check_config(&self) -> CheckConfig {
+        let defaults = CheckConfig::default();
+        let settings = &self.domain_check;
+        CheckConfig {
+            concurrent_checks: settings.concurrent_checks.unwrap_or(defaults.concurrent_checks),
+            timeout: settings.timeout_secs.map(Duration::from_secs).unwrap_or(defaults.timeout),
+            enable_rdap: settings.enable_rdap.unwrap_or(defaults.enable_rdap),
+            enable_whois: settings.enable_whois.unwrap_or(defaults.enable_whois),
+            proxy: settings.proxy.clone(),
+            user_agent: settings.user_agent.clone(),
+            extra_headers: settings.extra_headers.clone(),
+            ..defaults
+        }
+    }
+
+    /// Snipe configuration with file values applied over defaults
+    pub fn snipe_config(&self) -> SnipeConfig {
+        let defaults = SnipeConfig::default();
+        let settings = &self.snipe;
+        SnipeConfig {
+            tlds: settings.tlds.clone().unwrap_or(defaults.tlds.clone()),
+            concurrency: settings.concurrency.unwrap_or(defaults.concurrency),
+            expiring_days: settings.expiring_days.unwrap_or(defaults.expiring_days),
+            proxy: self.domain_check.proxy.clone(),
+            user_agent: self.domain_check.user_agent.clone(),
+            smtp: SmtpConfig::from_env(),
+            webhook: settings.webhook.clone(),
+            ..defaults
+        }
+    }
+}
+
+/// Load the config file at `explicit`, or the discovered one, or defaults
+pub(crate) fn load_file(explicit: Option<&Path>) -> Result<Config> {
+    match explicit.map(Path::to_path_buf).or_else(Config::discover) {
+        Some(path) => Config::load(&path),
+        None => Ok(Config::default()),
+    }
+}
+
+/// Install the process-wide configuration (the first call wins)
+pub(crate) fn set_current(config: Config) {
+    let _ = CURRENT.set(config);
+}
+
+/// Configuration loaded by [`crate::init`] (defaults if not initialized)
+pub fn current() -> &'static Config {
+    CURRENT.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snipe::WebhookMethod;
+
+    #[test]
+    fn test_parse_sections() {
+        let config = Config::from_toml(
+            r#"
+            [llm]
+            provider = "anthropic"
+            model = "claude-4-sonnet"
+
+            [domain_check]
+            concurrency = 15
+            timeout = 20
+            enable_whois = false
+            proxy = "http://proxy.corp.example.com:8080"
+            user_agent = "acme-monitor/2.0"
+            extra_headers = { Authorization = "Bearer token" }
+
+            [snipe]
+            tlds = ["com", "io"]
+            expiring_days = 14
+
+            [snipe.webhook]
+            url = "https://ntfy.sh/my-domains"
+            method = "PUT"
+            headers = { Priority = "high" }
+            "#,
+        )
+        .unwrap();
+
+        let llm = config.llm_config().unwrap();
+        assert_eq!(llm.provider, "anthropic");
+        assert_eq!(llm.model, "claude-4-sonnet");
+
+        let check = config.check_config();
+        assert_eq!(check.concurrent_checks, 15);
+        assert_eq!(check.timeout, Duration::from_secs(20));
+        assert!(!check.enable_whois);
+        assert!(check.enable_rdap);
+        assert_eq!(check.proxy.as_deref(), Some("http://proxy.corp.example.com:8080"));
+
+        let snipe = config.snipe_config();
+        assert_eq!(snipe.tlds, vec!["com", "io"]);
+        assert_eq!(snipe.expiring_days, 14);
+        assert_eq!(snipe.concurrency, SnipeConfig::default().concurrency);
+        assert_eq!(snipe.proxy, check.proxy);
+        assert_eq!(check.rdap_user_agent(), "acme-monitor/2.0");
+        assert_eq!(check.extra_headers.get("Authorization").map(String::as_str), Some("Bearer token"));
+        assert_eq!(snipe.user_agent, check.user_agent);
+        let webhook = snipe.webhook.unwrap();
+        assert_eq!(webhook.method, WebhookMethod::Put);
+        assert_eq!(webhook.headers.get("Priority").map(String::as_str), Some("high"));
+        assert!(webhook.payload_template.is_none());
+    }
+
+    #[test]
+    fn test_empty_config_uses_defaults() {
+        let config = Config::from_toml("").unwrap();
+        assert!(config.llm_config().is_none());
+        assert_eq!(config.check_config().concurrent_checks, CheckConfig::default().concurrent_checks);
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(Config::from_toml("[snipe]\nconcurrency = \"many\"").is_err());
+    }
+
+    #[test]
+    fn test_save_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("config.toml");
+        let config = Config {
+            llm: LlmSettings {
+                provider: Some("openai".to_string()),
+                model: Some("gpt-4.1-mini".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        config.save(&path).unwrap();
+        assert_eq!(Config::load(&path).unwrap(), config);
+    }
+}
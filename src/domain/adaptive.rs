@@ -0,0 +1,158 @@
+//! Adaptive concurrency control for domain checks
+//!
+//! Tracks the outcome of the most recent checks in a lock-free ring buffer.
+//! When registries start failing or rate-limiting, the effective number of
+//! in-flight checks is halved; once the error rate settles it creeps back up
+//! towards the configured maximum.
+
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Number of recent checks used to compute the error rate
+pub const ERROR_WINDOW: usize = 100;
+
+/// Re-evaluate the limit after this many recorded checks
+const ADJUST_INTERVAL: usize = 20;
+
+/// Poll interval while waiting for an in-flight slot
+const ACQUIRE_POLL: Duration = Duration::from_millis(10);
+
+/// Adaptive limit on concurrent domain checks
+#[derive(Debug)]
+pub(crate) struct AdaptiveConcurrency {
+    outcomes: [AtomicU8; ERROR_WINDOW],
+    recorded: AtomicUsize,
+    in_flight: AtomicUsize,
+    effective_limit: AtomicUsize,
+    max_limit: usize,
+    error_threshold: f64,
+    recovery_threshold: f64,
+}
+
+/// In-flight slot released on drop
+pub(crate) struct AdaptivePermit<'a> {
+    controller: &'a AdaptiveConcurrency,
+}
+
+impl Drop for AdaptivePermit<'_> {
+    fn drop(&mut self) {
+        self.controller.in_flight.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl AdaptiveConcurrency {
+    pub(crate) fn new(max_limit: usize, error_threshold: f64, recovery_threshold: f64) -> Self {
+        let max_limit = max_limit.max(1);
+        Self {
+            outcomes: std::array::from_fn(|_| AtomicU8::new(0)),
+            recorded: AtomicUsize::new(0),
+            in_flight: AtomicUsize::new(0),
+            effective_limit: AtomicUsize::new(max_limit),
+            max_limit,
+            error_threshold,
+            recovery_threshold,
+        }
+    }
+
+    /// Current effective concurrency limit
+    pub(crate) fn current_limit(&self) -> usize {
+        self.effective_limit.load(Ordering::Relaxed)
+    }
+
+    /// Wait until an in-flight slot is available under the effective limit
+    pub(crate) async fn acquire(&self) -> AdaptivePermit<'_> {
+        loop {
+            let in_flight = self.in_flight.load(Ordering::Acquire);
+            if in_flight < self.current_limit()
+                && self
+                    .in_flight
+                    .compare_exchange(in_flight, in_flight + 1, Ordering::AcqRel, Ordering::Acquire)
+                    .is_ok()
+            {
+                return AdaptivePermit { controller: self };
+            }
+            tokio::time::sleep(ACQUIRE_POLL).await;
+        }
+    }
+
+    /// Record the outcome of a check, returning the new limit if it changed
+    pub(crate) fn record(&self, is_error: bool) -> Option<usize> {
+        let n = self.recorded.fetch_add(1, Ordering::AcqRel) + 1;
+        self.outcomes[(n - 1) % ERROR_WINDOW].store(is_error as u8, Ordering::Relaxed);
+
+        if !n.is_multiple_of(ADJUST_INTERVAL) {
+            return None;
+        }
+
+        let rate = self.error_rate();
+        let current = self.current_limit();
+        let next = if rate > self.error_threshold {
+            (current / 2).max(1)
+        } else if rate < self.recovery_threshold {
+            (current + 1).min(self.max_limit)
+        } else {
+            current
+        };
+
+        if next != current {
+            self.effective_limit.store(next, Ordering::Relaxed);
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    /// Error rate over the recorded window
+    pub(crate) fn error_rate(&self) -> f64 {
+        let samples = self.recorded.load(Ordering::Acquire).min(ERROR_WINDOW);
+        if samples == 0 {
+            return 0.0;
+        }
+        let errors: usize = self.outcomes[..samples]
+            .iter()
+            .map(|o| o.load(Ordering::Relaxed) as usize)
+            .sum();
+        errors as f64 / samples as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_halves_on_high_error_rate() {
+        let controller = AdaptiveConcurrency::new(16, 0.2, 0.05);
+        for _ in 0..ADJUST_INTERVAL - 1 {
+            assert_eq!(controller.record(true), None);
+        }
+        assert_eq!(controller.record(true), Some(8));
+        assert_eq!(controller.current_limit(), 8);
+    }
+
+    #[test]
+    fn test_recovers_towards_max() {
+        let controller = AdaptiveConcurrency::new(4, 0.2, 0.05);
+        for _ in 0..ADJUST_INTERVAL {
+            controller.record(true);
+        }
+        assert_eq!(controller.current_limit(), 2);
+
+        // Push the errors out of the window with successes
+        for _ in 0..ERROR_WINDOW * 2 {
+            controller.record(false);
+        }
+        assert_eq!(controller.current_limit(), 4);
+        assert_eq!(controller.error_rate(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_respects_limit() {
+        let controller = AdaptiveConcurrency::new(1, 0.2, 0.05);
+        let permit = controller.acquire().await;
+        let blocked = tokio::time::timeout(Duration::from_millis(50), controller.acquire()).await;
+        assert!(blocked.is_err());
+        drop(permit);
+        let _permit = controller.acquire().await;
+    }
+}
@@ -0,0 +1,124 @@
+//! Bounded LRU cache for domain check results
+
+use crate::types::DomainResult;
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+
+struct CacheEntry {
+    result: DomainResult,
+    stored_at: Instant,
+    last_used: u64,
+}
+
+/// Result cache with TTL expiry and least-recently-used eviction
+pub(crate) struct ResultCache {
+    entries: HashMap<String, CacheEntry>,
+    /// Access tick -> domain, oldest first
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+    ttl: Duration,
+    max_entries: usize,
+}
+
+impl ResultCache {
+    pub(crate) fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            ttl,
+            max_entries: max_entries.max(1),
+        }
+    }
+
+    /// Look up a fresh result, marking it as recently used
+    pub(crate) fn get(&mut self, domain: &str) -> Option<DomainResult> {
+        let expired = self.entries.get(domain)?.stored_at.elapsed() >= self.ttl;
+        if expired {
+            self.remove(domain);
+            return None;
+        }
+
+        self.tick += 1;
+        let tick = self.tick;
+        let entry = self.entries.get_mut(domain)?;
+        self.recency.remove(&entry.last_used);
+        self.recency.insert(tick, domain.to_string());
+        entry.last_used = tick;
+        Some(entry.result.clone())
+    }
+
+    /// Store a result, evicting the least recently used entry when full
+    pub(crate) fn insert(&mut self, domain: String, result: DomainResult) {
+        self.remove(&domain);
+
+        while self.entries.len() >= self.max_entries {
+            match self.recency.pop_first() {
+                Some((_, oldest)) => {
+                    self.entries.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+
+        self.tick += 1;
+        self.recency.insert(self.tick, domain.clone());
+        self.entries.insert(
+            domain,
+            CacheEntry {
+                result,
+                stored_at: Instant::now(),
+                last_used: self.tick,
+            },
+        );
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn remove(&mut self, domain: &str) {
+        if let Some(entry) = self.entries.remove(domain) {
+            self.recency.remove(&entry.last_used);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AvailabilityStatus, CheckMethod};
+
+    fn result(domain: &str) -> DomainResult {
+        DomainResult::new(domain, AvailabilityStatus::Taken, CheckMethod::Rdap)
+    }
+
+    #[test]
+    fn test_cache_hit_and_expiry() {
+        let mut cache = ResultCache::new(Duration::from_secs(60), 10);
+        cache.insert("example.com".to_string(), result("example.com"));
+        assert!(cache.get("example.com").is_some());
+        assert!(cache.get("other.com").is_none());
+
+        let mut expired = ResultCache::new(Duration::ZERO, 10);
+        expired.insert("example.com".to_string(), result("example.com"));
+        assert!(expired.get("example.com").is_none());
+        assert_eq!(expired.len(), 0);
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let mut cache = ResultCache::new(Duration::from_secs(60), 2);
+        cache.insert("a.com".to_string(), result("a.com"));
+        cache.insert("b.com".to_string(), result("b.com"));
+
+        // Touch a.com so b.com becomes the eviction candidate
+        assert!(cache.get("a.com").is_some());
+        cache.insert("c.com".to_string(), result("c.com"));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("a.com").is_some());
+        assert!(cache.get("b.com").is_none());
+        assert!(cache.get("c.com").is_some());
+    }
+}
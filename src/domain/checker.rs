@@ -1,13 +1,21 @@
 //! Domain availability checker
 
-use crate::domain::DomainValidator;
+use crate::domain::adaptive::AdaptiveConcurrency;
+use crate::domain::cache::ResultCache;
+use crate::domain::{DomainValidator, ReservedNames};
 use crate::error::{DomainForgeError, Result};
-use crate::rdap::registry::rdap_base_url;
+use crate::rdap::registry::{lookup, supported_tlds, RdapRegistry};
+use crate::rdap::status::interpret_rdap_status;
+use crate::rdap::{with_headers, with_proxy};
 use crate::types::{AvailabilityStatus, CheckConfig, CheckMethod, DomainResult, PerformanceMetrics};
 use chrono::{DateTime, Utc};
 use futures::future::join_all;
+use futures::stream::{FuturesUnordered, Stream};
+use parking_lot::RwLock;
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Semaphore;
@@ -22,6 +30,10 @@ pub struct DomainChecker {
     whois_client: Option<WhoisClient>,
     validator: DomainValidator,
     metrics: Arc<PerformanceMetrics>,
+    cache: Option<Arc<RwLock<ResultCache>>>,
+    concurrency: AdaptiveConcurrency,
+    #[cfg(feature = "metrics")]
+    exporter: crate::metrics::PrometheusExporter,
 }
 
 impl DomainChecker {
@@ -33,34 +45,44 @@ impl DomainChecker {
 
     /// Create a new domain checker with custom configuration
     pub fn with_config(config: CheckConfig) -> Self {
-        let client = Client::builder()
-            .timeout(config.timeout)
-            .user_agent("domain-forge/0.1.0")
-            .pool_max_idle_per_host(config.connection_pool_size)
-            .pool_idle_timeout(Duration::from_secs(90))
-            .build()
-            .unwrap_or_else(|e| {
-                tracing::warn!("Failed to create optimized HTTP client: {}. Using default.", e);
-                Client::new()
-            });
-
         let semaphore = Semaphore::new(config.concurrent_checks);
         
         let rdap_client = if config.enable_rdap {
-            Some(RdapClient::new(client.clone()))
+            let client = http_client(&config, config.connection_pool_size);
+            Some(
+                RdapClient::new(client)
+                    .with_server_clients(server_clients(&config))
+                    .with_registry(config.rdap_registry.clone()),
+            )
         } else {
             None
         };
 
         #[cfg(feature = "whois")]
         let whois_client = if config.enable_whois {
-            Some(WhoisClient::new())
+            Some(
+                WhoisClient::new()
+                    .with_cache(config.cache_ttl.unwrap_or(WHOIS_CACHE_TTL))
+                    .with_limits(config.whois.clone()),
+            )
         } else {
             None
         };
 
-        let validator = DomainValidator::new();
+        let reserved = ReservedNames::new().with_extra(&config.extra_reserved_names);
+        let validator = DomainValidator::new()
+            .with_reserved_names(reserved)
+            .with_reserved_check();
         let metrics = Arc::new(PerformanceMetrics::new());
+        let concurrency = AdaptiveConcurrency::new(
+            config.concurrent_checks,
+            config.adaptive_error_threshold,
+            config.adaptive_recovery_threshold,
+        );
+        metrics.set_current_concurrency(concurrency.current_limit());
+        let cache = config
+            .cache_ttl
+            .map(|ttl| Arc::new(RwLock::new(ResultCache::new(ttl, config.cache_max_entries))));
 
         Self {
             config,
@@ -69,12 +91,116 @@ impl DomainChecker {
             #[cfg(feature = "whois")]
             whois_client,
             validator,
+            #[cfg(feature = "metrics")]
+            exporter: crate::metrics::PrometheusExporter::new(Arc::clone(&metrics)),
             metrics,
+            cache,
+            concurrency,
         }
     }
 
-    /// Check a single domain with performance monitoring
+    /// Check a single domain, serving fresh results from the cache when enabled
     pub async fn check_domain(&self, domain: &str) -> Result<DomainResult> {
+        self.check_domain_within(domain, None, true).await
+    }
+
+    /// Check a single domain with `limit` as the RDAP request timeout instead
+    /// of the default, e.g. longer for registries with slow RDAP servers
+    pub async fn check_domain_with_timeout(&self, domain: &str, limit: Duration) -> Result<DomainResult> {
+        self.check_domain_within(domain, Some(limit), true).await
+    }
+
+    /// Check a domain; with `whois_fallback` off, a domain RDAP cannot answer
+    /// is returned as `Unknown` for the caller to look up over WHOIS
+    async fn check_domain_within(
+        &self,
+        domain: &str,
+        limit: Option<Duration>,
+        whois_fallback: bool,
+    ) -> Result<DomainResult> {
+        let cache_key = domain.trim().to_lowercase();
+
+        // Reserved names are never registrable, no need to ask a registry
+        if self.validator.is_reserved(&cache_key) {
+            tracing::debug!(domain = %domain, "Domain is reserved");
+            return Ok(DomainResult {
+                domain: cache_key,
+                status: AvailabilityStatus::Reserved,
+                method: CheckMethod::Unknown,
+                checked_at: Utc::now(),
+                check_duration: None,
+                registrar: None,
+                creation_date: None,
+                expiration_date: None,
+                nameservers: Vec::new(),
+                error_message: None,
+                registrant_name: None,
+                admin_contact: None,
+                tech_contact: None,
+                abuse_email: None,
+            });
+        }
+
+        if let Some(cache) = &self.cache {
+            if let Some(result) = cache.write().get(&cache_key) {
+                tracing::debug!(domain = %domain, "Domain check served from cache");
+                return Ok(result);
+            }
+        }
+
+        let result = {
+            let _slot = self.concurrency.acquire().await;
+            self.check_domain_uncached(domain, limit, whois_fallback).await
+        };
+        self.record_outcome(&result);
+        let result = result?;
+        self.remember(cache_key, &result);
+
+        Ok(result)
+    }
+
+    /// Cache a result; only definitive answers are worth reusing
+    fn remember(&self, cache_key: String, result: &DomainResult) {
+        if let Some(cache) = &self.cache {
+            if matches!(
+                result.status,
+                AvailabilityStatus::Available
+                    | AvailabilityStatus::Taken
+                    | AvailabilityStatus::Expired
+                    | AvailabilityStatus::PendingDelete
+            ) {
+                cache.write().insert(cache_key, result.clone());
+            }
+        }
+    }
+
+    /// Feed a check outcome, failed checks included, into the adaptive
+    /// concurrency controller and the error metrics
+    fn record_outcome(&self, result: &Result<DomainResult>) {
+        let is_error = match result {
+            Ok(result) => matches!(result.status, AvailabilityStatus::Unknown | AvailabilityStatus::Error),
+            Err(_) => {
+                self.metrics.increment_errors();
+                true
+            }
+        };
+        if let Some(limit) = self.concurrency.record(is_error) {
+            self.metrics.set_current_concurrency(limit);
+            tracing::info!(
+                concurrency = limit,
+                error_rate = %format!("{:.2}", self.concurrency.error_rate()),
+                "Adjusted domain check concurrency"
+            );
+        }
+    }
+
+    /// Check a single domain with performance monitoring
+    async fn check_domain_uncached(
+        &self,
+        domain: &str,
+        limit: Option<Duration>,
+        whois_fallback: bool,
+    ) -> Result<DomainResult> {
         let _permit = self.semaphore.acquire().await.map_err(|e| {
             DomainForgeError::internal(format!("Failed to acquire semaphore: {}", e))
         })?;
@@ -86,7 +212,7 @@ impl DomainChecker {
         
         // Try RDAP first
         if let Some(rdap_client) = &self.rdap_client {
-            match rdap_client.check_domain(&validated.get_full_domain()).await {
+            match rdap_client.check_domain(&validated.get_full_domain(), limit).await {
                 Ok(result) => {
                     let duration = start_time.elapsed();
                     self.metrics.increment_domains_checked();
@@ -100,18 +226,14 @@ impl DomainChecker {
                         "Domain check completed"
                     );
                     
-                    return Ok(DomainResult {
-                        domain: validated.get_full_domain(),
-                        status: result.status,
-                        method: CheckMethod::Rdap,
-                        checked_at: Utc::now(),
-                        check_duration: Some(duration),
-                        registrar: result.registrar,
-                        creation_date: result.creation_date,
-                        expiration_date: result.expiration_date,
-                        nameservers: result.nameservers,
-                        error_message: None,
-                    });
+                    let rdap_result = result.into_domain_result(validated.get_full_domain(), CheckMethod::Rdap, duration);
+                    #[cfg(feature = "whois")]
+                    if whois_fallback {
+                        if let Some(whois_result) = self.whois_details(&rdap_result).await {
+                            return Ok(DomainResult::combine(&rdap_result, &whois_result));
+                        }
+                    }
+                    return Ok(rdap_result);
                 }
                 Err(e) => {
                     tracing::debug!(domain = %domain, method = "rdap", error = %e, "RDAP check failed");
@@ -133,6 +255,10 @@ impl DomainChecker {
                             expiration_date: None,
                             nameservers: Vec::new(),
                             error_message: None,
+                            registrant_name: None,
+                            admin_contact: None,
+                            tech_contact: None,
+                            abuse_email: None,
                         });
                     }
                 }
@@ -141,7 +267,7 @@ impl DomainChecker {
 
         // Fall back to WHOIS (optional feature)
         #[cfg(feature = "whois")]
-        if let Some(whois_client) = &self.whois_client {
+        if let Some(whois_client) = self.whois_client.as_ref().filter(|_| whois_fallback) {
             match whois_client.check_domain(&validated.get_full_domain()).await {
                 Ok(result) => {
                     let duration = start_time.elapsed();
@@ -156,18 +282,7 @@ impl DomainChecker {
                         "Domain check completed"
                     );
 
-                    return Ok(DomainResult {
-                        domain: validated.get_full_domain(),
-                        status: result.status,
-                        method: CheckMethod::Whois,
-                        checked_at: Utc::now(),
-                        check_duration: Some(duration),
-                        registrar: result.registrar,
-                        creation_date: result.creation_date,
-                        expiration_date: result.expiration_date,
-                        nameservers: result.nameservers,
-                        error_message: None,
-                    });
+                    return Ok(result.into_domain_result(validated.get_full_domain(), CheckMethod::Whois, duration));
                 }
                 Err(e) => {
                     tracing::debug!(domain = %domain, method = "whois", error = %e, "WHOIS check failed");
@@ -189,14 +304,38 @@ impl DomainChecker {
                             expiration_date: None,
                             nameservers: Vec::new(),
                             error_message: None,
+                            registrant_name: None,
+                            admin_contact: None,
+                            tech_contact: None,
+                            abuse_email: None,
                         });
                     }
                 }
             }
         }
 
-        // Both methods failed
         let duration = start_time.elapsed();
+        if !whois_fallback && self.has_whois() {
+            tracing::debug!(domain = %domain, "RDAP failed, deferring to batch WHOIS lookup");
+            return Ok(DomainResult {
+                domain: validated.get_full_domain(),
+                status: AvailabilityStatus::Unknown,
+                method: CheckMethod::Unknown,
+                checked_at: Utc::now(),
+                check_duration: Some(duration),
+                registrar: None,
+                creation_date: None,
+                expiration_date: None,
+                nameservers: Vec::new(),
+                error_message: Some("RDAP check failed".to_string()),
+                registrant_name: None,
+                admin_contact: None,
+                tech_contact: None,
+                abuse_email: None,
+            });
+        }
+
+        // Both methods failed
         self.metrics.increment_errors();
         
         tracing::warn!(
@@ -216,14 +355,28 @@ impl DomainChecker {
             expiration_date: None,
             nameservers: Vec::new(),
             error_message: Some("All checking methods failed".to_string()),
+            registrant_name: None,
+            admin_contact: None,
+            tech_contact: None,
+            abuse_email: None,
         })
     }
 
-    /// Check multiple domains concurrently with batch performance monitoring
-    pub async fn check_domains(&self, domains: &[String]) -> Result<Vec<DomainResult>> {
+    /// Check multiple domains concurrently with batch performance monitoring.
+    ///
+    /// `timeouts`, when given, runs parallel to `domains`: a `Some` entry
+    /// overrides the configured timeout for that domain, while `None` entries
+    /// (and domains past the end of the list) use the default.
+    ///
+    /// Domains RDAP cannot answer fall back to WHOIS together, grouped by
+    /// WHOIS server and rate limited per server (see [`crate::types::WhoisConfig`]).
+    pub async fn check_domains(
+        &self,
+        domains: &[String],
+        timeouts: Option<&[Option<Duration>]>,
+    ) -> Result<Vec<DomainResult>> {
         let batch_start = Instant::now();
-        let futures = domains.iter().map(|domain| self.check_domain(domain));
-        let results = join_all(futures).await;
+        let results = self.check_batch(domains, timeouts).await;
 
         let mut success_results = Vec::new();
         let mut error_count = 0u32;
@@ -251,6 +404,168 @@ impl DomainChecker {
         Ok(success_results)
     }
 
+    /// One result per domain, in input order, with batched WHOIS fallback
+    async fn check_batch(&self, domains: &[String], timeouts: Option<&[Option<Duration>]>) -> Vec<Result<DomainResult>> {
+        let futures = domains.iter().enumerate().map(|(index, domain)| {
+            let limit = timeouts.and_then(|timeouts| timeouts.get(index).copied().flatten());
+            self.check_domain_within(domain, limit, false)
+        });
+        let results = join_all(futures).await;
+        #[cfg(feature = "whois")]
+        let results = self.whois_fallback(results).await;
+        results
+    }
+
+    /// WHOIS record for a registered domain whose RDAP record lacks the
+    /// registrar or nameservers
+    #[cfg(feature = "whois")]
+    async fn whois_details(&self, rdap_result: &DomainResult) -> Option<DomainResult> {
+        let whois_client = self.whois_client.as_ref()?;
+        let registered = matches!(
+            rdap_result.status,
+            AvailabilityStatus::Taken | AvailabilityStatus::Expired | AvailabilityStatus::PendingDelete
+        );
+        if !registered || (rdap_result.registrar.is_some() && !rdap_result.nameservers.is_empty()) {
+            return None;
+        }
+
+        let start_time = Instant::now();
+        match whois_client.check_domain(&rdap_result.domain).await {
+            Ok(result) => Some(result.into_domain_result(
+                rdap_result.domain.clone(),
+                CheckMethod::Whois,
+                start_time.elapsed(),
+            )),
+            Err(e) => {
+                tracing::debug!(domain = %rdap_result.domain, error = %e, "WHOIS enrichment failed");
+                None
+            }
+        }
+    }
+
+    /// Look up domains RDAP could not answer over WHOIS, in one batch
+    #[cfg(feature = "whois")]
+    async fn whois_fallback(&self, mut results: Vec<Result<DomainResult>>) -> Vec<Result<DomainResult>> {
+        let Some(whois_client) = &self.whois_client else {
+            return results;
+        };
+
+        let pending: Vec<(usize, String)> = results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| match result {
+                Ok(result) if result.status == AvailabilityStatus::Unknown && result.method == CheckMethod::Unknown => {
+                    Some((index, result.domain.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        if pending.is_empty() {
+            return results;
+        }
+
+        let start_time = Instant::now();
+        let names: Vec<String> = pending.iter().map(|(_, domain)| domain.clone()).collect();
+        let checked = whois_client.check_domains(&names).await;
+        let duration = start_time.elapsed();
+
+        for ((index, domain), outcome) in pending.into_iter().zip(checked) {
+            let outcome = match outcome {
+                Err(e) if e.suggests_available() => Ok(DomainCheckResult::available()),
+                outcome => outcome,
+            };
+            let result = match outcome {
+                Ok(result) => {
+                    self.metrics.increment_domains_checked();
+                    self.metrics.add_check_time(duration.as_millis() as u64);
+                    result.into_domain_result(domain.clone(), CheckMethod::Whois, duration)
+                }
+                Err(e) => {
+                    self.metrics.increment_errors();
+                    tracing::warn!(domain = %domain, error = %e, "All domain checking methods failed");
+                    DomainResult {
+                        domain: domain.clone(),
+                        status: AvailabilityStatus::Unknown,
+                        method: CheckMethod::Unknown,
+                        checked_at: Utc::now(),
+                        check_duration: Some(duration),
+                        registrar: None,
+                        creation_date: None,
+                        expiration_date: None,
+                        nameservers: Vec::new(),
+                        error_message: Some("All checking methods failed".to_string()),
+                        registrant_name: None,
+                        admin_contact: None,
+                        tech_contact: None,
+                        abuse_email: None,
+                    }
+                }
+            };
+            self.remember(domain, &result);
+            results[index] = Ok(result);
+        }
+        results
+    }
+
+    /// Check multiple domains concurrently, yielding each result as soon as
+    /// its check finishes (completion order, not input order) together with
+    /// the index of its domain in `domains`
+    pub fn check_stream<'a>(
+        &'a self,
+        domains: &'a [String],
+    ) -> impl Stream<Item = (usize, Result<DomainResult>)> + 'a {
+        domains
+            .iter()
+            .enumerate()
+            .map(|(index, domain)| async move { (index, self.check_domain(domain).await) })
+            .collect::<FuturesUnordered<_>>()
+    }
+
+    /// Check multiple domains concurrently, pairing each input with its result.
+    ///
+    /// The output has one entry per input, in input order, whatever order the
+    /// checks finish in. Domains that cannot be checked at all (e.g. invalid
+    /// names) get an [`AvailabilityStatus::Error`] result carrying the error
+    /// message instead of being dropped.
+    pub async fn check_domains_ordered(&self, domains: &[String]) -> Result<Vec<(String, DomainResult)>> {
+        let results = self.check_batch(domains, None).await;
+        Ok(domains
+            .iter()
+            .zip(results)
+            .map(|(domain, result)| {
+                let result = result.unwrap_or_else(|e| {
+                    tracing::warn!(domain = %domain, error = %e, "Failed to check domain");
+                    DomainResult::error(domain.trim().to_lowercase(), e.to_string())
+                });
+                (domain.clone(), result)
+            })
+            .collect())
+    }
+
+    /// Check a domain against its registry's RDAP server only, returning the
+    /// record it was judged from (`Value::Null` when the registry has none)
+    pub async fn check_domain_raw(&self, domain: &str) -> Result<(DomainResult, serde_json::Value)> {
+        let rdap_client = self.rdap()?;
+        let full_domain = self.validator.validate(domain)?.get_full_domain();
+        let start_time = Instant::now();
+        let (result, record) = rdap_client.check_domain_raw(&full_domain).await?;
+        Ok((result.into_domain_result(full_domain, CheckMethod::Rdap, start_time.elapsed()), record))
+    }
+
+    /// Registry RDAP response body for a domain, exactly as served (`None`
+    /// when the registry has no record)
+    pub async fn rdap_body(&self, domain: &str) -> Result<Option<String>> {
+        let rdap_client = self.rdap()?;
+        let full_domain = self.validator.validate(domain)?.get_full_domain();
+        rdap_client.registry_body(&full_domain).await
+    }
+
+    fn rdap(&self) -> Result<&RdapClient> {
+        self.rdap_client
+            .as_ref()
+            .ok_or_else(|| DomainForgeError::config("RDAP is disabled"))
+    }
+
     /// Get checker configuration
     pub fn config(&self) -> &CheckConfig {
         &self.config
@@ -258,20 +573,26 @@ impl DomainChecker {
 
     /// Check if checker is configured properly
     pub fn is_configured(&self) -> bool {
-        let has_whois = {
-            #[cfg(feature = "whois")]
-            {
-                self.whois_client.is_some()
-            }
-            #[cfg(not(feature = "whois"))]
-            {
-                false
-            }
-        };
+        self.rdap_client.is_some() || self.has_whois()
+    }
 
-        self.rdap_client.is_some() || has_whois
+    /// Whether WHOIS fallback is compiled in and enabled
+    fn has_whois(&self) -> bool {
+        #[cfg(feature = "whois")]
+        {
+            self.whois_client.is_some()
+        }
+        #[cfg(not(feature = "whois"))]
+        {
+            false
+        }
     }
     
+    /// Number of results currently held in the check cache
+    pub fn cached_results(&self) -> usize {
+        self.cache.as_ref().map(|c| c.read().len()).unwrap_or(0)
+    }
+
     /// Get performance metrics
     pub fn get_metrics(&self) -> Arc<PerformanceMetrics> {
         Arc::clone(&self.metrics)
@@ -281,6 +602,32 @@ impl DomainChecker {
     pub fn get_metrics_snapshot(&self) -> crate::types::MetricsSnapshot {
         self.metrics.get_stats()
     }
+
+    /// TLDs this checker can check without discovery, sorted: those with an
+    /// RDAP server (from `rdap_registry` when set) plus, with the `whois`
+    /// feature, those with a built-in WHOIS server
+    pub fn available_tlds(&self) -> Vec<String> {
+        let mut tlds: Vec<String> = Vec::new();
+        if self.rdap_client.is_some() {
+            match self.config.rdap_registry.as_deref() {
+                Some(registry) => tlds.extend(registry.tlds().map(str::to_string)),
+                None => tlds.extend(supported_tlds().map(str::to_string)),
+            }
+        }
+        #[cfg(feature = "whois")]
+        if self.whois_client.is_some() {
+            tlds.extend(WHOIS_SERVERS.iter().map(|(tld, _)| tld.to_string()));
+        }
+        tlds.sort();
+        tlds.dedup();
+        tlds
+    }
+
+    /// Prometheus exporter bound to this checker's metrics
+    #[cfg(feature = "metrics")]
+    pub fn prometheus_exporter(&self) -> &crate::metrics::PrometheusExporter {
+        &self.exporter
+    }
 }
 
 impl Default for DomainChecker {
@@ -289,65 +636,190 @@ impl Default for DomainChecker {
     }
 }
 
+/// HTTP client for RDAP queries with the given idle pool size per host
+fn http_client(config: &CheckConfig, pool_size: usize) -> Client {
+    let builder = Client::builder()
+        .timeout(config.timeout)
+        .pool_max_idle_per_host(pool_size)
+        .pool_idle_timeout(Duration::from_secs(90));
+    with_headers(builder, &config.rdap_user_agent(), &config.extra_headers)
+        .and_then(|builder| {
+            with_proxy(
+                builder,
+                config.proxy.as_deref(),
+                config.proxy_username.as_deref(),
+                config.proxy_password.as_deref(),
+            )
+        })
+        .and_then(|builder| builder.build().map_err(|e| DomainForgeError::network(e.to_string(), None, None)))
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to create optimized HTTP client: {}. Using default.", e);
+            Client::new()
+        })
+}
+
+/// Clients for RDAP servers with a `per_tld_pool_size` override, keyed by
+/// base URL. TLDs sharing a server get the largest of their pool sizes.
+fn server_clients(config: &CheckConfig) -> HashMap<String, Client> {
+    let mut pool_sizes: HashMap<&str, usize> = HashMap::new();
+    for (tld, &size) in &config.per_tld_pool_size {
+        let tld = tld.trim_start_matches('.').to_lowercase();
+        match lookup(config.rdap_registry.as_deref(), &tld) {
+            Some(url) => {
+                let entry = pool_sizes.entry(url).or_insert(size);
+                *entry = (*entry).max(size);
+            }
+            None => tracing::warn!(tld = %tld, "Ignoring pool size for TLD without an RDAP server"),
+        }
+    }
+    pool_sizes
+        .into_iter()
+        .map(|(url, size)| (url.to_string(), http_client(config, size)))
+        .collect()
+}
+
+/// How many registrar referrals (`links` with `rel: "related"`) to follow.
+/// HTTP 301/302 redirects are followed by reqwest itself.
+const MAX_RDAP_REFERRALS: usize = 2;
+
+/// Per-request RDAP timeout unless a check overrides it
+const RDAP_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// RDAP client for domain checking
 struct RdapClient {
     client: Client,
+    /// Clients with their own pool size, keyed by RDAP base URL
+    server_clients: HashMap<String, Client>,
+    /// Runtime RDAP servers; the built-in table when `None`
+    registry: Option<Arc<RdapRegistry>>,
 }
 
 impl RdapClient {
     fn new(client: Client) -> Self {
         Self {
             client,
+            server_clients: HashMap::new(),
+            registry: None,
         }
     }
 
-    async fn check_domain(&self, domain: &str) -> Result<DomainCheckResult> {
+    fn with_registry(mut self, registry: Option<Arc<RdapRegistry>>) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    fn with_server_clients(mut self, clients: HashMap<String, Client>) -> Self {
+        self.server_clients = clients;
+        self
+    }
+
+    /// Client to use for an RDAP server
+    fn client_for(&self, rdap_url: &str) -> &Client {
+        self.server_clients.get(rdap_url).unwrap_or(&self.client)
+    }
+
+    /// Look a domain up, with `limit` replacing the default per-request timeout
+    async fn check_domain(&self, domain: &str, limit: Option<Duration>) -> Result<DomainCheckResult> {
+        let rdap_url = self.server_for(domain)?;
+        let url = format!("{}domain/{}", rdap_url, domain);
+
+        let Some(response): Option<RdapResponse> = self.fetch(self.client_for(rdap_url), &url, limit).await? else {
+            return Ok(DomainCheckResult::available());
+        };
+
+        // Thin registries point to the registrar's RDAP server for the full record
+        let mut visited = vec![url];
+        let mut referral = response.related_link(domain);
+        let mut result = self.parse_rdap_response(response);
+        for _ in 0..MAX_RDAP_REFERRALS {
+            let Some(href) = referral.take().filter(|href| !visited.contains(href)) else {
+                break;
+            };
+            match self.fetch::<RdapResponse>(&self.client, &href, limit).await {
+                Ok(Some(response)) => {
+                    referral = response.related_link(domain);
+                    result = result.refined_by(self.parse_rdap_response(response));
+                }
+                // The registry has a record, so its answer stands
+                Ok(None) => tracing::debug!(domain, url = %href, "Registrar RDAP has no record"),
+                Err(e) => tracing::debug!(domain, url = %href, error = %e, "Registrar RDAP referral failed"),
+            }
+            visited.push(href);
+        }
+
+        Ok(result)
+    }
+
+    /// RDAP base URL for a domain's TLD
+    fn server_for(&self, domain: &str) -> Result<&str> {
         // Safe TLD extraction
-        let tld = domain.split('.').last()
+        let tld = domain.split('.').next_back()
             .ok_or_else(|| DomainForgeError::validation("Invalid domain format - no TLD found".to_string()))?;
-            
-        let rdap_url = rdap_base_url(tld).ok_or_else(|| {
+
+        lookup(self.registry.as_deref(), tld).ok_or_else(|| {
             DomainForgeError::domain_check(
                 domain.to_string(),
                 format!("No RDAP server found for TLD: {}", tld),
                 Some("rdap".to_string()),
             )
-        })?;
+        })
+    }
+
+    /// Check a domain against its registry alone (no registrar referrals),
+    /// keeping the record as JSON; a 404 gives `Value::Null`
+    async fn check_domain_raw(&self, domain: &str) -> Result<(DomainCheckResult, serde_json::Value)> {
+        let Some(body) = self.registry_body(domain).await? else {
+            return Ok((DomainCheckResult::available(), serde_json::Value::Null));
+        };
+        let record: serde_json::Value =
+            serde_json::from_str(&body).map_err(|e| DomainForgeError::parse(e.to_string(), Some(body)))?;
+        let response: RdapResponse = serde_json::from_value(record.clone())
+            .map_err(|e| DomainForgeError::parse(e.to_string(), None))?;
+        Ok((self.parse_rdap_response(response), record))
+    }
 
+    /// The registry's RDAP response body for a domain
+    async fn registry_body(&self, domain: &str) -> Result<Option<String>> {
+        let rdap_url = self.server_for(domain)?;
         let url = format!("{}domain/{}", rdap_url, domain);
-        
-        let response = timeout(Duration::from_secs(10), self.client.get(&url).send()).await
-            .map_err(|_| DomainForgeError::timeout("RDAP request", 10))?
-            .map_err(|e| DomainForgeError::network(e.to_string(), None, Some(url.clone())))?;
+        self.fetch_body(self.client_for(rdap_url), &url, None).await
+    }
+
+    /// GET an RDAP domain record; `None` when the server answers 404
+    async fn fetch<T: DeserializeOwned>(&self, client: &Client, url: &str, limit: Option<Duration>) -> Result<Option<T>> {
+        let Some(text) = self.fetch_body(client, url, limit).await? else {
+            return Ok(None);
+        };
+
+        serde_json::from_str(&text)
+            .map(Some)
+            .map_err(|e| DomainForgeError::parse(e.to_string(), Some(text)))
+    }
+
+    /// GET an RDAP response body; `None` when the server answers 404
+    async fn fetch_body(&self, client: &Client, url: &str, limit: Option<Duration>) -> Result<Option<String>> {
+        let limit = limit.unwrap_or(RDAP_REQUEST_TIMEOUT);
+        let response = timeout(limit, client.get(url).timeout(limit).send()).await
+            .map_err(|_| DomainForgeError::timeout("RDAP request", limit.as_secs().max(1)))?
+            .map_err(|e| DomainForgeError::network(e.to_string(), None, Some(url.to_string())))?;
 
         let status = response.status();
-        
+
         if status.as_u16() == 404 {
-            return Ok(DomainCheckResult {
-                status: AvailabilityStatus::Available,
-                registrar: None,
-                creation_date: None,
-                expiration_date: None,
-                nameservers: Vec::new(),
-            });
+            return Ok(None);
         }
 
         if !status.is_success() {
             return Err(DomainForgeError::network(
                 format!("RDAP request failed with status {}", status),
                 Some(status.as_u16()),
-                Some(url),
+                Some(url.to_string()),
             ));
         }
 
-        let text = response.text().await.map_err(|e| {
-            DomainForgeError::network(e.to_string(), None, Some(url.clone()))
-        })?;
-
-        let rdap_response: RdapResponse = serde_json::from_str(&text)
-            .map_err(|e| DomainForgeError::parse(e.to_string(), Some(text)))?;
-
-        Ok(self.parse_rdap_response(rdap_response))
+        response.text().await.map(Some).map_err(|e| {
+            DomainForgeError::network(e.to_string(), None, Some(url.to_string()))
+        })
     }
 
     fn parse_rdap_response(&self, response: RdapResponse) -> DomainCheckResult {
@@ -357,7 +829,7 @@ impl RdapClient {
                         !response.entities.is_empty() ||
                         !response.events.is_empty() ||
                         !response.nameservers.is_empty() {
-            AvailabilityStatus::Taken
+            interpret_rdap_status(&response.status)
         } else {
             AvailabilityStatus::Available
         };
@@ -369,7 +841,7 @@ impl RdapClient {
             .and_then(|vcard| {
                 vcard.get(1)
                     .and_then(|props| props.as_array())
-                    .and_then(|props| props.get(0))
+                    .and_then(|props| props.first())
                     .and_then(|prop| prop.as_array())
                     .and_then(|prop| prop.get(3))
                     .and_then(|name| name.as_str())
@@ -391,52 +863,168 @@ impl RdapClient {
             .map(|ns| ns.ldh_name.clone())
             .collect();
 
+        let contact = |role: &str| find_entity(&response.entities, role).and_then(RdapEntity::contact);
+        let contacts = RdapContacts {
+            registrant_name: find_entity(&response.entities, "registrant").and_then(|e| e.vcard_value("fn")),
+            admin_contact: contact("administrative"),
+            tech_contact: contact("technical"),
+            abuse_email: find_entity(&response.entities, "abuse").and_then(|e| e.vcard_value("email")),
+        };
+
+        // Past expiry and in the deletion pipeline: still registered, but likely to drop
+        let status = if status == AvailabilityStatus::PendingDelete
+            && expiration_date.is_some_and(|exp| exp < Utc::now())
+        {
+            AvailabilityStatus::Expired
+        } else {
+            status
+        };
+
         DomainCheckResult {
             status,
             registrar,
             creation_date,
             expiration_date,
             nameservers,
+            contacts,
         }
     }
 }
 
+/// How long raw WHOIS responses are reused when `cache_ttl` is not set
+#[cfg(feature = "whois")]
+const WHOIS_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Minimal convention-based WHOIS servers for high-usage TLDs.
+/// Unknown TLDs fall back to IANA discovery (no extra user config).
+#[cfg(feature = "whois")]
+const WHOIS_SERVERS: &[(&str, &str)] = &[
+    ("com", "whois.verisign-grs.com"),
+    ("net", "whois.verisign-grs.com"),
+    ("org", "whois.pir.org"),
+    ("io", "whois.nic.io"),
+    ("ai", "whois.nic.ai"),
+    ("co", "whois.nic.co"),
+    ("me", "whois.nic.me"),
+    ("xyz", "whois.nic.xyz"),
+];
+
+/// How long a WHOIS server discovered through IANA is remembered
+#[cfg(feature = "whois")]
+const IANA_DISCOVERY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Values with the time they were stored
+#[cfg(feature = "whois")]
+type TimedCache<K> = Arc<RwLock<HashMap<K, (String, Instant)>>>;
+
 /// WHOIS client for domain checking (optional feature)
 #[cfg(feature = "whois")]
-struct WhoisClient;
+#[derive(Default)]
+struct WhoisClient {
+    /// Raw responses keyed on `(server, query)`, when enabled
+    responses: Option<TimedCache<(String, String)>>,
+    response_ttl: Duration,
+    limits: crate::types::WhoisConfig,
+    /// Authoritative WHOIS servers found through IANA, keyed on TLD
+    discovered: TimedCache<String>,
+}
 
 #[cfg(feature = "whois")]
 impl WhoisClient {
     fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Reuse responses for the same `(server, query)` for `ttl`
+    fn with_cache(mut self, ttl: Duration) -> Self {
+        self.responses = Some(Arc::default());
+        self.response_ttl = ttl;
+        self
+    }
+
+    /// Rate limits for [`Self::check_domains`]
+    fn with_limits(mut self, limits: crate::types::WhoisConfig) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    fn cached<K: Eq + std::hash::Hash>(cache: &TimedCache<K>, key: &K, ttl: Duration) -> Option<String> {
+        cache
+            .read()
+            .get(key)
+            .filter(|(_, stored_at)| stored_at.elapsed() < ttl)
+            .map(|(value, _)| value.clone())
     }
 
     async fn check_domain(&self, domain: &str) -> Result<DomainCheckResult> {
         // Pure Rust WHOIS over TCP/43 (no external `whois` binary required).
+        let server = self.server_for(domain).await?;
+        let raw = self.query_cached(&server, domain).await?;
+        self.parse_whois_response(&raw, domain)
+    }
+
+    /// Check several domains, one result per input in input order.
+    ///
+    /// Most WHOIS servers answer one query per connection, so rather than
+    /// pipelining, domains are grouped by server: each server gets at most
+    /// `max_connections_per_server` queries at a time, each connection
+    /// waiting `per_server_rate_limit_ms` between queries.
+    async fn check_domains(&self, domains: &[String]) -> Vec<Result<DomainCheckResult>> {
+        let mut results: Vec<Option<Result<DomainCheckResult>>> = domains.iter().map(|_| None).collect();
+        let mut groups: HashMap<String, Vec<usize>> = HashMap::new();
+        for (index, domain) in domains.iter().enumerate() {
+            match self.server_for(domain).await {
+                Ok(server) => groups.entry(server).or_default().push(index),
+                Err(e) => results[index] = Some(Err(e)),
+            }
+        }
+
+        let delay = Duration::from_millis(self.limits.per_server_rate_limit_ms);
+        let connections = self.limits.max_connections_per_server.max(1);
+        let queues = groups.iter().flat_map(|(server, indices)| {
+            (0..connections.min(indices.len())).map(move |lane| async move {
+                let mut checked = Vec::new();
+                for (n, &index) in indices.iter().skip(lane).step_by(connections).enumerate() {
+                    if n > 0 {
+                        tokio::time::sleep(delay).await;
+                    }
+                    let domain = &domains[index];
+                    let result = match self.query_cached(server, domain).await {
+                        Ok(raw) => self.parse_whois_response(&raw, domain),
+                        Err(e) => Err(e),
+                    };
+                    checked.push((index, result));
+                }
+                checked
+            })
+        });
+        for (index, result) in join_all(queues).await.into_iter().flatten() {
+            results[index] = Some(result);
+        }
+
+        // Every domain either failed server lookup or was queried above
+        results.into_iter().flatten().collect()
+    }
+
+    /// WHOIS server for a domain; TLDs outside the built-in table are looked
+    /// up through IANA
+    async fn server_for(&self, domain: &str) -> Result<String> {
         let tld = domain
             .split('.')
-            .last()
+            .next_back()
             .ok_or_else(|| DomainForgeError::validation("Invalid domain format - no TLD found".to_string()))?
             .to_lowercase();
 
-        let server = self.whois_server_for_tld(&tld).unwrap_or_else(|| "whois.iana.org".to_string());
-
-        // If unknown TLD, ask IANA first to discover the authoritative WHOIS server.
-        let raw = if server == "whois.iana.org" {
-            let iana = self.query_whois("whois.iana.org", &tld).await?;
-            let discovered = Self::parse_iana_whois_server(&iana)
-                .or_else(|| Self::parse_iana_refer_server(&iana))
-                .ok_or_else(|| DomainForgeError::domain_check(
-                    domain.to_string(),
-                    format!("No WHOIS server found for TLD: {}", tld),
-                    Some("whois".to_string()),
-                ))?;
-            self.query_whois(&discovered, domain).await?
-        } else {
-            self.query_whois(&server, domain).await?
-        };
-
-        self.parse_whois_response(&raw, domain)
+        if let Some(server) = self.whois_server_for_tld(&tld) {
+            return Ok(server);
+        }
+        self.discover_server(&tld).await?.ok_or_else(|| {
+            DomainForgeError::domain_check(
+                domain.to_string(),
+                format!("No WHOIS server found for TLD: {}", tld),
+                Some("whois".to_string()),
+            )
+        })
     }
 
     fn parse_whois_response(&self, output: &str, _domain: &str) -> Result<DomainCheckResult> {
@@ -489,6 +1077,7 @@ impl WhoisClient {
             creation_date,
             expiration_date,
             nameservers,
+            contacts: RdapContacts::default(),
         })
     }
 
@@ -541,18 +1130,42 @@ impl WhoisClient {
     }
 
     fn whois_server_for_tld(&self, tld: &str) -> Option<String> {
-        // Minimal convention-based mapping for high-usage TLDs.
-        // Unknown TLDs fall back to IANA discovery (no extra user config).
-        match tld {
-            "com" | "net" => Some("whois.verisign-grs.com".to_string()),
-            "org" => Some("whois.pir.org".to_string()),
-            "io" => Some("whois.nic.io".to_string()),
-            "ai" => Some("whois.nic.ai".to_string()),
-            "co" => Some("whois.nic.co".to_string()),
-            "me" => Some("whois.nic.me".to_string()),
-            "xyz" => Some("whois.nic.xyz".to_string()),
-            _ => None,
+        WHOIS_SERVERS
+            .iter()
+            .find(|(known, _)| *known == tld)
+            .map(|(_, server)| server.to_string())
+    }
+
+    /// Authoritative WHOIS server for `tld` according to IANA
+    async fn discover_server(&self, tld: &str) -> Result<Option<String>> {
+        let key = tld.to_string();
+        if let Some(server) = Self::cached(&self.discovered, &key, IANA_DISCOVERY_TTL) {
+            return Ok(Some(server));
         }
+
+        let iana = self.query_whois("whois.iana.org", tld).await?;
+        let server = Self::parse_iana_whois_server(&iana).or_else(|| Self::parse_iana_refer_server(&iana));
+        if let Some(server) = &server {
+            self.discovered.write().insert(key, (server.clone(), Instant::now()));
+        }
+        Ok(server)
+    }
+
+    /// [`Self::query_whois`] through the response cache, when enabled
+    async fn query_cached(&self, server: &str, query: &str) -> Result<String> {
+        let Some(cache) = &self.responses else {
+            return self.query_whois(server, query).await;
+        };
+
+        let key = (server.to_lowercase(), query.to_lowercase());
+        if let Some(raw) = Self::cached(cache, &key, self.response_ttl) {
+            tracing::debug!(server, query, "WHOIS response served from cache");
+            return Ok(raw);
+        }
+
+        let raw = self.query_whois(server, query).await?;
+        cache.write().insert(key, (raw.clone(), Instant::now()));
+        Ok(raw)
     }
 
     async fn query_whois(&self, server: &str, query: &str) -> Result<String> {
@@ -585,7 +1198,7 @@ impl WhoisClient {
             .find_map(|line| {
                 let lower = line.to_lowercase();
                 if lower.starts_with("whois:") {
-                    Some(line.splitn(2, ':').nth(1)?.trim().to_string())
+                    Some(line.split_once(':')?.1.trim().to_string())
                 } else {
                     None
                 }
@@ -599,7 +1212,7 @@ impl WhoisClient {
             .find_map(|line| {
                 let lower = line.to_lowercase();
                 if lower.starts_with("refer:") {
-                    Some(line.splitn(2, ':').nth(1)?.trim().to_string())
+                    Some(line.split_once(':')?.1.trim().to_string())
                 } else {
                     None
                 }
@@ -616,6 +1229,86 @@ struct DomainCheckResult {
     creation_date: Option<DateTime<Utc>>,
     expiration_date: Option<DateTime<Utc>>,
     nameservers: Vec<String>,
+    contacts: RdapContacts,
+}
+
+/// Contacts from RDAP entities; GDPR redaction often leaves them all empty
+#[derive(Debug, Clone, Default)]
+struct RdapContacts {
+    registrant_name: Option<String>,
+    admin_contact: Option<String>,
+    tech_contact: Option<String>,
+    abuse_email: Option<String>,
+}
+
+impl RdapContacts {
+    /// Fill missing contacts from `fallback`
+    fn or(self, fallback: RdapContacts) -> RdapContacts {
+        RdapContacts {
+            registrant_name: self.registrant_name.or(fallback.registrant_name),
+            admin_contact: self.admin_contact.or(fallback.admin_contact),
+            tech_contact: self.tech_contact.or(fallback.tech_contact),
+            abuse_email: self.abuse_email.or(fallback.abuse_email),
+        }
+    }
+}
+
+impl DomainCheckResult {
+    /// No registration record exists
+    fn available() -> Self {
+        DomainCheckResult {
+            status: AvailabilityStatus::Available,
+            registrar: None,
+            creation_date: None,
+            expiration_date: None,
+            nameservers: Vec::new(),
+            contacts: RdapContacts::default(),
+        }
+    }
+
+    fn into_domain_result(self, domain: String, method: CheckMethod, duration: Duration) -> DomainResult {
+        DomainResult {
+            domain,
+            status: self.status,
+            method,
+            checked_at: Utc::now(),
+            check_duration: Some(duration),
+            registrar: self.registrar,
+            creation_date: self.creation_date,
+            expiration_date: self.expiration_date,
+            nameservers: self.nameservers,
+            error_message: None,
+            registrant_name: self.contacts.registrant_name,
+            admin_contact: self.contacts.admin_contact,
+            tech_contact: self.contacts.tech_contact,
+            abuse_email: self.contacts.abuse_email,
+        }
+    }
+
+    /// Prefer a registrar's answer, keeping registry data it leaves out.
+    ///
+    /// The registry already has a record, so the registrar may only narrow a
+    /// registered status (e.g. to pending delete), never report it available.
+    fn refined_by(self, registrar: DomainCheckResult) -> DomainCheckResult {
+        let status = match registrar.status {
+            AvailabilityStatus::Taken | AvailabilityStatus::Expired | AvailabilityStatus::PendingDelete => {
+                registrar.status
+            }
+            _ => self.status,
+        };
+        DomainCheckResult {
+            status,
+            registrar: registrar.registrar.or(self.registrar),
+            creation_date: registrar.creation_date.or(self.creation_date),
+            expiration_date: registrar.expiration_date.or(self.expiration_date),
+            nameservers: if registrar.nameservers.is_empty() {
+                self.nameservers
+            } else {
+                registrar.nameservers
+            },
+            contacts: registrar.contacts.or(self.contacts),
+        }
+    }
 }
 
 /// RDAP response structures
@@ -629,6 +1322,30 @@ struct RdapResponse {
     events: Vec<RdapEvent>,
     #[serde(default)]
     nameservers: Vec<RdapNameserver>,
+    #[serde(default)]
+    links: Vec<RdapLink>,
+}
+
+impl RdapResponse {
+    /// URL of the registrar's record for `domain`, if the server refers to one
+    fn related_link(&self, domain: &str) -> Option<String> {
+        let path = format!("/domain/{}", domain.to_lowercase());
+        self.links
+            .iter()
+            .filter(|link| link.rel.eq_ignore_ascii_case("related"))
+            .filter(|link| link.media_type.as_deref().is_none_or(|t| t == "application/rdap+json"))
+            .find(|link| link.href.to_lowercase().trim_end_matches('/').ends_with(&path))
+            .map(|link| link.href.clone())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RdapLink {
+    #[serde(default)]
+    rel: String,
+    href: String,
+    #[serde(rename = "type")]
+    media_type: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -637,6 +1354,42 @@ struct RdapEntity {
     roles: Vec<String>,
     #[serde(rename = "vcardArray")]
     vcard_array: Option<serde_json::Value>,
+    /// Nested entities, e.g. the abuse contact of a registrar
+    #[serde(default)]
+    entities: Vec<RdapEntity>,
+}
+
+impl RdapEntity {
+    /// Value of a vCard property (`fn`, `email`, ...), ignoring redacted ones
+    fn vcard_value(&self, property: &str) -> Option<String> {
+        self.vcard_array
+            .as_ref()?
+            .get(1)?
+            .as_array()?
+            .iter()
+            .filter_map(|prop| prop.as_array())
+            .find(|prop| prop.first().and_then(|name| name.as_str()) == Some(property))
+            .and_then(|prop| prop.get(3))
+            .and_then(|value| value.as_str())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty() && !value.to_lowercase().contains("redacted"))
+    }
+
+    /// Contact name, or email when the name is withheld
+    fn contact(&self) -> Option<String> {
+        self.vcard_value("fn").or_else(|| self.vcard_value("email"))
+    }
+}
+
+/// First entity with `role`, looking inside nested entities too
+fn find_entity<'a>(entities: &'a [RdapEntity], role: &str) -> Option<&'a RdapEntity> {
+    entities.iter().find_map(|entity| {
+        if entity.roles.iter().any(|r| r.eq_ignore_ascii_case(role)) {
+            Some(entity)
+        } else {
+            find_entity(&entity.entities, role)
+        }
+    })
 }
 
 #[derive(Debug, Deserialize)]
@@ -656,6 +1409,8 @@ struct RdapNameserver {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rdap::registry::rdap_base_url;
+    use futures::StreamExt;
 
     #[tokio::test]
     async fn test_domain_checker_creation() {
@@ -671,6 +1426,153 @@ mod tests {
         // Initially should be zero
         assert_eq!(metrics.domains_checked, 0);
         assert_eq!(metrics.errors_encountered, 0);
+        assert_eq!(metrics.current_concurrency, checker.config().concurrent_checks);
+    }
+
+    #[tokio::test]
+    async fn test_failed_check_is_recorded() {
+        let checker = DomainChecker::new();
+        assert!(checker.check_domain("not a domain!").await.is_err());
+        assert_eq!(checker.get_metrics_snapshot().errors_encountered, 1);
+    }
+
+    #[test]
+    fn test_cache_disabled_by_default() {
+        let checker = DomainChecker::new();
+        assert!(checker.config().cache_ttl.is_none());
+        assert_eq!(checker.cached_results(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_reserved_names_skip_network() {
+        let config = CheckConfig {
+            extra_reserved_names: vec!["forge".to_string()],
+            ..Default::default()
+        };
+        let checker = DomainChecker::with_config(config);
+
+        for domain in ["example.com", "foo.test", "forge.io"] {
+            let result = checker.check_domain(domain).await.unwrap();
+            assert_eq!(result.status, AvailabilityStatus::Reserved, "{}", domain);
+        }
+        assert_eq!(checker.get_metrics_snapshot().domains_checked, 0);
+    }
+
+    #[tokio::test]
+    async fn test_check_stream() {
+        let config = CheckConfig {
+            extra_reserved_names: vec!["forge".to_string()],
+            ..Default::default()
+        };
+        let checker = DomainChecker::with_config(config);
+        let domains: Vec<String> = ["forge.io", "-bad-.com", "example.com"].iter().map(|s| s.to_string()).collect();
+
+        let mut indexed: Vec<(usize, Result<DomainResult>)> = checker.check_stream(&domains).collect().await;
+        indexed.sort_by_key(|(index, _)| *index);
+        assert_eq!(indexed.iter().map(|(index, _)| *index).collect::<Vec<_>>(), [0, 1, 2]);
+        assert!(indexed[1].1.is_err());
+        let results: Vec<Result<DomainResult>> = indexed.into_iter().map(|(_, result)| result).collect();
+        let mut reserved: Vec<String> = results
+            .iter()
+            .filter_map(|r| r.as_ref().ok())
+            .filter(|r| r.status == AvailabilityStatus::Reserved)
+            .map(|r| r.domain.clone())
+            .collect();
+        reserved.sort();
+        assert_eq!(reserved, ["example.com", "forge.io"]);
+        assert_eq!(results.iter().filter(|r| r.is_err()).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_per_domain_timeout() {
+        // An RDAP server that accepts connections but never answers
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                held.push(socket);
+            }
+        });
+        let mut registry = RdapRegistry::new();
+        registry.override_tld("io", &format!("http://{}/", addr));
+        let checker = DomainChecker::with_config(CheckConfig::builder().rdap_registry(Arc::new(registry)).build().unwrap());
+
+        let start = Instant::now();
+        let result = checker
+            .check_domain_with_timeout("zorviaforge.io", Duration::from_millis(200))
+            .await
+            .unwrap();
+        assert_eq!(result.status, AvailabilityStatus::Unknown);
+        assert!(start.elapsed() < RDAP_REQUEST_TIMEOUT);
+
+        let domains: Vec<String> = ["example.com", "zorviaforge.io"].iter().map(|s| s.to_string()).collect();
+        let results = checker
+            .check_domains(&domains, Some(&[None, Some(Duration::from_millis(200))]))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(start.elapsed() < RDAP_REQUEST_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn test_check_domains_ordered() {
+        let checker = DomainChecker::new();
+        let domains: Vec<String> = ["foo.test", "-bad-.com", "example.com"].iter().map(|s| s.to_string()).collect();
+
+        let checked = checker.check_domains_ordered(&domains).await.unwrap();
+        let names: Vec<&str> = checked.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["foo.test", "-bad-.com", "example.com"]);
+        assert_eq!(checked[0].1.status, AvailabilityStatus::Reserved);
+        assert_eq!(checked[1].1.status, AvailabilityStatus::Error);
+        assert!(checked[1].1.error_message.is_some());
+        assert_eq!(checked[2].1.status, AvailabilityStatus::Reserved);
+    }
+
+    /// Serve every request with the same HTTP response
+    async fn serve(status_line: &'static str, body: &'static str) -> std::net::SocketAddr {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut request = [0u8; 1024];
+                let _ = socket.read(&mut request).await;
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Type: application/rdap+json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status_line,
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        addr
+    }
+
+    fn checker_for(addr: std::net::SocketAddr) -> DomainChecker {
+        let mut registry = RdapRegistry::new();
+        registry.override_tld("io", &format!("http://{}/", addr));
+        DomainChecker::with_config(CheckConfig::builder().rdap_registry(Arc::new(registry)).build().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_check_domain_raw() {
+        let body = r#"{"ldhName":"FORGE.IO","status":["active"],"events":[{"eventAction":"expiration","eventDate":"2031-01-01T00:00:00Z"}]}"#;
+        let checker = checker_for(serve("200 OK", body).await);
+        let (result, record) = checker.check_domain_raw("Forge.io").await.unwrap();
+        assert_eq!(result.domain, "forge.io");
+        assert_eq!(result.status, AvailabilityStatus::Taken);
+        assert_eq!(record["ldhName"], "FORGE.IO");
+        assert_eq!(checker.rdap_body("forge.io").await.unwrap().as_deref(), Some(body));
+
+        let checker = checker_for(serve("404 Not Found", "").await);
+        let (result, record) = checker.check_domain_raw("forge.io").await.unwrap();
+        assert_eq!(result.status, AvailabilityStatus::Available);
+        assert!(record.is_null());
+
+        assert!(checker.check_domain_raw("forge.unknowntld").await.is_err());
     }
 
     #[test]
@@ -680,10 +1582,183 @@ mod tests {
         assert!(crate::rdap::registry::rdap_base_url("com").is_some());
     }
 
+    #[test]
+    fn test_available_tlds() {
+        let tlds = DomainChecker::new().available_tlds();
+        assert!(tlds.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(tlds.iter().any(|tld| tld == "com"));
+        assert!(tlds.len() >= supported_tlds().count());
+
+        let mut registry = RdapRegistry::new();
+        registry.register("forge", "https://rdap.nic.forge/").unwrap();
+        let checker = DomainChecker::with_config(CheckConfig::builder().rdap_registry(Arc::new(registry)).build().unwrap());
+        assert!(checker.available_tlds().contains(&"forge".to_string()));
+
+        let config = CheckConfig { enable_rdap: false, enable_whois: false, ..Default::default() };
+        assert!(DomainChecker::with_config(config).available_tlds().is_empty());
+    }
+
+    #[test]
+    fn test_per_tld_pool_clients() {
+        let config = CheckConfig::builder()
+            .per_tld_pool_size("com", 50)
+            .per_tld_pool_size("net", 20)
+            .per_tld_pool_size(".io", 2)
+            .per_tld_pool_size("notatld", 5)
+            .build()
+            .unwrap();
+        let clients = server_clients(&config);
+
+        // .com and .net use different Verisign base URLs; unknown TLDs are skipped
+        assert_eq!(clients.len(), 3);
+        let io_url = rdap_base_url("io").unwrap();
+        assert!(clients.contains_key(io_url));
+
+        let rdap = RdapClient::new(Client::new()).with_server_clients(clients);
+        assert!(std::ptr::eq(rdap.client_for(io_url), &rdap.server_clients[io_url]));
+        assert!(std::ptr::eq(rdap.client_for(rdap_base_url("xyz").unwrap()), &rdap.client));
+
+        // Pool sizes follow overridden servers
+        let mut registry = RdapRegistry::new();
+        registry.override_tld("io", "http://127.0.0.1:9000/");
+        let config = CheckConfig::builder()
+            .per_tld_pool_size("io", 2)
+            .rdap_registry(Arc::new(registry))
+            .build()
+            .unwrap();
+        assert!(server_clients(&config).contains_key("http://127.0.0.1:9000/"));
+    }
+
+    #[test]
+    fn test_rdap_expired_detection() {
+        let rdap = RdapClient::new(Client::new());
+        let response = |status: &str, expires: &str| -> RdapResponse {
+            serde_json::from_value(serde_json::json!({
+                "status": [status],
+                "events": [{ "eventAction": "expiration", "eventDate": expires }],
+            }))
+            .unwrap()
+        };
+
+        let lapsed = rdap.parse_rdap_response(response("pending delete", "2020-01-01T00:00:00Z"));
+        assert_eq!(lapsed.status, AvailabilityStatus::Expired);
+
+        // Past expiry without a deletion status is usually an auto-renew grace period
+        let grace = rdap.parse_rdap_response(response("active", "2020-01-01T00:00:00Z"));
+        assert_eq!(grace.status, AvailabilityStatus::Taken);
+
+        // Deleted before its expiry date
+        let future = rdap.parse_rdap_response(response("pendingDelete", "2999-01-01T00:00:00Z"));
+        assert_eq!(future.status, AvailabilityStatus::PendingDelete);
+    }
+
+    #[test]
+    fn test_rdap_contacts() {
+        let rdap = RdapClient::new(Client::new());
+        let vcard = |props: serde_json::Value| serde_json::json!(["vcard", props]);
+        let response: RdapResponse = serde_json::from_value(serde_json::json!({
+            "status": ["active"],
+            "entities": [
+                { "roles": ["registrant"],
+                  "vcardArray": vcard(serde_json::json!([
+                      ["version", {}, "text", "4.0"],
+                      ["fn", {}, "text", "REDACTED FOR PRIVACY"],
+                  ])) },
+                { "roles": ["administrative"],
+                  "vcardArray": vcard(serde_json::json!([
+                      ["fn", {}, "text", ""],
+                      ["email", {}, "text", "admin@example.com"],
+                  ])) },
+                { "roles": ["Technical"],
+                  "vcardArray": vcard(serde_json::json!([["fn", {}, "text", "Tech Team"]])) },
+                { "roles": ["registrar"],
+                  "entities": [
+                      { "roles": ["abuse"],
+                        "vcardArray": vcard(serde_json::json!([
+                            ["fn", {}, "text", "Abuse Desk"],
+                            ["email", {}, "text", "abuse@registrar.example"],
+                        ])) },
+                  ] },
+            ],
+        }))
+        .unwrap();
+
+        let result = rdap.parse_rdap_response(response).into_domain_result("forge.com".to_string(), CheckMethod::Rdap, Duration::ZERO);
+        assert_eq!(result.registrant_name, None);
+        assert_eq!(result.admin_contact.as_deref(), Some("admin@example.com"));
+        assert_eq!(result.tech_contact.as_deref(), Some("Tech Team"));
+        assert_eq!(result.abuse_email.as_deref(), Some("abuse@registrar.example"));
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["abuse_email"], "abuse@registrar.example");
+    }
+
+    #[test]
+    fn test_rdap_related_link() {
+        let response: RdapResponse = serde_json::from_value(serde_json::json!({
+            "status": ["active"],
+            "links": [
+                { "rel": "self", "href": "https://rdap.registry.it/domain/example.it" },
+                { "rel": "related", "href": "https://registrar.example/terms", "type": "text/html" },
+                { "rel": "related", "href": "https://rdap.registrar.example/domain/EXAMPLE.it",
+                  "type": "application/rdap+json" },
+            ],
+        }))
+        .unwrap();
+        assert_eq!(
+            response.related_link("example.it").as_deref(),
+            Some("https://rdap.registrar.example/domain/EXAMPLE.it")
+        );
+        assert_eq!(response.related_link("other.it"), None);
+
+        let registry = DomainCheckResult {
+            status: AvailabilityStatus::Taken,
+            registrar: Some("Registry Registrar".to_string()),
+            creation_date: None,
+            expiration_date: Some(Utc::now()),
+            nameservers: vec!["ns1.example.it".to_string()],
+            contacts: RdapContacts::default(),
+        };
+        let registrar = DomainCheckResult {
+            status: AvailabilityStatus::PendingDelete,
+            registrar: Some("Registrar Inc".to_string()),
+            creation_date: None,
+            expiration_date: None,
+            nameservers: Vec::new(),
+            contacts: RdapContacts::default(),
+        };
+        let refined = registry.refined_by(registrar);
+        assert_eq!(refined.status, AvailabilityStatus::PendingDelete);
+        assert_eq!(refined.registrar.as_deref(), Some("Registrar Inc"));
+        assert!(refined.expiration_date.is_some());
+        assert_eq!(refined.nameservers, vec!["ns1.example.it"]);
+    }
+
+    #[test]
+    fn test_sparse_registrar_answer_keeps_registered_status() {
+        let registry = DomainCheckResult {
+            status: AvailabilityStatus::Taken,
+            registrar: None,
+            creation_date: None,
+            expiration_date: None,
+            nameservers: vec!["ns1.example.it".to_string()],
+            contacts: RdapContacts::default(),
+        };
+        let registrar = DomainCheckResult {
+            registrar: Some("Registrar Inc".to_string()),
+            expiration_date: Some(Utc::now()),
+            ..DomainCheckResult::available()
+        };
+        let refined = registry.refined_by(registrar);
+        assert_eq!(refined.status, AvailabilityStatus::Taken);
+        assert_eq!(refined.registrar.as_deref(), Some("Registrar Inc"));
+        assert!(refined.expiration_date.is_some());
+        assert_eq!(refined.nameservers, vec!["ns1.example.it"]);
+    }
+
     #[test]
     fn test_whois_client_creation() {
         // WHOIS is optional and may be disabled at compile time
-        assert!(true);
     }
 
     #[cfg(feature = "whois")]
@@ -709,4 +1784,52 @@ refer: whois.nic.io
 "#;
         assert_eq!(WhoisClient::parse_iana_refer_server(sample).as_deref(), Some("whois.nic.io"));
     }
+
+    #[cfg(feature = "whois")]
+    #[tokio::test]
+    async fn test_whois_cache() {
+        let whois = WhoisClient::new().with_cache(Duration::from_secs(60));
+        let key = ("whois.nic.test".to_string(), "forge.test".to_string());
+        whois.responses.as_ref().unwrap().write().insert(key, ("No match for FORGE.TEST".to_string(), Instant::now()));
+        let raw = whois.query_cached("whois.nic.test", "FORGE.test").await.unwrap();
+        assert_eq!(raw, "No match for FORGE.TEST");
+
+        whois.discovered.write().insert("zz".to_string(), ("whois.nic.zz".to_string(), Instant::now()));
+        assert_eq!(whois.discover_server("zz").await.unwrap().as_deref(), Some("whois.nic.zz"));
+
+        // Entries older than the TTL are ignored
+        assert!(WhoisClient::cached(&whois.discovered, &"zz".to_string(), Duration::ZERO).is_none());
+    }
+
+    #[cfg(feature = "whois")]
+    #[tokio::test]
+    async fn test_whois_batch_grouping() {
+        let whois = WhoisClient::new()
+            .with_cache(Duration::from_secs(60))
+            .with_limits(crate::types::WhoisConfig {
+                per_server_rate_limit_ms: 50,
+                max_connections_per_server: 1,
+            });
+        let responses = [
+            ("whois.verisign-grs.com", "zorvia.com", "No match for \"ZORVIA.COM\"."),
+            ("whois.verisign-grs.com", "forge.com", "Registrar: Example Registrar, Inc."),
+            ("whois.nic.zz", "forge.zz", "Domain not found."),
+        ];
+        for (server, query, raw) in responses {
+            let key = (server.to_string(), query.to_string());
+            whois.responses.as_ref().unwrap().write().insert(key, (raw.to_string(), Instant::now()));
+        }
+        whois.discovered.write().insert("zz".to_string(), ("whois.nic.zz".to_string(), Instant::now()));
+
+        let domains: Vec<String> = ["zorvia.com", "forge.zz", "forge.com"].iter().map(|s| s.to_string()).collect();
+        let start = Instant::now();
+        let results = whois.check_domains(&domains).await;
+        let statuses: Vec<AvailabilityStatus> = results.into_iter().map(|r| r.unwrap().status).collect();
+        assert_eq!(
+            statuses,
+            [AvailabilityStatus::Available, AvailabilityStatus::Available, AvailabilityStatus::Taken]
+        );
+        // The two .com queries share one connection to Verisign and are spaced out
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
 }
\ No newline at end of file
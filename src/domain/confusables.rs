@@ -0,0 +1,81 @@
+//! Unicode look-alikes of ASCII letters (homograph detection)
+//!
+//! The map is a curated subset of the Unicode confusables data covering the
+//! Cyrillic, Greek and Latin-extended letters most often used to spoof brands.
+
+/// Non-ASCII character → the ASCII character it resembles
+pub const CONFUSABLE_MAP: &[(char, char)] = &[
+    // Cyrillic
+    ('а', 'a'), ('в', 'b'), ('е', 'e'), ('ё', 'e'), ('һ', 'h'), ('і', 'i'), ('ї', 'i'),
+    ('ј', 'j'), ('к', 'k'), ('м', 'm'), ('н', 'h'), ('о', 'o'), ('р', 'p'), ('с', 'c'),
+    ('т', 't'), ('у', 'y'), ('х', 'x'), ('ѕ', 's'), ('ԁ', 'd'), ('ԛ', 'q'), ('ԝ', 'w'),
+    ('ɡ', 'g'), ('ӏ', 'l'),
+    // Greek
+    ('α', 'a'), ('β', 'b'), ('ε', 'e'), ('ι', 'i'), ('κ', 'k'), ('ν', 'v'), ('ο', 'o'),
+    ('ρ', 'p'), ('τ', 't'), ('υ', 'u'), ('χ', 'x'), ('γ', 'y'),
+    // Latin look-alikes
+    ('ı', 'i'), ('ȷ', 'j'), ('ℓ', 'l'), ('ǀ', 'l'), ('ɑ', 'a'), ('ɩ', 'i'), ('ʋ', 'u'),
+    ('ᴅ', 'd'), ('ⅰ', 'i'), ('ⅼ', 'l'), ('ⅽ', 'c'), ('ⅾ', 'd'), ('ⅿ', 'm'),
+];
+
+/// A suspicious character found in a domain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfusableChar {
+    /// Character index within the Unicode form of the domain
+    pub position: usize,
+    pub found: char,
+    pub looks_like: char,
+}
+
+impl std::fmt::Display for ConfusableChar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "'{}' (U+{:04X}) at position {} looks like '{}'",
+            self.found, self.found as u32, self.position, self.looks_like
+        )
+    }
+}
+
+/// ASCII look-alike of `c`, if it is a known confusable
+pub fn lookalike(c: char) -> Option<char> {
+    CONFUSABLE_MAP
+        .iter()
+        .find(|(confusable, _)| *confusable == c)
+        .map(|(_, ascii)| *ascii)
+}
+
+/// Confusable characters in `domain`; Punycode labels are decoded first
+pub fn detect(domain: &str) -> Vec<ConfusableChar> {
+    let (unicode, _) = idna::domain_to_unicode(domain);
+    unicode
+        .chars()
+        .enumerate()
+        .filter_map(|(position, found)| {
+            lookalike(found).map(|looks_like| ConfusableChar {
+                position,
+                found,
+                looks_like,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_confusables() {
+        // Cyrillic 'а' and 'р' in "аpple"/"рaypal"
+        let found = detect("\u{0430}pple.com");
+        assert_eq!(found, vec![ConfusableChar { position: 0, found: 'а', looks_like: 'a' }]);
+
+        let ace = idna::domain_to_ascii("\u{0440}aypal.com").unwrap();
+        assert_eq!(detect(&ace).len(), 1);
+
+        assert!(detect("apple.com").is_empty());
+        assert!(detect("münchen.de").is_empty());
+        assert!(CONFUSABLE_MAP.iter().all(|(c, ascii)| !c.is_ascii() && ascii.is_ascii_lowercase()));
+    }
+}
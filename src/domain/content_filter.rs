@@ -0,0 +1,94 @@
+//! Blocked-word filter for generated domain names
+//!
+//! This is a best-effort heuristic: it only catches names containing one of
+//! the listed substrings and will miss misspellings, while occasionally
+//! dropping an innocent name that happens to contain a blocked word.
+
+use crate::types::DomainSuggestion;
+use serde::{Deserialize, Serialize};
+
+/// Bundled list of obviously offensive substrings
+pub const DEFAULT_BLOCKED_WORDS: &[&str] = &[
+    "fuck", "shit", "cunt", "bitch", "whore", "slut", "porn", "nazi", "rape", "nigger", "faggot",
+];
+
+/// Drops suggestions whose name contains a blocked substring (case-insensitive)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentFilter {
+    blocked: Vec<String>,
+}
+
+impl ContentFilter {
+    /// Create with the bundled default list
+    pub fn new() -> Self {
+        Self {
+            blocked: DEFAULT_BLOCKED_WORDS.iter().map(|w| w.to_string()).collect(),
+        }
+    }
+
+    /// Block additional words, e.g. competitor brand names
+    pub fn with_extra_words(mut self, words: Vec<String>) -> Self {
+        for word in words {
+            let word = word.trim().to_lowercase();
+            if !word.is_empty() && !self.blocked.contains(&word) {
+                self.blocked.push(word);
+            }
+        }
+        self
+    }
+
+    /// The first blocked word contained in `name`, if any
+    pub fn blocked_word(&self, name: &str) -> Option<&str> {
+        let name = name.to_lowercase();
+        self.blocked
+            .iter()
+            .find(|word| name.contains(word.as_str()))
+            .map(String::as_str)
+    }
+
+    /// Whether `name` passes the filter
+    pub fn is_allowed(&self, name: &str) -> bool {
+        self.blocked_word(name).is_none()
+    }
+
+    /// Remove suggestions containing a blocked word
+    pub fn filter(&self, suggestions: Vec<DomainSuggestion>) -> Vec<DomainSuggestion> {
+        suggestions
+            .into_iter()
+            .filter(|suggestion| match self.blocked_word(&suggestion.name) {
+                Some(word) => {
+                    tracing::debug!(domain = %suggestion.get_full_domain(), blocked = %word, "Content filter dropped suggestion");
+                    false
+                }
+                None => true,
+            })
+            .collect()
+    }
+}
+
+impl Default for ContentFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_is_case_insensitive() {
+        let filter = ContentFilter::new().with_extra_words(vec!["Google".to_string()]);
+        let suggestions = vec![
+            DomainSuggestion::new("forge", "com", 0.9, None::<String>),
+            DomainSuggestion::new("PornHub", "com", 0.9, None::<String>),
+            DomainSuggestion::new("googleish", "io", 0.8, None::<String>),
+        ];
+
+        let kept = filter.filter(suggestions);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].name, "forge");
+        assert_eq!(filter.blocked_word("MyGoogleApp"), Some("google"));
+        assert!(ContentFilter::new().is_allowed("googleish"));
+    }
+}
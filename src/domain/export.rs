@@ -0,0 +1,479 @@
+//! Export generation sessions and snipe scans as text, JSON, CSV, Markdown,
+//! HTML or YAML
+//!
+//! Every format implements [`Exporter`]; [`ExporterFactory::for_extension`]
+//! picks one from a file extension or `--format` value.
+
+use crate::domain::report;
+use crate::error::{DomainForgeError, Result};
+use crate::snipe::export::SnipeCsvExporter;
+use crate::snipe::ScanState;
+use crate::types::{DomainResult, DomainSession, JsonOutput};
+use chrono::Utc;
+use std::io::Write;
+use std::path::Path;
+
+/// Writes sessions and scans in one file format
+pub trait Exporter {
+    /// Write a generation session
+    fn export(&self, session: &DomainSession, writer: &mut dyn Write) -> Result<()>;
+
+    /// Write the domains found by a snipe scan
+    fn export_scan(&self, state: &ScanState, writer: &mut dyn Write) -> Result<()>;
+
+    /// Save a session to a file
+    fn save(&self, session: &DomainSession, path: &Path) -> Result<()> {
+        let mut writer = create(path)?;
+        self.export(session, &mut writer)?;
+        writer.flush().map_err(|e| io_error(e, path))
+    }
+
+    /// Save a scan to a file
+    fn save_scan(&self, state: &ScanState, path: &Path) -> Result<()> {
+        let mut writer = create(path)?;
+        self.export_scan(state, &mut writer)?;
+        writer.flush().map_err(|e| io_error(e, path))
+    }
+}
+
+/// Picks an [`Exporter`] by file extension
+pub struct ExporterFactory;
+
+impl ExporterFactory {
+    /// Extensions accepted by [`for_extension`](Self::for_extension)
+    pub const EXTENSIONS: &'static [&'static str] =
+        &["txt", "text", "json", "csv", "md", "markdown", "html", "htm", "yaml", "yml"];
+
+    /// Exporter for an extension such as `csv` or `.yml` (case-insensitive).
+    ///
+    /// CSV output starts with a byte order mark on Windows, for Excel.
+    pub fn for_extension(ext: &str) -> Result<Box<dyn Exporter>> {
+        let ext = ext.trim().trim_start_matches('.').to_lowercase();
+        let exporter: Box<dyn Exporter> = match ext.as_str() {
+            "txt" | "text" => Box::new(TextExporter),
+            "json" => Box::new(JsonExporter::new()),
+            "csv" => Box::new(CsvExporter::new().with_bom(cfg!(windows))),
+            "md" | "markdown" => Box::new(MarkdownExporter::new()),
+            "html" | "htm" => Box::new(MarkdownExporter::new().with_html(true)),
+            "yaml" | "yml" => Box::new(YamlExporter),
+            _ => {
+                return Err(DomainForgeError::validation(format!(
+                    "Unsupported export format '{}' (expected one of: {})",
+                    ext,
+                    Self::EXTENSIONS.join(", ")
+                )))
+            }
+        };
+        Ok(exporter)
+    }
+}
+
+fn io_error(e: std::io::Error, path: &Path) -> DomainForgeError {
+    DomainForgeError::io(e.to_string(), Some(path.display().to_string()))
+}
+
+fn create(path: &Path) -> Result<std::io::BufWriter<std::fs::File>> {
+    std::fs::File::create(path)
+        .map(std::io::BufWriter::new)
+        .map_err(|e| io_error(e, path))
+}
+
+fn write_str(writer: &mut dyn Write, content: &str) -> Result<()> {
+    writer
+        .write_all(content.as_bytes())
+        .map_err(|e| DomainForgeError::io(e.to_string(), None))
+}
+
+/// Plain-text report: one domain per line under a heading per category
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextExporter;
+
+impl Exporter for TextExporter {
+    fn export(&self, session: &DomainSession, writer: &mut dyn Write) -> Result<()> {
+        let mut content = String::new();
+        content.push_str("Domain Forge Results\n");
+        content.push_str(&format!("Generated: {}\n", Utc::now().format("%Y-%m-%d %H:%M:%S UTC")));
+        content.push_str(&format!("Search: {}\n", session.description.as_deref().unwrap_or("")));
+        content.push_str(&format!("Rounds: {}\n", session.round_count));
+        content.push_str(&format!("Total Time: {:.1}s\n", session.total_time.as_secs_f32()));
+        content.push_str(&format!("Total Checked: {}\n\n", session.total_domains_checked()));
+
+        content.push_str(&format!("=== AVAILABLE DOMAINS ({}) ===\n", session.available_domains.len()));
+        if session.available_domains.is_empty() {
+            content.push_str("None found.\n");
+        } else {
+            for domain in &session.available_domains {
+                content.push_str(&format!("{}\n", domain.get_full_domain()));
+            }
+        }
+
+        content.push_str(&format!("\n=== TAKEN DOMAINS ({}) ===\n", session.taken_domains.len()));
+        for domain in &session.taken_domains {
+            content.push_str(&format!("{}\n", domain));
+        }
+
+        if !session.error_domains.is_empty() {
+            content.push_str(&format!("\n=== ERRORS ({}) ===\n", session.error_domains.len()));
+            for (domain, error) in &session.error_domains {
+                content.push_str(&format!("{}: {}\n", domain, error));
+            }
+        }
+
+        write_str(writer, &content)
+    }
+
+    fn export_scan(&self, state: &ScanState, writer: &mut dyn Write) -> Result<()> {
+        let mut content = String::new();
+        if !state.tags.is_empty() {
+            content.push_str(&format!("Tags: {}\n\n", state.tags.join(", ")));
+        }
+        for (title, domains) in [
+            ("AVAILABLE", &state.available),
+            ("PENDING DELETE", &state.pending_delete),
+            ("EXPIRING SOON", &state.expiring_soon),
+            ("EXPIRED", &state.expired),
+        ] {
+            content.push_str(&format!("=== {} ({}) ===\n", title, domains.len()));
+            for domain in domains {
+                content.push_str(&format!("{}\n", domain.full_domain));
+            }
+            content.push('\n');
+        }
+        write_str(writer, &content)
+    }
+}
+
+/// JSON: [`JsonOutput`] for sessions, the full state for scans
+#[derive(Debug, Clone, Copy)]
+pub struct JsonExporter {
+    pretty: bool,
+}
+
+impl JsonExporter {
+    /// Create a pretty-printing exporter
+    pub fn new() -> Self {
+        Self { pretty: true }
+    }
+
+    /// Indent output (the default) or write it on one line
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    fn write<T: serde::Serialize>(&self, value: &T, writer: &mut dyn Write) -> Result<()> {
+        if self.pretty {
+            serde_json::to_writer_pretty(writer, value)?;
+        } else {
+            serde_json::to_writer(writer, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for JsonExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Exporter for JsonExporter {
+    fn export(&self, session: &DomainSession, writer: &mut dyn Write) -> Result<()> {
+        let output = JsonOutput::from_session(session, session.description.as_deref().unwrap_or(""));
+        self.write(&output, writer)
+    }
+
+    fn export_scan(&self, state: &ScanState, writer: &mut dyn Write) -> Result<()> {
+        self.write(state, writer)
+    }
+}
+
+/// Markdown report, or the same report as a standalone HTML page
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarkdownExporter {
+    html: bool,
+}
+
+impl MarkdownExporter {
+    /// Create a Markdown exporter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render the report as HTML instead
+    pub fn with_html(mut self, html: bool) -> Self {
+        self.html = html;
+        self
+    }
+}
+
+impl Exporter for MarkdownExporter {
+    fn export(&self, session: &DomainSession, writer: &mut dyn Write) -> Result<()> {
+        let content = if self.html {
+            report::session_html(session)
+        } else {
+            report::session_markdown(session)
+        };
+        write_str(writer, &content)
+    }
+
+    fn export_scan(&self, state: &ScanState, writer: &mut dyn Write) -> Result<()> {
+        let content = if self.html {
+            report::scan_html(state)
+        } else {
+            report::scan_markdown(state)
+        };
+        write_str(writer, &content)
+    }
+}
+
+/// YAML with the same fields as [`JsonExporter`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct YamlExporter;
+
+impl YamlExporter {
+    fn write<T: serde::Serialize>(value: &T, writer: &mut dyn Write) -> Result<()> {
+        serde_yaml::to_writer(writer, value)
+            .map_err(|e| DomainForgeError::internal(format!("Failed to write YAML: {}", e)))
+    }
+}
+
+impl Exporter for YamlExporter {
+    fn export(&self, session: &DomainSession, writer: &mut dyn Write) -> Result<()> {
+        let output = JsonOutput::from_session(session, session.description.as_deref().unwrap_or(""));
+        Self::write(&output, writer)
+    }
+
+    fn export_scan(&self, state: &ScanState, writer: &mut dyn Write) -> Result<()> {
+        Self::write(state, writer)
+    }
+}
+
+/// Column header shared by all CSV exports
+pub const CSV_HEADER: [&str; 7] = [
+    "domain",
+    "tld",
+    "status",
+    "registrar",
+    "expiration_date",
+    "days_until_expiry",
+    "found_at",
+];
+
+/// UTF-8 byte order mark, lets Excel detect the encoding
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// CSV writer for domain results
+#[derive(Debug, Clone)]
+pub struct CsvExporter {
+    bom: bool,
+    header: bool,
+    /// Columns appended after [`CSV_HEADER`]
+    extra_columns: Vec<&'static str>,
+}
+
+impl CsvExporter {
+    /// Create an exporter with a header row and no byte order mark
+    pub fn new() -> Self {
+        Self {
+            bom: false,
+            header: true,
+            extra_columns: Vec::new(),
+        }
+    }
+
+    /// Prefix output with a UTF-8 BOM (for Excel on Windows)
+    pub fn with_bom(mut self, bom: bool) -> Self {
+        self.bom = bom;
+        self
+    }
+
+    /// Write the header row (the default), or only data rows for appending
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Append columns to the standard header (rows must supply them)
+    pub fn with_extra_columns(mut self, columns: &[&'static str]) -> Self {
+        self.extra_columns = columns.to_vec();
+        self
+    }
+
+    /// Write pre-built rows under the standard header
+    pub fn write_rows<W, I>(&self, mut writer: W, rows: I) -> Result<()>
+    where
+        W: Write,
+        I: IntoIterator<Item = Vec<String>>,
+    {
+        if self.bom {
+            writer
+                .write_all(UTF8_BOM)
+                .map_err(|e| DomainForgeError::io(e.to_string(), None))?;
+        }
+
+        let mut csv = csv::Writer::from_writer(writer);
+        let csv_error = |e: csv::Error| DomainForgeError::io(format!("Failed to write CSV: {}", e), None);
+
+        if self.header {
+            csv.write_record(CSV_HEADER.iter().chain(&self.extra_columns)).map_err(csv_error)?;
+        }
+        for row in rows {
+            csv.write_record(&row).map_err(csv_error)?;
+        }
+        csv.flush()
+            .map_err(|e| DomainForgeError::io(e.to_string(), None))
+    }
+
+    /// Write domain results as CSV
+    pub fn write<W: Write>(&self, writer: W, results: &[DomainResult]) -> Result<()> {
+        self.write_rows(writer, results.iter().map(DomainResult::to_csv_row))
+    }
+
+    /// Save pre-built rows to a CSV file
+    pub fn save_rows<I>(&self, path: &Path, rows: I) -> Result<()>
+    where
+        I: IntoIterator<Item = Vec<String>>,
+    {
+        let file = std::fs::File::create(path)
+            .map_err(|e| DomainForgeError::io(e.to_string(), Some(path.display().to_string())))?;
+        self.write_rows(std::io::BufWriter::new(file), rows)
+    }
+
+    /// Save domain results to a CSV file
+    pub fn save(&self, path: &Path, results: &[DomainResult]) -> Result<()> {
+        self.save_rows(path, results.iter().map(DomainResult::to_csv_row))
+    }
+}
+
+impl Default for CsvExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sessions keep no RDAP details, so their rows carry only domain and status
+impl Exporter for CsvExporter {
+    fn export(&self, session: &DomainSession, writer: &mut dyn Write) -> Result<()> {
+        let row = |domain: String, status: &str| {
+            let tld = domain.rsplit_once('.').map(|(_, tld)| tld.to_string()).unwrap_or_default();
+            let mut row = vec![domain, tld, status.to_string()];
+            row.resize(CSV_HEADER.len() + self.extra_columns.len(), String::new());
+            row
+        };
+        let mut taken: Vec<&String> = session.taken_domains.iter().collect();
+        taken.sort();
+
+        let rows = session
+            .available_domains
+            .iter()
+            .map(|domain| row(domain.get_full_domain(), "available"))
+            .chain(taken.into_iter().map(|domain| row(domain.clone(), "taken")))
+            .chain(session.error_domains.iter().map(|(domain, _)| row(domain.clone(), "error")));
+        self.write_rows(writer, rows)
+    }
+
+    fn export_scan(&self, state: &ScanState, writer: &mut dyn Write) -> Result<()> {
+        SnipeCsvExporter::from_csv(self.clone()).write(writer, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AvailabilityStatus, CheckMethod, DomainSuggestion};
+    use std::time::Duration;
+
+    fn result(domain: &str, registrar: Option<&str>) -> DomainResult {
+        DomainResult {
+            registrar: registrar.map(str::to_string),
+            ..DomainResult::new(domain, AvailabilityStatus::Taken, CheckMethod::Rdap)
+        }
+    }
+
+    #[test]
+    fn test_csv_quotes_commas() {
+        let mut out = Vec::new();
+        CsvExporter::new()
+            .write(&mut out, &[result("example.com", Some("Registrar, Inc."))])
+            .unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let mut lines = text.lines();
+        assert_eq!(lines.next().unwrap(), CSV_HEADER.join(","));
+        assert!(lines.next().unwrap().starts_with("example.com,com,taken,\"Registrar, Inc.\","));
+    }
+
+    #[test]
+    fn test_csv_bom() {
+        let mut out = Vec::new();
+        CsvExporter::new().with_bom(true).write(&mut out, &[]).unwrap();
+        assert!(out.starts_with(UTF8_BOM));
+
+        let mut out = Vec::new();
+        CsvExporter::new().with_header(false).write(&mut out, &[]).unwrap();
+        assert!(out.is_empty());
+    }
+
+    fn session() -> DomainSession {
+        let mut available = result("forge.com", None);
+        available.status = AvailabilityStatus::Available;
+        let domains = vec![
+            DomainSuggestion::new("forge", "com", 0.9, None::<String>),
+            DomainSuggestion::new("smith", "io", 0.7, None::<String>),
+        ];
+        let mut session = DomainSession::new();
+        session.description = Some("metalworking".to_string());
+        session.add_round_results(&domains, &[available, result("smith.io", None)], Duration::from_secs(1));
+        session
+    }
+
+    fn export(ext: &str, session: &DomainSession) -> String {
+        let mut out = Vec::new();
+        ExporterFactory::for_extension(ext).unwrap().export(session, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_session_formats() {
+        let session = session();
+
+        let text = export("txt", &session);
+        assert!(text.contains("Search: metalworking"));
+        assert!(text.contains("=== AVAILABLE DOMAINS (1) ===\nforge.com"));
+
+        let json: serde_json::Value = serde_json::from_str(&export("JSON", &session)).unwrap();
+        assert_eq!(json["description"], "metalworking");
+        assert_eq!(json["taken"][0], "smith.io");
+
+        let csv = export(".csv", &session);
+        let lines: Vec<&str> = csv.trim_start_matches('\u{feff}').lines().collect();
+        assert_eq!(lines, vec![CSV_HEADER.join(","), "forge.com,com,available,,,,".to_string(), "smith.io,io,taken,,,,".to_string()]);
+
+        let yaml: serde_json::Value = serde_yaml::from_str(&export("yml", &session)).unwrap();
+        assert_eq!(yaml["available"][0]["name"], "forge");
+
+        assert!(export("md", &session).starts_with("# Domain Forge Session"));
+        assert!(export("html", &session).starts_with("<!DOCTYPE html>"));
+        assert!(ExporterFactory::for_extension("xlsx").is_err());
+    }
+
+    #[test]
+    fn test_scan_formats() {
+        let mut state = ScanState::new(4, vec!["com".to_string()], 100);
+        state.tags = vec!["q3".to_string()];
+        let exported = |ext: &str| {
+            let mut out = Vec::new();
+            ExporterFactory::for_extension(ext).unwrap().export_scan(&state, &mut out).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        assert!(exported("txt").starts_with("Tags: q3\n\n=== AVAILABLE (0) ==="));
+        assert!(exported("csv").trim_start_matches('\u{feff}').starts_with(&format!("{},tags", CSV_HEADER.join(","))));
+        let loaded: ScanState = serde_yaml::from_str(&exported("yaml")).unwrap();
+        assert_eq!(loaded.scan_id, state.scan_id);
+        let loaded: ScanState = serde_json::from_str(&exported("json")).unwrap();
+        assert_eq!(loaded.total_combinations, 100);
+        assert!(exported("md").contains("## Available Domains (0)"));
+    }
+}
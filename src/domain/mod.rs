@@ -1,10 +1,27 @@
 //! Domain availability checking module
 
+mod adaptive;
+mod cache;
 pub mod checker;
+pub mod confusables;
+pub mod content_filter;
+pub mod export;
+pub mod phonetic;
+pub mod report;
+pub mod reserved;
+pub mod scoring;
+pub mod tld_presets;
+pub mod utils;
 pub mod validator;
 
 // Re-export main functionality
 pub use checker::DomainChecker;
+pub use confusables::ConfusableChar;
+pub use content_filter::ContentFilter;
+pub use export::{CsvExporter, Exporter, ExporterFactory, JsonExporter, MarkdownExporter, TextExporter, YamlExporter};
+pub use reserved::ReservedNames;
+pub use scoring::ScoreBreakdown;
+pub use tld_presets::{get_tld_presets, COUNTRY_TLDS, ENTERPRISE_TLDS, POPULAR_TLDS, STARTUP_TLDS};
 pub use validator::DomainValidator;
 
 use crate::error::Result;
@@ -24,35 +41,12 @@ pub trait DomainCheckMethod: Send + Sync {
     fn supports_tld(&self, tld: &str) -> bool;
 }
 
-/// Common TLD lists
-pub const POPULAR_TLDS: &[&str] = &[
-    "com", "org", "net", "io", "ai", "co", "me", "app", "dev", "tech", "xyz"
-];
-
-pub const STARTUP_TLDS: &[&str] = &[
-    "com", "org", "io", "ai", "tech", "app", "dev", "xyz"
-];
-
-pub const ENTERPRISE_TLDS: &[&str] = &[
-    "com", "org", "net", "biz", "info", "us"
-];
-
-pub const COUNTRY_TLDS: &[&str] = &[
-    "us", "uk", "de", "fr", "ca", "au", "jp", "br", "in"
-];
-
-/// Get TLD list by name
+/// Get TLD list by preset name (see [`tld_presets`])
 pub fn get_tld_list(name: &str) -> Option<Vec<String>> {
-    match name.to_lowercase().as_str() {
-        "popular" => Some(POPULAR_TLDS.iter().map(|s| s.to_string()).collect()),
-        "startup" => Some(STARTUP_TLDS.iter().map(|s| s.to_string()).collect()),
-        "enterprise" => Some(ENTERPRISE_TLDS.iter().map(|s| s.to_string()).collect()),
-        "country" => Some(COUNTRY_TLDS.iter().map(|s| s.to_string()).collect()),
-        _ => None,
-    }
+    tld_presets::preset(name).map(|tlds| tlds.iter().map(|s| s.to_string()).collect())
 }
 
 /// Get all available TLD list names
 pub fn get_tld_list_names() -> Vec<&'static str> {
-    vec!["popular", "startup", "enterprise", "country"]
-}
\ No newline at end of file
+    tld_presets::preset_names()
+}
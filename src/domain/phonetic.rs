@@ -0,0 +1,311 @@
+//! Phonetic encodings for comparing how domain names sound
+//!
+//! Implements American Soundex and the original Metaphone algorithm. Both
+//! only look at ASCII letters; digits and hyphens are ignored.
+
+/// Soundex digit for a consonant, `None` for vowels and H/W/Y
+fn soundex_digit(c: char) -> Option<char> {
+    match c {
+        'B' | 'F' | 'P' | 'V' => Some('1'),
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+        'D' | 'T' => Some('3'),
+        'L' => Some('4'),
+        'M' | 'N' => Some('5'),
+        'R' => Some('6'),
+        _ => None,
+    }
+}
+
+fn ascii_letters(name: &str) -> Vec<char> {
+    name.chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// American Soundex code, e.g. `S530` for both "Smith" and "Smyth".
+///
+/// Returns an empty string when the name contains no letters.
+pub fn to_soundex(name: &str) -> String {
+    let letters = ascii_letters(name);
+    let Some(&first) = letters.first() else {
+        return String::new();
+    };
+
+    let mut code = String::from(first);
+    let mut previous = soundex_digit(first);
+    for &c in &letters[1..] {
+        match c {
+            // H and W don't separate letters with the same code
+            'H' | 'W' => continue,
+            _ => {
+                let digit = soundex_digit(c);
+                if let Some(d) = digit.filter(|_| digit != previous) {
+                    code.push(d);
+                    if code.len() == 4 {
+                        break;
+                    }
+                }
+                previous = digit;
+            }
+        }
+    }
+
+    while code.len() < 4 {
+        code.push('0');
+    }
+    code
+}
+
+fn is_vowel(c: Option<&char>) -> bool {
+    matches!(c, Some('A' | 'E' | 'I' | 'O' | 'U'))
+}
+
+fn is_front_vowel(c: Option<&char>) -> bool {
+    matches!(c, Some('E' | 'I' | 'Y'))
+}
+
+/// Metaphone key, e.g. `SM0` for "Smith" (`0` stands for "th").
+///
+/// Returns an empty string when the name contains no letters.
+pub fn to_metaphone(name: &str) -> String {
+    let mut letters = ascii_letters(name);
+    if letters.is_empty() {
+        return String::new();
+    }
+
+    // Initial letter exceptions
+    match (letters[0], letters.get(1)) {
+        ('A', Some('E')) | ('G' | 'K' | 'P', Some('N')) | ('W', Some('R')) => {
+            letters.remove(0);
+        }
+        ('X', _) => letters[0] = 'S',
+        ('W', Some('H')) => {
+            letters.remove(1);
+        }
+        _ => {}
+    }
+
+    let mut key = String::new();
+    for i in 0..letters.len() {
+        let c = letters[i];
+        let prev = i.checked_sub(1).map(|p| letters[p]);
+        let next = letters.get(i + 1);
+        let after_next = letters.get(i + 2);
+
+        // Doubled letters count once, except C
+        if prev == Some(c) && c != 'C' {
+            continue;
+        }
+
+        match c {
+            'A' | 'E' | 'I' | 'O' | 'U' => {
+                if i == 0 {
+                    key.push(c);
+                }
+            }
+            'B' => {
+                // Silent in a trailing "MB"
+                if !(prev == Some('M') && next.is_none()) {
+                    key.push('B');
+                }
+            }
+            'C' => {
+                if next == Some(&'I') && after_next == Some(&'A') {
+                    key.push('X');
+                } else if next == Some(&'H') {
+                    key.push(if prev == Some('S') { 'K' } else { 'X' });
+                } else if is_front_vowel(next) {
+                    if prev != Some('S') {
+                        key.push('S');
+                    }
+                } else {
+                    key.push('K');
+                }
+            }
+            'D' => {
+                if next == Some(&'G') && is_front_vowel(after_next) {
+                    key.push('J');
+                } else {
+                    key.push('T');
+                }
+            }
+            'G' => {
+                let silent_gh = next == Some(&'H') && !is_vowel(after_next);
+                let silent_gn = next == Some(&'N') && (after_next.is_none() || letters[i + 2..] == ['E', 'D']);
+                if silent_gh || silent_gn || prev == Some('D') && is_front_vowel(next) {
+                    continue;
+                }
+                key.push(if is_front_vowel(next) { 'J' } else { 'K' });
+            }
+            'H' => {
+                let after_modifier = matches!(prev, Some('C' | 'S' | 'P' | 'T' | 'G'));
+                if is_vowel(next) && !after_modifier {
+                    key.push('H');
+                }
+            }
+            'K' => {
+                if prev != Some('C') {
+                    key.push('K');
+                }
+            }
+            'P' => key.push(if next == Some(&'H') { 'F' } else { 'P' }),
+            'Q' => key.push('K'),
+            'S' => {
+                if next == Some(&'H') || (next == Some(&'I') && matches!(after_next, Some('O' | 'A'))) {
+                    key.push('X');
+                } else {
+                    key.push('S');
+                }
+            }
+            'T' => {
+                if next == Some(&'I') && matches!(after_next, Some('O' | 'A')) {
+                    key.push('X');
+                } else if next == Some(&'H') {
+                    key.push('0');
+                } else if !(next == Some(&'C') && after_next == Some(&'H')) {
+                    key.push('T');
+                }
+            }
+            'V' => key.push('F'),
+            'W' | 'Y' => {
+                if is_vowel(next) {
+                    key.push(c);
+                }
+            }
+            'X' => key.push_str("KS"),
+            'Z' => key.push('S'),
+            _ => key.push(c),
+        }
+    }
+    key
+}
+
+/// Edit distance between two ASCII strings
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// How alike two names sound, from 0.0 (nothing in common) to 1.0.
+///
+/// Averages position-wise agreement of the Soundex codes with the
+/// normalised edit-distance similarity of the Metaphone keys.
+pub fn phonetic_similarity(a: &str, b: &str) -> f32 {
+    let (soundex_a, soundex_b) = (to_soundex(a), to_soundex(b));
+    if soundex_a.is_empty() || soundex_b.is_empty() {
+        return 0.0;
+    }
+    let soundex_score = soundex_a.chars().zip(soundex_b.chars()).filter(|(x, y)| x == y).count() as f32 / 4.0;
+
+    let (metaphone_a, metaphone_b) = (to_metaphone(a), to_metaphone(b));
+    let longest = metaphone_a.len().max(metaphone_b.len());
+    let metaphone_score = if longest == 0 {
+        1.0
+    } else {
+        1.0 - levenshtein(&metaphone_a, &metaphone_b) as f32 / longest as f32
+    };
+
+    (soundex_score + metaphone_score) / 2.0
+}
+
+/// Rough estimate of how easy a name is to say, from 0.0 to 1.0.
+///
+/// Letters inside runs of more than two consonants or two vowels count as
+/// awkward, as do digits and hyphens. `y` counts as a vowel after a
+/// consonant and as a consonant otherwise. A name without any vowel scores 0.0.
+pub fn pronounceability(name: &str) -> f32 {
+    let chars: Vec<char> = name.chars().map(|c| c.to_ascii_lowercase()).collect();
+    if chars.is_empty() || !chars.iter().any(|c| "aeiouy".contains(*c)) {
+        return 0.0;
+    }
+
+    let mut awkward = chars.iter().filter(|c| !c.is_ascii_alphabetic()).count();
+    // (is_vowel, length) of the current run of letters
+    let mut run: Option<(bool, usize)> = None;
+    for &c in &chars {
+        let vowel = match c {
+            'a' | 'e' | 'i' | 'o' | 'u' => true,
+            'y' => matches!(run, Some((false, _))),
+            c if c.is_ascii_alphabetic() => false,
+            _ => {
+                run = None;
+                continue;
+            }
+        };
+        run = match run {
+            Some((kind, len)) if kind == vowel => Some((kind, len + 1)),
+            _ => Some((vowel, 1)),
+        };
+        if matches!(run, Some((_, len)) if len > 2) {
+            awkward += 1;
+        }
+    }
+
+    1.0 - awkward as f32 / chars.len() as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_soundex_classic_cases() {
+        assert_eq!(to_soundex("Smith"), "S530");
+        assert_eq!(to_soundex("Smyth"), "S530");
+        assert_eq!(to_soundex("Robert"), "R163");
+        assert_eq!(to_soundex("Rupert"), "R163");
+        assert_eq!(to_soundex("Ashcraft"), "A261");
+        assert_eq!(to_soundex("Tymczak"), "T522");
+        assert_eq!(to_soundex("Pfister"), "P236");
+        assert_eq!(to_soundex("Lee"), "L000");
+        assert_eq!(to_soundex("123"), "");
+    }
+
+    #[test]
+    fn test_metaphone() {
+        assert_eq!(to_metaphone("Smith"), "SM0");
+        assert_eq!(to_metaphone("Smyth"), "SM0");
+        assert_eq!(to_metaphone("knight"), "NT");
+        assert_eq!(to_metaphone("phone"), "FN");
+        assert_eq!(to_metaphone("Xerox"), "SRKS");
+        assert_eq!(to_metaphone("school"), "SKL");
+        assert_eq!(to_metaphone("lamb"), "LM");
+    }
+
+    #[test]
+    fn test_phonetic_similarity() {
+        assert_eq!(phonetic_similarity("Smith", "Smyth"), 1.0);
+        assert!(phonetic_similarity("fone", "phone") > 0.8);
+
+        let close = phonetic_similarity("forge", "forj");
+        let far = phonetic_similarity("forge", "zebra");
+        assert!(close > 0.8, "{}", close);
+        assert!(far < 0.4, "{}", far);
+        assert!(close > phonetic_similarity("forge", "fortune"));
+
+        assert_eq!(phonetic_similarity("", "forge"), 0.0);
+    }
+
+    #[test]
+    fn test_pronounceability() {
+        assert_eq!(pronounceability("forge"), 1.0);
+        assert_eq!(pronounceability("banana"), 1.0);
+        assert_eq!(pronounceability("yoga"), 1.0);
+        assert!(pronounceability("rhythm") > 0.8);
+        assert_eq!(pronounceability("xkcd"), 0.0);
+        assert!(pronounceability("strngth") < 0.5);
+        assert!(pronounceability("forge-4u") < pronounceability("forgeu"));
+        assert!(pronounceability("zapier") > pronounceability("zxqvier"));
+    }
+}
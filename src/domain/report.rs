@@ -0,0 +1,239 @@
+//! Shareable Markdown and HTML reports for generation sessions and snipe scans
+
+use chrono::Utc;
+use pulldown_cmark::{html, Options, Parser};
+
+use crate::snipe::{ScanState, SnipedDomain};
+use crate::types::DomainSession;
+
+const HTML_STYLE: &str = "\
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; max-width: 960px; margin: 2rem auto; padding: 0 1rem; color: #24292f; line-height: 1.5; }
+h1, h2 { border-bottom: 1px solid #d0d7de; padding-bottom: 0.3rem; }
+table { border-collapse: collapse; width: 100%; margin: 1rem 0; }
+th, td { border: 1px solid #d0d7de; padding: 6px 12px; text-align: left; }
+th { background: #f6f8fa; }
+tr:nth-child(even) td { background: #fafbfc; }";
+
+/// Make text safe inside a Markdown table cell
+fn cell(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '|' | '<' | '>' | '*' | '_' | '`' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\r' | '\n' => escaped.push(' '),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Render a session as Markdown, available domains best score first
+pub fn session_markdown(session: &DomainSession) -> String {
+    let mut md = String::from("# Domain Forge Session\n\n");
+    if let Some(description) = &session.description {
+        md.push_str(&format!("- **Description:** {}\n", cell(description)));
+    }
+    md.push_str(&format!("- **Date:** {}\n", Utc::now().format("%Y-%m-%d %H:%M UTC")));
+    md.push_str(&format!("- **Duration:** {:.1}s\n", session.total_time.as_secs_f32()));
+    md.push_str(&format!("- **Rounds:** {}\n", session.round_count));
+    md.push_str(&format!("- **Domains checked:** {}\n", session.total_domains_checked()));
+
+    md.push_str(&format!("\n## Available Domains ({})\n\n", session.available_domains.len()));
+    if session.available_domains.is_empty() {
+        md.push_str("None found.\n");
+    } else {
+        md.push_str("| Domain | TLD | Confidence | Score | Reasoning |\n");
+        md.push_str("|--------|-----|-----------:|------:|-----------|\n");
+        for domain in session.ranked_available() {
+            md.push_str(&format!(
+                "| {} | {} | {:.2} | {:.2} | {} |\n",
+                cell(&domain.get_full_domain()),
+                cell(&domain.tld),
+                domain.confidence,
+                domain.score(),
+                cell(domain.reasoning.as_deref().unwrap_or("")),
+            ));
+        }
+    }
+
+    md.push_str(&format!("\n## Taken Domains ({})\n\n", session.taken_domains.len()));
+    if session.taken_domains.is_empty() {
+        md.push_str("None.\n");
+    } else {
+        let mut taken: Vec<&String> = session.taken_domains.iter().collect();
+        taken.sort();
+        md.push_str("| Domain |\n|--------|\n");
+        for domain in taken {
+            md.push_str(&format!("| {} |\n", cell(domain)));
+        }
+    }
+
+    if !session.error_domains.is_empty() {
+        md.push_str(&format!("\n## Errors ({})\n\n", session.error_domains.len()));
+        md.push_str("| Domain | Error |\n|--------|-------|\n");
+        for (domain, error) in &session.error_domains {
+            md.push_str(&format!("| {} | {} |\n", cell(domain), cell(error)));
+        }
+    }
+
+    md
+}
+
+/// Render the Markdown report as a standalone HTML page
+pub fn session_html(session: &DomainSession) -> String {
+    html_page("Domain Forge Session", &session_markdown(session))
+}
+
+/// Render a snipe scan as Markdown: found domains by category, best first
+pub fn scan_markdown(state: &ScanState) -> String {
+    let mut md = String::from("# Domain Forge Scan\n\n");
+    md.push_str(&format!("- **Scan:** {}\n", cell(&state.scan_id)));
+    if !state.tags.is_empty() {
+        md.push_str(&format!("- **Tags:** {}\n", cell(&state.tags.join(", "))));
+    }
+    md.push_str(&format!("- **TLDs:** {}\n", cell(&state.tlds.join(", "))));
+    md.push_str(&format!(
+        "- **Checked:** {} of {} ({:.1}%)\n",
+        state.checked_count,
+        state.total_combinations,
+        state.progress_percent()
+    ));
+
+    md.push_str(&format!("\n## Available Domains ({})\n\n", state.available.len()));
+    if state.available.is_empty() {
+        md.push_str("None found.\n");
+    } else {
+        md.push_str("| Domain | Score |\n|--------|------:|\n");
+        for domain in &state.available {
+            md.push_str(&format!("| {} | {:.2} |\n", cell(&domain.full_domain), domain.score));
+        }
+    }
+
+    for (title, domains) in [
+        ("Pending Delete", &state.pending_delete),
+        ("Expiring Soon", &state.expiring_soon),
+        ("Expired", &state.expired),
+    ] {
+        if !domains.is_empty() {
+            md.push_str(&format!("\n## {} ({})\n\n", title, domains.len()));
+            md.push_str("| Domain | Expires | Registrar | RDAP Status |\n");
+            md.push_str("|--------|---------|-----------|-------------|\n");
+            for domain in domains {
+                md.push_str(&registered_row(domain));
+            }
+        }
+    }
+
+    md
+}
+
+/// Render the scan Markdown report as a standalone HTML page
+pub fn scan_html(state: &ScanState) -> String {
+    html_page("Domain Forge Scan", &scan_markdown(state))
+}
+
+fn registered_row(domain: &SnipedDomain) -> String {
+    format!(
+        "| {} | {} | {} | {} |\n",
+        cell(&domain.full_domain),
+        domain.expiration_date.map(|date| date.format("%Y-%m-%d").to_string()).unwrap_or_default(),
+        cell(domain.registrar.as_deref().unwrap_or("")),
+        cell(&domain.rdap_status.join(", ")),
+    )
+}
+
+fn html_page(title: &str, markdown: &str) -> String {
+    let mut body = String::new();
+    html::push_html(&mut body, Parser::new_ext(markdown, Options::ENABLE_TABLES));
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        title, HTML_STYLE, body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AvailabilityStatus, CheckMethod, DomainResult, DomainSuggestion};
+    use regex::Regex;
+    use std::time::Duration;
+
+    fn result(domain: &str, status: AvailabilityStatus) -> DomainResult {
+        DomainResult {
+            error_message: Some("RDAP timeout".to_string()),
+            ..DomainResult::new(domain, status, CheckMethod::Rdap)
+        }
+    }
+
+    fn session() -> DomainSession {
+        let domains = vec![
+            DomainSuggestion::new("forgeworks", "xyz", 0.6, Some("Long | piped")),
+            DomainSuggestion::new("forge", "com", 0.9, Some("Short and strong")),
+            DomainSuggestion::new("smith", "io", 0.7, None::<String>),
+            DomainSuggestion::new("anvil", "ai", 0.8, None::<String>),
+        ];
+        let results = vec![
+            result("forgeworks.xyz", AvailabilityStatus::Available),
+            result("forge.com", AvailabilityStatus::Available),
+            result("smith.io", AvailabilityStatus::Taken),
+            result("anvil.ai", AvailabilityStatus::Unknown),
+        ];
+        let mut session = DomainSession::new();
+        session.description = Some("metalworking tools".to_string());
+        session.add_round_results(&domains, &results, Duration::from_secs(3));
+        session
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let md = session_markdown(&session());
+        assert!(Regex::new(r"^# Domain Forge Session\n").unwrap().is_match(&md));
+        let table = Regex::new(r"(?m)^\|( [^|]+ \|)+\n\|([-:]+\|)+\n(\|.*\|\n)+").unwrap();
+        assert!(table.is_match(&md), "{}", md);
+
+        assert!(md.contains("- **Description:** metalworking tools"));
+        assert!(md.contains("## Taken Domains (1)"));
+        assert!(md.contains("| anvil.ai | RDAP timeout |"));
+        assert!(md.contains("Long \\| piped"));
+        // Best score first
+        assert!(md.find("| forge.com |").unwrap() < md.find("| forgeworks.xyz |").unwrap());
+    }
+
+    #[test]
+    fn test_scan_report() {
+        let mut state = ScanState::new(5, vec!["com".to_string(), "io".to_string()], 200);
+        state.tags = vec!["drops".to_string()];
+        state.pending_delete.push(SnipedDomain {
+            registrar: Some("Example | Registrar".to_string()),
+            rdap_status: vec!["pendingDelete".to_string()],
+            score: 0.8,
+            ..SnipedDomain::new("forge", "io")
+        });
+
+        let md = scan_markdown(&state);
+        assert!(md.starts_with("# Domain Forge Scan\n"));
+        assert!(md.contains("- **Tags:** drops"));
+        assert!(md.contains("## Pending Delete (1)"));
+        assert!(md.contains("| forge.io |  | Example \\| Registrar | pendingDelete |"));
+        assert!(!md.contains("## Expired"));
+
+        let html = scan_html(&state);
+        assert!(html.contains("<title>Domain Forge Scan</title>"));
+        assert!(html.contains("<td>forge.io</td>"));
+    }
+
+    #[test]
+    fn test_html_report() {
+        let html = session_html(&session());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<style>"));
+        assert!(html.contains("<h1>Domain Forge Session</h1>"));
+        assert!(html.contains("<table>"));
+        assert!(html.contains("<td>forge.com</td>"));
+        assert!(html.contains("Long | piped"));
+    }
+}
@@ -0,0 +1,99 @@
+//! IANA / RFC 2606 reserved domain names
+//!
+//! These names can never be registered, so an RDAP 404 for them must not be
+//! reported as "available".
+
+use std::collections::HashSet;
+
+/// Second-level labels reserved under any TLD (RFC 2606, RFC 6761)
+pub const RESERVED_LABELS: &[&str] = &["example", "test", "localhost", "invalid", "local"];
+
+/// Top-level domains reserved for testing and documentation
+pub const RESERVED_TLDS: &[&str] = &["example", "invalid", "localhost", "test"];
+
+/// Set of reserved labels, TLDs and full domain names
+#[derive(Debug, Clone)]
+pub struct ReservedNames {
+    labels: HashSet<String>,
+    tlds: HashSet<String>,
+    domains: HashSet<String>,
+}
+
+impl ReservedNames {
+    /// Create with the built-in IANA/RFC list
+    pub fn new() -> Self {
+        Self {
+            labels: RESERVED_LABELS.iter().map(|s| s.to_string()).collect(),
+            tlds: RESERVED_TLDS.iter().map(|s| s.to_string()).collect(),
+            domains: HashSet::new(),
+        }
+    }
+
+    /// Add extra reserved names.
+    ///
+    /// `.tld` reserves a TLD, `name.tld` a single domain, and a bare `name`
+    /// the label under every TLD.
+    pub fn with_extra<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for name in names {
+            let name = name.as_ref().trim().to_lowercase();
+            if let Some(tld) = name.strip_prefix('.') {
+                self.tlds.insert(tld.to_string());
+            } else if name.contains('.') {
+                self.domains.insert(name);
+            } else if !name.is_empty() {
+                self.labels.insert(name);
+            }
+        }
+        self
+    }
+
+    /// Whether the domain is reserved
+    pub fn is_reserved(&self, domain: &str) -> bool {
+        let domain = domain.trim().trim_end_matches('.').to_lowercase();
+        if self.domains.contains(&domain) {
+            return true;
+        }
+
+        let mut labels = domain.rsplit('.');
+        let tld = labels.next().unwrap_or_default();
+        if self.tlds.contains(tld) {
+            return true;
+        }
+        labels.next().is_some_and(|label| self.labels.contains(label))
+    }
+}
+
+impl Default for ReservedNames {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_reserved() {
+        let reserved = ReservedNames::new();
+        assert!(reserved.is_reserved("example.com"));
+        assert!(reserved.is_reserved("LOCALHOST.com"));
+        assert!(reserved.is_reserved("anything.test"));
+        assert!(reserved.is_reserved("www.example.org"));
+        assert!(!reserved.is_reserved("examples.com"));
+        assert!(!reserved.is_reserved("forge.io"));
+    }
+
+    #[test]
+    fn test_extra_reserved() {
+        let reserved = ReservedNames::new().with_extra(["nic", ".corp", "icann.org"]);
+        assert!(reserved.is_reserved("nic.io"));
+        assert!(reserved.is_reserved("intranet.corp"));
+        assert!(reserved.is_reserved("icann.org"));
+        assert!(!reserved.is_reserved("icann.com"));
+    }
+}
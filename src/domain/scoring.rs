@@ -0,0 +1,178 @@
+//! Composite quality score for domain suggestions
+//!
+//! The LLM's self-reported confidence is rarely calibrated, so it is blended
+//! with cheap signals that hold for any name: length, TLD popularity, how
+//! easy the name is to say, its structural complexity and letter variety.
+
+use serde::{Deserialize, Serialize};
+
+use super::phonetic::pronounceability;
+use super::utils::{name_complexity, name_entropy};
+use crate::types::DomainSuggestion;
+
+pub const CONFIDENCE_WEIGHT: f32 = 0.3;
+pub const LENGTH_WEIGHT: f32 = 0.2;
+pub const TLD_WEIGHT: f32 = 0.2;
+pub const PRONOUNCEABILITY_WEIGHT: f32 = 0.15;
+pub const COMPLEXITY_WEIGHT: f32 = 0.1;
+pub const ENTROPY_WEIGHT: f32 = 0.05;
+
+/// Names up to this length get the full length score
+const IDEAL_MAX_LENGTH: usize = 5;
+/// Names this long or longer get no length score
+const MAX_SCORED_LENGTH: usize = 20;
+
+/// Popularity of well-known TLDs; anything else scores [`OTHER_TLD_SCORE`]
+const TLD_POPULARITY: &[(&str, f32)] = &[
+    ("com", 1.0),
+    ("io", 0.85),
+    ("ai", 0.8),
+    ("co", 0.75),
+    ("net", 0.7),
+    ("org", 0.7),
+    ("app", 0.65),
+    ("dev", 0.65),
+    ("tech", 0.5),
+    ("me", 0.5),
+    ("xyz", 0.4),
+];
+const OTHER_TLD_SCORE: f32 = 0.3;
+
+/// Individual score components (each 0.0-1.0) and their weighted total
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    pub confidence: f32,
+    pub length: f32,
+    pub tld: f32,
+    pub pronounceability: f32,
+    /// [`name_complexity`] out of 10
+    #[serde(default)]
+    pub complexity: f32,
+    /// [`name_entropy`] as a share of the most a name this long can have
+    #[serde(default)]
+    pub entropy: f32,
+    pub total: f32,
+}
+
+/// Shorter is better: full marks up to 5 characters, falling to 0.0 at 20
+pub fn length_score(name: &str) -> f32 {
+    let len = name.chars().count();
+    if len <= IDEAL_MAX_LENGTH {
+        return 1.0;
+    }
+    let over = (len - IDEAL_MAX_LENGTH) as f32;
+    (1.0 - over / (MAX_SCORED_LENGTH - IDEAL_MAX_LENGTH) as f32).max(0.0)
+}
+
+/// How recognisable a TLD is (`.com` > `.io` > `.ai` > `.xyz`)
+pub fn tld_popularity(tld: &str) -> f32 {
+    let tld = tld.trim_start_matches('.').to_lowercase();
+    TLD_POPULARITY
+        .iter()
+        .find(|(known, _)| *known == tld)
+        .map_or(OTHER_TLD_SCORE, |(_, score)| *score)
+}
+
+/// Letter variety: entropy relative to a name of all-different characters
+pub fn entropy_score(name: &str) -> f32 {
+    let len = name.trim().chars().count();
+    if len < 2 {
+        return 0.0;
+    }
+    (name_entropy(name) / (len as f32).log2()).clamp(0.0, 1.0)
+}
+
+/// Score each component of a suggestion
+pub fn score_breakdown(suggestion: &DomainSuggestion) -> ScoreBreakdown {
+    let confidence = suggestion.confidence.clamp(0.0, 1.0);
+    let length = length_score(&suggestion.name);
+    let tld = tld_popularity(&suggestion.tld);
+    let pronounceability = pronounceability(&suggestion.name);
+    let complexity = f32::from(name_complexity(&suggestion.name)) / 10.0;
+    let entropy = entropy_score(&suggestion.name);
+
+    ScoreBreakdown {
+        confidence,
+        length,
+        tld,
+        pronounceability,
+        complexity,
+        entropy,
+        total: confidence * CONFIDENCE_WEIGHT
+            + length * LENGTH_WEIGHT
+            + tld * TLD_WEIGHT
+            + pronounceability * PRONOUNCEABILITY_WEIGHT
+            + complexity * COMPLEXITY_WEIGHT
+            + entropy * ENTROPY_WEIGHT,
+    }
+}
+
+/// Sort suggestions best first
+pub fn sort_by_score(suggestions: &mut [&DomainSuggestion]) {
+    suggestions.sort_by(|a, b| b.score().total_cmp(&a.score()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggestion(name: &str, tld: &str, confidence: f32) -> DomainSuggestion {
+        DomainSuggestion::new(name, tld, confidence, None::<String>)
+    }
+
+    #[test]
+    fn test_length_score() {
+        assert_eq!(length_score("forge"), 1.0);
+        assert!(length_score("forgelab") < 1.0);
+        assert!(length_score("forgelab") > length_score("forgelaboratory"));
+        assert_eq!(length_score(&"a".repeat(30)), 0.0);
+    }
+
+    #[test]
+    fn test_tld_popularity_order() {
+        assert!(tld_popularity("com") > tld_popularity("io"));
+        assert!(tld_popularity("io") > tld_popularity(".ai"));
+        assert!(tld_popularity("ai") > tld_popularity("XYZ"));
+        assert!(tld_popularity("xyz") > tld_popularity("pizza"));
+    }
+
+    #[test]
+    fn test_breakdown_weights() {
+        let total = CONFIDENCE_WEIGHT
+            + LENGTH_WEIGHT
+            + TLD_WEIGHT
+            + PRONOUNCEABILITY_WEIGHT
+            + COMPLEXITY_WEIGHT
+            + ENTROPY_WEIGHT;
+        assert!((total - 1.0).abs() < 1e-6);
+
+        let breakdown = score_breakdown(&suggestion("zorva", "com", 1.0));
+        assert_eq!(breakdown.confidence, 1.0);
+        assert_eq!(breakdown.pronounceability, 1.0);
+        assert_eq!(breakdown.complexity, 1.0);
+        assert_eq!(breakdown.entropy, 1.0);
+        assert!((breakdown.total - 1.0).abs() < 1e-6);
+
+        let breakdown = score_breakdown(&suggestion("zorva", "com", 0.0));
+        assert!((breakdown.total - (1.0 - CONFIDENCE_WEIGHT)).abs() < 1e-6);
+
+        // Dictionary words lose part of the complexity component
+        let breakdown = score_breakdown(&suggestion("forge", "com", 1.0));
+        assert!((breakdown.total - (1.0 - 0.3 * COMPLEXITY_WEIGHT)).abs() < 1e-6);
+
+        // Out-of-range confidence from the LLM is clamped
+        assert_eq!(score_breakdown(&suggestion("forge", "com", 7.0)).confidence, 1.0);
+    }
+
+    #[test]
+    fn test_sort_by_score() {
+        let best = suggestion("forge", "com", 0.9);
+        let middle = suggestion("forgeworks", "io", 0.9);
+        let worst = suggestion("xkcdqzrt", "xyz", 0.5);
+        let mut ranked = vec![&worst, &best, &middle];
+        sort_by_score(&mut ranked);
+        assert_eq!(ranked[0].name, "forge");
+        assert_eq!(ranked[1].name, "forgeworks");
+        assert_eq!(ranked[2].name, "xkcdqzrt");
+    }
+}
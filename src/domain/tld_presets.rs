@@ -0,0 +1,110 @@
+//! Named TLD lists for common use cases and industries
+
+use std::collections::HashMap;
+
+pub const POPULAR_TLDS: &[&str] = &[
+    "com", "org", "net", "io", "ai", "co", "me", "app", "dev", "tech", "xyz"
+];
+
+pub const STARTUP_TLDS: &[&str] = &[
+    "com", "org", "io", "ai", "tech", "app", "dev", "xyz"
+];
+
+pub const ENTERPRISE_TLDS: &[&str] = &[
+    "com", "org", "net", "biz", "info", "us"
+];
+
+pub const COUNTRY_TLDS: &[&str] = &[
+    "us", "uk", "de", "fr", "ca", "au", "jp", "br", "in"
+];
+
+pub const FINTECH_TLDS: &[&str] = &["com", "io", "finance", "money", "capital"];
+
+pub const HEALTH_TLDS: &[&str] = &["com", "health", "care", "clinic", "doctor"];
+
+pub const EDUCATION_TLDS: &[&str] = &["com", "edu", "academy", "school", "learn"];
+
+pub const ECOMMERCE_TLDS: &[&str] = &["com", "shop", "store", "market", "deals"];
+
+pub const GAMING_TLDS: &[&str] = &["com", "gg", "game", "play", "xyz"];
+
+pub const LEGAL_TLDS: &[&str] = &["com", "legal", "law", "attorney"];
+
+/// Every preset by name, generic presets first
+const PRESETS: &[(&str, &[&str])] = &[
+    ("popular", POPULAR_TLDS),
+    ("startup", STARTUP_TLDS),
+    ("enterprise", ENTERPRISE_TLDS),
+    ("country", COUNTRY_TLDS),
+    ("fintech", FINTECH_TLDS),
+    ("health", HEALTH_TLDS),
+    ("education", EDUCATION_TLDS),
+    ("ecommerce", ECOMMERCE_TLDS),
+    ("gaming", GAMING_TLDS),
+    ("legal", LEGAL_TLDS),
+];
+
+/// TLDs of a preset (case-insensitive name)
+pub fn preset(name: &str) -> Option<&'static [&'static str]> {
+    let name = name.trim().to_lowercase();
+    PRESETS
+        .iter()
+        .find(|(preset, _)| *preset == name)
+        .map(|(_, tlds)| *tlds)
+}
+
+/// Preset names in display order
+pub fn preset_names() -> Vec<&'static str> {
+    PRESETS.iter().map(|(name, _)| *name).collect()
+}
+
+/// All presets keyed by name
+pub fn get_tld_presets() -> HashMap<&'static str, &'static [&'static str]> {
+    PRESETS.iter().copied().collect()
+}
+
+/// Replace preset names in a TLD list with their TLDs, dropping duplicates.
+///
+/// `health` and `legal` are also real TLDs; their presets include them, so
+/// expanding never loses the TLD itself.
+pub fn expand_presets(tlds: &[String]) -> Vec<String> {
+    let mut expanded: Vec<String> = Vec::new();
+    for entry in tlds {
+        let entry = entry.trim().trim_start_matches('.').to_lowercase();
+        let items = match preset(&entry) {
+            Some(preset) => preset.iter().map(|tld| tld.to_string()).collect(),
+            None => vec![entry],
+        };
+        for tld in items {
+            if !tld.is_empty() && !expanded.contains(&tld) {
+                expanded.push(tld);
+            }
+        }
+    }
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_industry_presets() {
+        assert_eq!(preset("fintech"), Some(FINTECH_TLDS));
+        assert_eq!(preset("Gaming"), Some(GAMING_TLDS));
+        assert!(preset("unknown").is_none());
+
+        let presets = get_tld_presets();
+        assert_eq!(presets.len(), preset_names().len());
+        for (name, tlds) in presets {
+            assert!(tlds.contains(&"com") || name == "country", "{} should include .com", name);
+        }
+    }
+
+    #[test]
+    fn test_expand_presets() {
+        let tlds = vec!["AI".to_string(), "legal".to_string(), "com".to_string(), ".law".to_string()];
+        assert_eq!(expand_presets(&tlds), vec!["ai", "com", "legal", "law", "attorney"]);
+        assert_eq!(expand_presets(&["io".to_string()]), vec!["io"]);
+    }
+}
@@ -0,0 +1,279 @@
+//! Small helpers for working with domain names
+
+pub use super::phonetic::phonetic_similarity;
+
+use super::phonetic;
+
+use crate::snipe::words::{BRANDABLE_WORDS, COMMON_WORDS, ROOTS_3, ROOTS_4, TECH_WORDS};
+
+/// Weight of the length rule: 4-7 characters score fully, each character
+/// outside that range costs a quarter of the rule
+pub const BRAND_LENGTH_WEIGHT: f32 = 0.25;
+/// Weight of the rule requiring at least one vowel (`y` counts)
+pub const BRAND_VOWEL_WEIGHT: f32 = 0.15;
+/// Weight of the rule forbidding runs of three or more consonants
+pub const BRAND_CLUSTER_WEIGHT: f32 = 0.15;
+/// Weight of the rule requiring a clean ending (not `q`, `x`, a digit or `-`)
+pub const BRAND_ENDING_WEIGHT: f32 = 0.10;
+/// Weight of the rule rewarding names that are not plain dictionary words
+pub const BRAND_UNIQUENESS_WEIGHT: f32 = 0.15;
+/// Weight of the rule forbidding hard-to-type letter pairs, digits and hyphens
+pub const BRAND_TYPING_WEIGHT: f32 = 0.20;
+
+/// [`name_complexity`] points for using both consonants and vowels
+pub const COMPLEXITY_MIX_POINTS: u8 = 2;
+/// [`name_complexity`] points for no run of three consonants or three vowels
+pub const COMPLEXITY_RUN_POINTS: u8 = 3;
+/// [`name_complexity`] points for containing no dictionary word longer than three letters
+pub const COMPLEXITY_SUBWORD_POINTS: u8 = 3;
+/// [`name_complexity`] points for a length of 4-8 characters
+pub const COMPLEXITY_LENGTH_POINTS: u8 = 2;
+
+/// Share of the maximum entropy below which a name counts as repetitive
+const REPETITIVE_ENTROPY: f32 = 0.75;
+
+/// Letter pairs that are awkward to type or read
+const AWKWARD_PAIRS: &[&str] = &[
+    "qj", "jq", "qx", "xq", "qz", "zq", "qk", "kq", "jx", "xj", "jz", "zj", "vx", "xv", "wv", "vw", "vv",
+    "ww", "jj", "hh", "kk", "qq", "xx", "yy",
+];
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+fn longest_consonant_run(name: &str) -> usize {
+    name.split(|c: char| !c.is_ascii_alphabetic() || is_vowel(c))
+        .map(str::len)
+        .max()
+        .unwrap_or(0)
+}
+
+fn is_dictionary_word(name: &str) -> bool {
+    [COMMON_WORDS, TECH_WORDS, BRANDABLE_WORDS, ROOTS_3, ROOTS_4]
+        .iter()
+        .any(|list| list.contains(&name))
+}
+
+/// Heuristic commercial potential of a name (without TLD), from 0.0 to 1.0.
+///
+/// The score is the sum of these rules, each worth its `BRAND_*` weight:
+///
+/// - **Length** ([`BRAND_LENGTH_WEIGHT`]): 4-7 characters is optimal
+/// - **Vowel** ([`BRAND_VOWEL_WEIGHT`]): contains at least one vowel
+/// - **Clusters** ([`BRAND_CLUSTER_WEIGHT`]): no three consonants in a row
+/// - **Ending** ([`BRAND_ENDING_WEIGHT`]): does not end in `q`, `x`, a digit or `-`
+/// - **Uniqueness** ([`BRAND_UNIQUENESS_WEIGHT`]): not a word from the built-in snipe word lists
+/// - **Typing** ([`BRAND_TYPING_WEIGHT`]): no digits, hyphens or awkward pairs such as `qj` or `vv`
+pub fn brandability_score(name: &str) -> f32 {
+    let name = name.trim().to_lowercase();
+    let len = name.chars().count();
+    if len == 0 {
+        return 0.0;
+    }
+
+    let distance = if len < 4 { 4 - len } else { len.saturating_sub(7) };
+    let mut score = BRAND_LENGTH_WEIGHT * (1.0 - distance as f32 * 0.25).max(0.0);
+
+    if name.chars().any(is_vowel) {
+        score += BRAND_VOWEL_WEIGHT;
+    }
+    if longest_consonant_run(&name) < 3 {
+        score += BRAND_CLUSTER_WEIGHT;
+    }
+    if !name.ends_with(|c: char| matches!(c, 'q' | 'x' | '-') || c.is_ascii_digit()) {
+        score += BRAND_ENDING_WEIGHT;
+    }
+    if !is_dictionary_word(&name) {
+        score += BRAND_UNIQUENESS_WEIGHT;
+    }
+    let easy_to_type = name.chars().all(|c| c.is_ascii_lowercase())
+        && !AWKWARD_PAIRS.iter().any(|pair| name.contains(pair));
+    if easy_to_type {
+        score += BRAND_TYPING_WEIGHT;
+    }
+
+    score.clamp(0.0, 1.0)
+}
+
+/// Shannon entropy of the name's character distribution, in bits per character.
+///
+/// Repetitive names score low (`aaab` ≈ 0.81) and names with no repeated
+/// character score `log2(len)` (`forge` ≈ 2.32). Empty names score 0.0.
+pub fn name_entropy(name: &str) -> f32 {
+    let name = name.trim().to_lowercase();
+    let len = name.chars().count();
+    if len == 0 {
+        return 0.0;
+    }
+
+    let mut counts: std::collections::HashMap<char, usize> = std::collections::HashMap::new();
+    for c in name.chars() {
+        *counts.entry(c).or_default() += 1;
+    }
+    counts
+        .values()
+        .map(|&count| {
+            let p = count as f32 / len as f32;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Longest run of consonants, or of vowels (`y` counts as a vowel)
+fn longest_same_type_run(name: &str) -> usize {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous: Option<bool> = None;
+    for c in name.chars() {
+        if !c.is_ascii_alphabetic() {
+            previous = None;
+            run = 0;
+            continue;
+        }
+        let vowel = is_vowel(c);
+        run = if previous == Some(vowel) { run + 1 } else { 1 };
+        previous = Some(vowel);
+        longest = longest.max(run);
+    }
+    longest
+}
+
+/// Longest dictionary word of four or more letters inside the name
+fn longest_subword(name: &str) -> Option<&str> {
+    let len = name.len();
+    (4..=len)
+        .rev()
+        .flat_map(|size| (0..=len - size).map(move |start| (start, size)))
+        .filter_map(|(start, size)| name.get(start..start + size))
+        .find(|word| is_dictionary_word(word))
+}
+
+/// Structural complexity of a name (without TLD), from 0 to 10.
+///
+/// The sum of these rules, each worth its `COMPLEXITY_*` points:
+///
+/// - **Mix** ([`COMPLEXITY_MIX_POINTS`]): uses both consonants and vowels
+/// - **Runs** ([`COMPLEXITY_RUN_POINTS`]): no more than two consonants or vowels in a row
+/// - **Subwords** ([`COMPLEXITY_SUBWORD_POINTS`]): no word from the built-in snipe
+///   word lists longer than three letters, so compounds like `cloudbase` lose these
+/// - **Length** ([`COMPLEXITY_LENGTH_POINTS`]): 4-8 characters
+pub fn name_complexity(name: &str) -> u8 {
+    let name = name.trim().to_lowercase();
+    let alphabetic = name.chars().filter(char::is_ascii_alphabetic);
+    let vowels = alphabetic.clone().filter(|&c| is_vowel(c)).count();
+    let consonants = alphabetic.count() - vowels;
+
+    let mut points = 0;
+    if vowels > 0 && consonants > 0 {
+        points += COMPLEXITY_MIX_POINTS;
+    }
+    if !name.is_empty() && longest_same_type_run(&name) <= 2 {
+        points += COMPLEXITY_RUN_POINTS;
+    }
+    if !name.is_empty() && longest_subword(&name).is_none() {
+        points += COMPLEXITY_SUBWORD_POINTS;
+    }
+    if (4..=8).contains(&name.chars().count()) {
+        points += COMPLEXITY_LENGTH_POINTS;
+    }
+    points
+}
+
+/// Why a name loses [`name_complexity`] points or looks repetitive by
+/// [`name_entropy`], one sentence per issue (empty for a clean name)
+pub fn complexity_notes(name: &str) -> Vec<String> {
+    let name = name.trim().to_lowercase();
+    let len = name.chars().count();
+    let mut notes = Vec::new();
+
+    let letters: Vec<char> = name.chars().filter(char::is_ascii_alphabetic).collect();
+    if !letters.iter().any(|&c| is_vowel(c)) {
+        notes.push("No vowels".to_string());
+    } else if letters.iter().all(|&c| is_vowel(c)) {
+        notes.push("No consonants".to_string());
+    }
+    if longest_same_type_run(&name) > 2 {
+        notes.push("Three or more consonants or vowels in a row".to_string());
+    }
+    if let Some(word) = longest_subword(&name) {
+        notes.push(format!("Contains the dictionary word '{}'", word));
+    }
+    if !(4..=8).contains(&len) {
+        notes.push(format!("Length {} is outside 4-8 characters", len));
+    }
+    if len >= 2 && name_entropy(&name) < (len as f32).log2() * REPETITIVE_ENTROPY {
+        notes.push(format!("Repetitive letters ({:.2} bits of entropy per character)", name_entropy(&name)));
+    }
+    notes
+}
+
+/// How sayable a name is, from 0.0 to 1.0; companion to [`brandability_score`].
+///
+/// Same heuristic as [`phonetic::pronounceability`], so names rank the same
+/// wherever pronounceability is shown.
+pub fn pronounceability_score(name: &str) -> f32 {
+    phonetic::pronounceability(name.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brand_weights_sum_to_one() {
+        let total = BRAND_LENGTH_WEIGHT
+            + BRAND_VOWEL_WEIGHT
+            + BRAND_CLUSTER_WEIGHT
+            + BRAND_ENDING_WEIGHT
+            + BRAND_UNIQUENESS_WEIGHT
+            + BRAND_TYPING_WEIGHT;
+        assert!((total - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_brandability_score() {
+        assert!((brandability_score("zorvia") - 1.0).abs() < 1e-6);
+        // Dictionary word loses only the uniqueness rule
+        assert!((brandability_score("forge") - (1.0 - BRAND_UNIQUENESS_WEIGHT)).abs() < 1e-6);
+        assert!(brandability_score("zorvia") > brandability_score("strx"));
+        assert!(brandability_score("zorvia") > brandability_score("zorviafoundation"));
+        assert!(brandability_score("zor-4") < brandability_score("zorva"));
+        assert_eq!(brandability_score(""), 0.0);
+    }
+
+    #[test]
+    fn test_name_entropy() {
+        assert_eq!(name_entropy("aaaa"), 0.0);
+        assert!((name_entropy("aaab") - 0.811).abs() < 1e-3);
+        assert!((name_entropy("forge") - 5f32.log2()).abs() < 1e-6);
+        assert!(name_entropy("zorvia") > name_entropy("zozozo"));
+        assert_eq!(name_entropy(""), 0.0);
+    }
+
+    #[test]
+    fn test_name_complexity() {
+        assert_eq!(name_complexity("zorvia"), 10);
+        // Dictionary word loses only the subword rule
+        assert_eq!(name_complexity("forge"), 10 - COMPLEXITY_SUBWORD_POINTS);
+        assert_eq!(name_complexity("strx"), COMPLEXITY_SUBWORD_POINTS + COMPLEXITY_LENGTH_POINTS);
+        assert_eq!(name_complexity("zorviatek"), 10 - COMPLEXITY_LENGTH_POINTS);
+        assert_eq!(name_complexity(""), 0);
+
+        assert!(complexity_notes("zorvia").is_empty());
+        assert_eq!(complexity_notes("forge"), vec!["Contains the dictionary word 'forge'"]);
+        let notes = complexity_notes("aaab");
+        assert!(notes.iter().any(|note| note.starts_with("Three or more")));
+        assert!(notes.iter().any(|note| note.starts_with("Repetitive letters")));
+    }
+
+    #[test]
+    fn test_pronounceability_score() {
+        assert_eq!(pronounceability_score("banana"), 1.0);
+        assert_eq!(pronounceability_score("forge"), 1.0);
+        assert_eq!(pronounceability_score("xkcd"), 0.0);
+        assert!(pronounceability_score("strength") < pronounceability_score("zorvia"));
+        assert!(pronounceability_score("aeiou") < pronounceability_score("zorvia"));
+        assert_eq!(pronounceability_score(" Zorvia "), phonetic::pronounceability("zorvia"));
+    }
+}
@@ -1,13 +1,46 @@
 //! Domain name validation utilities
 
+use crate::domain::confusables::{self, ConfusableChar};
+use crate::domain::reserved::ReservedNames;
 use crate::error::{DomainForgeError, Result};
 use regex::Regex;
 use std::collections::HashSet;
 
+/// Prefix of Punycode-encoded (ACE) labels
+const ACE_PREFIX: &str = "xn--";
+
+const MIN_DOMAIN_LENGTH: usize = 3;
+const MAX_DOMAIN_LENGTH: usize = 253;
+
+/// Second-level registries under ccTLDs, where names are registered one level
+/// down (`company.co.uk`, not `co.uk`)
+const SECOND_LEVEL_SUFFIXES: &[&str] = &[
+    // United Kingdom
+    "co.uk", "org.uk", "me.uk", "net.uk", "ltd.uk", "plc.uk", "ac.uk", "gov.uk", "sch.uk",
+    // Australia / New Zealand
+    "com.au", "net.au", "org.au", "edu.au", "gov.au", "id.au", "asn.au",
+    "co.nz", "net.nz", "org.nz", "ac.nz", "govt.nz",
+    // Japan / Korea / China
+    "co.jp", "or.jp", "ne.jp", "ac.jp", "go.jp", "gr.jp", "ed.jp",
+    "co.kr", "or.kr", "ac.kr",
+    "com.cn", "net.cn", "org.cn", "edu.cn", "gov.cn",
+    "com.hk", "com.tw", "com.sg", "com.my",
+    // India
+    "co.in", "net.in", "org.in", "firm.in", "gen.in", "ind.in", "ac.in", "edu.in", "gov.in",
+    // Americas
+    "com.br", "net.br", "org.br", "edu.br", "gov.br",
+    "com.mx", "org.mx", "com.ar", "com.co",
+    // Elsewhere
+    "co.za", "org.za", "web.za", "co.il", "com.tr",
+];
+
 /// Domain name validator
 pub struct DomainValidator {
     tld_whitelist: Option<HashSet<String>>,
     blocked_words: HashSet<String>,
+    reserved: ReservedNames,
+    /// Reject reserved names instead of only warning about them
+    check_reserved: bool,
 }
 
 impl DomainValidator {
@@ -16,6 +49,8 @@ impl DomainValidator {
         Self {
             tld_whitelist: None,
             blocked_words: HashSet::new(),
+            reserved: ReservedNames::new(),
+            check_reserved: false,
         }
     }
 
@@ -31,21 +66,38 @@ impl DomainValidator {
         self
     }
 
+    /// Use a custom reserved name list for warnings
+    pub fn with_reserved_names(mut self, reserved: ReservedNames) -> Self {
+        self.reserved = reserved;
+        self
+    }
+
+    /// Reject reserved names (see [`crate::domain::reserved`]) with a validation error
+    pub fn with_reserved_check(mut self) -> Self {
+        self.check_reserved = true;
+        self
+    }
+
+    /// Whether the domain is on the reserved list
+    pub fn is_reserved(&self, domain: &str) -> bool {
+        self.reserved.is_reserved(domain)
+    }
+
     /// Validate a domain name
     pub fn validate(&self, domain: &str) -> Result<ValidatedDomain> {
         let domain = domain.trim().to_lowercase();
         
         // Basic format validation
-        self.validate_format(&domain)?;
+        Self::validate_format(&domain)?;
         
         // Length validation
-        self.validate_length(&domain)?;
+        Self::validate_length(&domain, MIN_DOMAIN_LENGTH, MAX_DOMAIN_LENGTH)?;
         
         // Character validation
-        self.validate_characters(&domain)?;
+        Self::validate_characters(&domain)?;
         
         // Parse domain parts
-        let parts = self.parse_domain(&domain)?;
+        let parts = Self::parse_domain(&domain)?;
         
         // TLD validation
         self.validate_tld(&parts.tld)?;
@@ -53,15 +105,55 @@ impl DomainValidator {
         // Content validation
         self.validate_content(&parts.name)?;
         
+        let mut warnings = Vec::new();
+        if self.reserved.is_reserved(&domain) {
+            if self.check_reserved {
+                return Err(reserved_error(&domain));
+            }
+            warnings.push(format!("'{}' is a reserved name and cannot be registered", domain));
+        }
+
         Ok(ValidatedDomain {
             original: domain.clone(),
             name: parts.name,
             tld: parts.tld,
             full_domain: domain,
             is_valid: true,
+            warnings,
+            unicode_form: None,
         })
     }
 
+    /// Validate an internationalized domain name.
+    ///
+    /// Unicode input is converted to its ACE/Punycode form (`münchen.de` →
+    /// `xn--mnchen-3ya.de`), which is then validated like any other domain.
+    /// `full_domain` holds the ACE form and `unicode_form` the original input.
+    pub fn validate_idn(&self, domain: &str) -> Result<ValidatedDomain> {
+        let input = domain.trim();
+        let ace = idna::domain_to_ascii(input)
+            .map_err(|e| DomainForgeError::validation(format!("Invalid internationalized domain '{}': {}", input, e)))?;
+
+        let mut validated = self.validate(&ace)?;
+        if Self::is_idn(input) {
+            validated.original = input.to_string();
+            validated.unicode_form = Some(input.to_string());
+        }
+        Ok(validated)
+    }
+
+    /// Whether the domain is internationalized (non-ASCII or has a Punycode label)
+    pub fn is_idn(domain: &str) -> bool {
+        !domain.is_ascii() || domain.split('.').any(|label| label.to_ascii_lowercase().starts_with(ACE_PREFIX))
+    }
+
+    /// Characters that mimic ASCII letters (homograph risk).
+    ///
+    /// Advisory only: a match does not make the domain invalid.
+    pub fn detect_confusables(domain: &str) -> Vec<ConfusableChar> {
+        confusables::detect(domain)
+    }
+
     /// Validate multiple domains
     pub fn validate_batch(&self, domains: &[String]) -> Vec<DomainValidationResult> {
         domains
@@ -74,7 +166,7 @@ impl DomainValidator {
     }
 
     /// Validate domain format
-    fn validate_format(&self, domain: &str) -> Result<()> {
+    fn validate_format(domain: &str) -> Result<()> {
         if domain.is_empty() {
             return Err(DomainForgeError::validation("Domain name cannot be empty"));
         }
@@ -93,8 +185,8 @@ impl DomainValidator {
             return Err(DomainForgeError::validation("Domain cannot contain consecutive dots"));
         }
 
-        // Check for consecutive hyphens
-        if domain.contains("--") {
+        // Check for consecutive hyphens (allowed only as the Punycode prefix)
+        if domain.split('.').any(|label| label.strip_prefix(ACE_PREFIX).unwrap_or(label).contains("--")) {
             return Err(DomainForgeError::validation("Domain cannot contain consecutive hyphens"));
         }
 
@@ -102,20 +194,20 @@ impl DomainValidator {
     }
 
     /// Validate domain length
-    fn validate_length(&self, domain: &str) -> Result<()> {
-        if domain.len() > 253 {
-            return Err(DomainForgeError::validation("Domain name too long (max 253 characters)"));
+    fn validate_length(domain: &str, min: usize, max: usize) -> Result<()> {
+        if domain.len() > max {
+            return Err(DomainForgeError::validation(format!("Domain name too long (max {} characters)", max)));
         }
 
-        if domain.len() < 3 {
-            return Err(DomainForgeError::validation("Domain name too short (min 3 characters)"));
+        if domain.len() < min {
+            return Err(DomainForgeError::validation(format!("Domain name too short (min {} characters)", min)));
         }
 
         Ok(())
     }
 
     /// Validate domain characters
-    fn validate_characters(&self, domain: &str) -> Result<()> {
+    fn validate_characters(domain: &str) -> Result<()> {
         let valid_chars = Regex::new(r"^[a-z0-9.-]+$")
             .map_err(|e| DomainForgeError::internal(e.to_string()))?;
 
@@ -126,16 +218,29 @@ impl DomainValidator {
         Ok(())
     }
 
-    /// Parse domain into name and TLD
-    fn parse_domain(&self, domain: &str) -> Result<DomainParts> {
+    /// Number of trailing labels forming the registry suffix: 2 for
+    /// `<name>.co.uk` and friends, otherwise 1
+    fn suffix_labels(parts: &[&str]) -> usize {
+        if parts.len() >= 3 {
+            let suffix = parts[parts.len() - 2..].join(".");
+            if SECOND_LEVEL_SUFFIXES.contains(&suffix.as_str()) {
+                return 2;
+            }
+        }
+        1
+    }
+
+    /// Parse domain into name and TLD (which may be a second-level suffix like `co.uk`)
+    fn parse_domain(domain: &str) -> Result<DomainParts> {
         let parts: Vec<&str> = domain.split('.').collect();
         
         if parts.len() < 2 {
             return Err(DomainForgeError::validation("Domain must have at least one dot"));
         }
 
-        let tld = parts.last().unwrap().to_string();
-        let name = parts[..parts.len()-1].join(".");
+        let split = parts.len() - Self::suffix_labels(&parts);
+        let tld = parts[split..].join(".");
+        let name = parts[..split].join(".");
 
         if name.is_empty() {
             return Err(DomainForgeError::validation("Domain name part cannot be empty"));
@@ -150,6 +255,17 @@ impl DomainValidator {
 
     /// Validate TLD
     fn validate_tld(&self, tld: &str) -> Result<()> {
+        Self::validate_tld_format(tld)?;
+
+        if let Some(whitelist) = &self.tld_whitelist {
+            check_tld_whitelist(whitelist, tld)?;
+        }
+
+        Ok(())
+    }
+
+    /// Validate TLD length and characters
+    fn validate_tld_format(tld: &str) -> Result<()> {
         if tld.len() < 2 {
             return Err(DomainForgeError::validation("TLD too short (min 2 characters)"));
         }
@@ -158,18 +274,11 @@ impl DomainValidator {
             return Err(DomainForgeError::validation("TLD too long (max 63 characters)"));
         }
 
-        // Check against whitelist if provided
-        if let Some(whitelist) = &self.tld_whitelist {
-            if !whitelist.contains(tld) {
-                return Err(DomainForgeError::validation(format!("TLD '{}' not in whitelist", tld)));
-            }
-        }
-
-        // Basic TLD format validation
-        let tld_regex = Regex::new(r"^[a-z]{2,63}$")
+        // Basic TLD format validation (letters, or a Punycode IDN TLD)
+        let tld_regex = Regex::new(r"^([a-z]{2,63}|xn--[a-z0-9-]{2,59})$")
             .map_err(|e| DomainForgeError::internal(e.to_string()))?;
 
-        if !tld_regex.is_match(tld) {
+        if !tld.split('.').all(|label| tld_regex.is_match(label)) {
             return Err(DomainForgeError::validation("Invalid TLD format"));
         }
 
@@ -178,14 +287,12 @@ impl DomainValidator {
 
     /// Validate domain content
     fn validate_content(&self, name: &str) -> Result<()> {
-        // Check for blocked words
-        for blocked_word in &self.blocked_words {
-            if name.contains(blocked_word) {
-                return Err(DomainForgeError::validation(format!("Domain contains blocked word: {}", blocked_word)));
-            }
-        }
+        check_blocked_words(&self.blocked_words, name)?;
+        Self::validate_labels(name)
+    }
 
-        // Check each label in the domain name
+    /// Check each label in the domain name
+    fn validate_labels(name: &str) -> Result<()> {
         for label in name.split('.') {
             if label.is_empty() {
                 return Err(DomainForgeError::validation("Domain label cannot be empty"));
@@ -215,20 +322,20 @@ impl DomainValidator {
 
     /// Extract domain name without TLD
     pub fn extract_name(&self, domain: &str) -> Result<String> {
-        let parts = self.parse_domain(&self.normalize(domain))?;
+        let parts = Self::parse_domain(&self.normalize(domain))?;
         Ok(parts.name)
     }
 
     /// Extract TLD from domain
     pub fn extract_tld(&self, domain: &str) -> Result<String> {
-        let parts = self.parse_domain(&self.normalize(domain))?;
+        let parts = Self::parse_domain(&self.normalize(domain))?;
         Ok(parts.tld)
     }
 
     /// Check if domain is a subdomain
     pub fn is_subdomain(&self, domain: &str) -> bool {
         let parts: Vec<&str> = domain.split('.').collect();
-        parts.len() > 2
+        parts.len() > Self::suffix_labels(&parts) + 1
     }
 
     /// Get the root domain (remove subdomains)
@@ -239,13 +346,8 @@ impl DomainValidator {
             return Err(DomainForgeError::validation("Invalid domain format"));
         }
 
-        let root = if parts.len() == 2 {
-            domain.to_string()
-        } else {
-            format!("{}.{}", parts[parts.len()-2], parts[parts.len()-1])
-        };
-
-        Ok(root)
+        let keep = Self::suffix_labels(&parts) + 1;
+        Ok(parts[parts.len().saturating_sub(keep)..].join("."))
     }
 }
 
@@ -255,6 +357,176 @@ impl Default for DomainValidator {
     }
 }
 
+/// Check against a TLD whitelist (`uk` also admits `co.uk`)
+fn check_tld_whitelist(whitelist: &HashSet<String>, tld: &str) -> Result<()> {
+    let cctld = tld.rsplit('.').next().unwrap_or(tld);
+    if !whitelist.contains(tld) && !whitelist.contains(cctld) {
+        return Err(DomainForgeError::validation(format!("TLD '{}' not in whitelist", tld)));
+    }
+    Ok(())
+}
+
+fn check_blocked_words(blocked_words: &HashSet<String>, name: &str) -> Result<()> {
+    for blocked_word in blocked_words {
+        if name.contains(blocked_word) {
+            return Err(DomainForgeError::validation(format!("Domain contains blocked word: {}", blocked_word)));
+        }
+    }
+    Ok(())
+}
+
+fn reserved_error(domain: &str) -> DomainForgeError {
+    DomainForgeError::validation(format!("Domain '{}' is IANA-reserved and cannot be registered", domain))
+}
+
+/// A single check run by a [`ValidatorChain`].
+///
+/// Rules receive the domain trimmed and lowercased.
+pub trait DomainValidationRule: Send + Sync {
+    fn validate(&self, domain: &str) -> Result<()>;
+}
+
+/// Dots, hyphens, characters, label and TLD format
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FormatRule;
+
+impl DomainValidationRule for FormatRule {
+    fn validate(&self, domain: &str) -> Result<()> {
+        DomainValidator::validate_format(domain)?;
+        DomainValidator::validate_characters(domain)?;
+        let parts = DomainValidator::parse_domain(domain)?;
+        DomainValidator::validate_tld_format(&parts.tld)?;
+        DomainValidator::validate_labels(&parts.name)
+    }
+}
+
+/// Total length of the domain, 3 to 253 characters by default
+#[derive(Debug, Clone, Copy)]
+pub struct LengthRule {
+    min: usize,
+    max: usize,
+}
+
+impl LengthRule {
+    pub fn new(min: usize, max: usize) -> Self {
+        Self { min, max }
+    }
+}
+
+impl Default for LengthRule {
+    fn default() -> Self {
+        Self::new(MIN_DOMAIN_LENGTH, MAX_DOMAIN_LENGTH)
+    }
+}
+
+impl DomainValidationRule for LengthRule {
+    fn validate(&self, domain: &str) -> Result<()> {
+        DomainValidator::validate_length(domain, self.min, self.max)
+    }
+}
+
+/// Only allow the listed TLDs (`uk` also admits `co.uk`)
+#[derive(Debug, Clone)]
+pub struct TldWhitelistRule {
+    tlds: HashSet<String>,
+}
+
+impl TldWhitelistRule {
+    pub fn new<I, S>(tlds: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            tlds: tlds.into_iter().map(|tld| tld.as_ref().trim_start_matches('.').to_lowercase()).collect(),
+        }
+    }
+}
+
+impl DomainValidationRule for TldWhitelistRule {
+    fn validate(&self, domain: &str) -> Result<()> {
+        check_tld_whitelist(&self.tlds, &DomainValidator::parse_domain(domain)?.tld)
+    }
+}
+
+/// Reject names (the part before the TLD) containing any of the words
+#[derive(Debug, Clone)]
+pub struct BlockedWordsRule {
+    words: HashSet<String>,
+}
+
+impl BlockedWordsRule {
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            words: words.into_iter().map(|word| word.as_ref().to_lowercase()).collect(),
+        }
+    }
+}
+
+impl DomainValidationRule for BlockedWordsRule {
+    fn validate(&self, domain: &str) -> Result<()> {
+        check_blocked_words(&self.words, &DomainValidator::parse_domain(domain)?.name)
+    }
+}
+
+/// Reject reserved names, see [`crate::domain::reserved`]
+#[derive(Debug, Clone, Default)]
+pub struct ReservedNamesRule {
+    reserved: ReservedNames,
+}
+
+impl ReservedNamesRule {
+    /// Use a custom reserved name list
+    pub fn with_names(reserved: ReservedNames) -> Self {
+        Self { reserved }
+    }
+}
+
+impl DomainValidationRule for ReservedNamesRule {
+    fn validate(&self, domain: &str) -> Result<()> {
+        if self.reserved.is_reserved(domain) {
+            return Err(reserved_error(domain));
+        }
+        Ok(())
+    }
+}
+
+/// Validator built from pluggable rules, run in the order they were added
+#[derive(Default)]
+pub struct ValidatorChain {
+    rules: Vec<Box<dyn DomainValidationRule>>,
+}
+
+impl ValidatorChain {
+    /// Create an empty chain, which accepts everything
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Chain with [`FormatRule`] and [`LengthRule`], the checks every
+    /// domain needs
+    pub fn standard() -> Self {
+        let mut chain = Self::new();
+        chain.add_rule(FormatRule).add_rule(LengthRule::default());
+        chain
+    }
+
+    pub fn add_rule(&mut self, rule: impl DomainValidationRule + 'static) -> &mut Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Run every rule, stopping at the first failure
+    pub fn validate(&self, domain: &str) -> Result<()> {
+        let domain = domain.trim().to_lowercase();
+        self.rules.iter().try_for_each(|rule| rule.validate(&domain))
+    }
+}
+
 /// Domain validation result
 #[derive(Debug, Clone)]
 pub struct ValidatedDomain {
@@ -263,6 +535,10 @@ pub struct ValidatedDomain {
     pub tld: String,
     pub full_domain: String,
     pub is_valid: bool,
+    /// Non-fatal issues, e.g. reserved names
+    pub warnings: Vec<String>,
+    /// Original Unicode spelling when validated via `validate_idn`
+    pub unicode_form: Option<String>,
 }
 
 impl ValidatedDomain {
@@ -391,6 +667,47 @@ impl<T, I> IteratorExt<T> for I where I: Iterator<Item = T> {}
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_reserved_name_warning() {
+        let validator = DomainValidator::new();
+        let validated = validator.validate("example.com").unwrap();
+        assert_eq!(validated.warnings.len(), 1);
+        assert!(validator.validate("forge.com").unwrap().warnings.is_empty());
+    }
+
+    #[test]
+    fn test_reserved_check() {
+        let validator = DomainValidator::new().with_reserved_check();
+        let err = validator.validate("Example.com").unwrap_err();
+        assert!(err.to_string().contains("'example.com' is IANA-reserved"));
+        assert!(validator.validate("foo.test").is_err());
+        assert!(validator.validate("forge.com").is_ok());
+        assert!(validator.is_reserved("localhost.io"));
+    }
+
+    #[test]
+    fn test_idn_validation() {
+        let validator = DomainValidator::new();
+
+        let validated = validator.validate_idn("münchen.de").unwrap();
+        assert_eq!(validated.full_domain, "xn--mnchen-3ya.de");
+        assert_eq!(validated.name, "xn--mnchen-3ya");
+        assert_eq!(validated.unicode_form.as_deref(), Some("münchen.de"));
+
+        let validated = validator.validate_idn("forge.com").unwrap();
+        assert_eq!(validated.full_domain, "forge.com");
+        assert!(validated.unicode_form.is_none());
+
+        // ASCII validation stays strict, but accepts the ACE form
+        assert!(validator.validate("münchen.de").is_err());
+        assert!(validator.validate("xn--mnchen-3ya.de").is_ok());
+        assert!(validator.validate("bad--name.com").is_err());
+
+        assert!(DomainValidator::is_idn("münchen.de"));
+        assert!(DomainValidator::is_idn("xn--mnchen-3ya.de"));
+        assert!(!DomainValidator::is_idn("forge.com"));
+    }
+
     #[test]
     fn test_basic_validation() {
         let validator = DomainValidator::new();
@@ -425,6 +742,42 @@ mod tests {
         assert!(validator.validate("bad-domain.com").is_err());
     }
 
+    #[test]
+    fn test_validator_chain() {
+        struct NoDigits;
+        impl DomainValidationRule for NoDigits {
+            fn validate(&self, domain: &str) -> Result<()> {
+                if domain.chars().any(|c| c.is_ascii_digit()) {
+                    return Err(DomainForgeError::validation("Digits are not allowed"));
+                }
+                Ok(())
+            }
+        }
+
+        assert!(ValidatorChain::new().validate("not a domain").is_ok());
+
+        let mut chain = ValidatorChain::standard();
+        chain
+            .add_rule(TldWhitelistRule::new([".com", "uk"]))
+            .add_rule(BlockedWordsRule::new(["Spam"]))
+            .add_rule(ReservedNamesRule::default())
+            .add_rule(NoDigits);
+
+        assert!(chain.validate(" Forge.COM ").is_ok());
+        assert!(chain.validate("forge.co.uk").is_ok());
+        assert!(chain.validate("-forge.com").is_err());
+        assert!(chain.validate("a.b").is_err());
+        assert!(chain.validate("forge.net").unwrap_err().to_string().contains("not in whitelist"));
+        assert!(chain.validate("nospam.com").unwrap_err().to_string().contains("blocked word"));
+        assert!(chain.validate("example.com").unwrap_err().to_string().contains("IANA-reserved"));
+        assert!(chain.validate("forge4.com").unwrap_err().to_string().contains("Digits"));
+
+        let mut short = ValidatorChain::new();
+        short.add_rule(LengthRule::new(3, 8));
+        assert!(short.validate("forge.io").is_ok());
+        assert!(short.validate("forgeworks.io").is_err());
+    }
+
     #[test]
     fn test_domain_parsing() {
         let validator = DomainValidator::new();
@@ -453,6 +806,30 @@ mod tests {
         assert_eq!(validator.get_root_domain("deep.sub.example.com").unwrap(), "example.com");
     }
 
+    #[test]
+    fn test_second_level_cctlds() {
+        let validator = DomainValidator::new();
+
+        let validated = validator.validate("company.co.uk").unwrap();
+        assert_eq!((validated.name.as_str(), validated.tld.as_str()), ("company", "co.uk"));
+        let validated = validator.validate("university.ac.uk").unwrap();
+        assert_eq!((validated.name.as_str(), validated.tld.as_str()), ("university", "ac.uk"));
+        let validated = validator.validate("startup.com.br").unwrap();
+        assert_eq!((validated.name.as_str(), validated.tld.as_str()), ("startup", "com.br"));
+
+        assert_eq!(validator.extract_name("shop.startup.com.br").unwrap(), "shop.startup");
+        assert_eq!(validator.extract_tld("co.uk").unwrap(), "uk");
+
+        assert!(!validator.is_subdomain("company.co.uk"));
+        assert!(validator.is_subdomain("www.company.co.uk"));
+        assert_eq!(validator.get_root_domain("www.company.co.uk").unwrap(), "company.co.uk");
+        assert_eq!(validator.get_root_domain("company.co.uk").unwrap(), "company.co.uk");
+
+        let uk_only = DomainValidator::new().with_tld_whitelist(vec!["uk".to_string()]);
+        assert!(uk_only.validate("company.co.uk").is_ok());
+        assert!(uk_only.validate("startup.com.br").is_err());
+    }
+
     #[test]
     fn test_utility_functions() {
         assert!(utils::looks_like_domain("example.com"));
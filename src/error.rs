@@ -1,15 +1,22 @@
 //! Error handling for domain-forge
 
-
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Main error type for domain-forge
-#[derive(Error, Debug, Clone)]
+///
+/// Serializes as a flat JSON object tagged with its [`error_code`](Self::error_code),
+/// e.g. `{"error":"rate_limit","message":"slow down","retry_after":30}`.
+#[derive(Error, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "error")]
 pub enum DomainForgeError {
     #[error("Configuration error: {message}")]
+    #[serde(rename = "config_error")]
     Config { message: String },
 
     #[error("LLM provider error ({provider}): {message}")]
+    #[serde(rename = "llm_provider_error")]
     LlmProvider {
         provider: crate::types::LlmProvider,
         message: String,
@@ -17,6 +24,7 @@ pub enum DomainForgeError {
     },
 
     #[error("Domain checking error for '{domain}': {message}")]
+    #[serde(rename = "domain_check_error")]
     DomainCheck {
         domain: String,
         message: String,
@@ -24,6 +32,7 @@ pub enum DomainForgeError {
     },
 
     #[error("Network error: {message}")]
+    #[serde(rename = "network_error")]
     Network {
         message: String,
         status_code: Option<u16>,
@@ -31,39 +40,49 @@ pub enum DomainForgeError {
     },
 
     #[error("Authentication error: {message}")]
+    #[serde(rename = "authentication_error")]
     Authentication { message: String },
 
     #[error("Rate limit exceeded: {message}")]
+    #[serde(rename = "rate_limit")]
     RateLimit {
         message: String,
         retry_after: Option<u64>,
     },
 
     #[error("Timeout error: {operation} timed out after {timeout_secs}s")]
+    #[serde(rename = "timeout")]
     Timeout {
         operation: String,
         timeout_secs: u64,
     },
 
     #[error("Parse error: {message}")]
+    #[serde(rename = "parse_error")]
     Parse {
         message: String,
         content: Option<String>,
+        /// Number of re-prompts made before giving up
+        retry_count: u8,
     },
 
     #[error("Validation error: {message}")]
+    #[serde(rename = "validation_error")]
     Validation { message: String },
 
     #[error("IO error: {message}")]
+    #[serde(rename = "io_error")]
     Io {
         message: String,
         path: Option<String>,
     },
 
     #[error("Internal error: {message}")]
+    #[serde(rename = "internal_error")]
     Internal { message: String },
 
     #[error("CLI error: {message}")]
+    #[serde(rename = "cli_error")]
     Cli { message: String },
 }
 
@@ -142,6 +161,7 @@ impl DomainForgeError {
         Self::Parse {
             message: message.into(),
             content,
+            retry_count: 0,
         }
     }
 
@@ -174,6 +194,48 @@ impl DomainForgeError {
         }
     }
 
+    /// Machine-readable code, suitable for API error responses
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            Self::Config { .. } => "config_error",
+            Self::LlmProvider { .. } => "llm_provider_error",
+            Self::DomainCheck { .. } => "domain_check_error",
+            Self::Network { .. } => "network_error",
+            Self::Authentication { .. } => "authentication_error",
+            Self::RateLimit { .. } => "rate_limit",
+            Self::Timeout { .. } => "timeout",
+            Self::Parse { .. } => "parse_error",
+            Self::Validation { .. } => "validation_error",
+            Self::Io { .. } => "io_error",
+            Self::Internal { .. } => "internal_error",
+            Self::Cli { .. } => "cli_error",
+        }
+    }
+
+    /// Human-friendly error category
+    pub fn error_type(&self) -> &'static str {
+        match self {
+            Self::Config { .. } => "Configuration",
+            Self::LlmProvider { .. } => "LLM provider",
+            Self::DomainCheck { .. } => "Domain check",
+            Self::Network { .. } => "Network",
+            Self::Authentication { .. } => "Authentication",
+            Self::RateLimit { .. } => "Rate limit",
+            Self::Timeout { .. } => "Timeout",
+            Self::Parse { .. } => "Parse",
+            Self::Validation { .. } => "Validation",
+            Self::Io { .. } => "File I/O",
+            Self::Internal { .. } => "Internal",
+            Self::Cli { .. } => "Command line",
+        }
+    }
+
+    /// Serialize to a JSON object, e.g. for HTTP responses or structured logs
+    pub fn to_json(&self) -> String {
+        // Every field is a string, number or option of one, so this cannot fail
+        serde_json::to_string(self).expect("DomainForgeError is always serializable")
+    }
+
     /// Check if this error indicates a domain might be available
     pub fn suggests_available(&self) -> bool {
         match self {
@@ -189,7 +251,29 @@ impl DomainForgeError {
         }
     }
 
+    /// Whether retrying the same request may succeed
+    ///
+    /// Network errors count as transient when the server answered 5xx or 429,
+    /// or when no response arrived at all (connection refused/reset).
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Timeout { .. } | Self::RateLimit { .. } => true,
+            Self::Network { status_code, .. } => match status_code {
+                Some(code) => *code == 429 || (500..=599).contains(code),
+                None => true,
+            },
+            _ => false,
+        }
+    }
 
+    /// How long to wait before retrying, if the error suggests a delay
+    pub fn retry_delay(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimit { retry_after, .. } => retry_after.map(Duration::from_secs),
+            Self::Timeout { .. } => Some(Duration::from_millis(500)),
+            _ => None,
+        }
+    }
 
     /// Get user-friendly error message with suggestions
     pub fn user_message(&self) -> String {
@@ -310,4 +394,58 @@ macro_rules! internal_error {
     ($fmt:expr, $($arg:tt)*) => {
         $crate::error::DomainForgeError::internal(format!($fmt, $($arg)*))
     };
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_classification() {
+        assert!(DomainForgeError::timeout("RDAP query", 10).is_retryable());
+        assert!(DomainForgeError::rate_limit("slow down", Some(30)).is_retryable());
+        assert!(DomainForgeError::network("bad gateway", Some(502), None).is_retryable());
+        assert!(DomainForgeError::network("connection reset", None, None).is_retryable());
+        assert!(!DomainForgeError::network("forbidden", Some(403), None).is_retryable());
+        assert!(!DomainForgeError::authentication("bad key").is_retryable());
+        assert!(!DomainForgeError::config("missing key").is_retryable());
+        assert!(!DomainForgeError::validation("bad domain").is_retryable());
+        assert!(!DomainForgeError::parse("bad json", None).is_retryable());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let err = DomainForgeError::rate_limit("slow down", Some(30));
+        assert_eq!(err.to_json(), r#"{"error":"rate_limit","message":"slow down","retry_after":30}"#);
+
+        let errors = [
+            DomainForgeError::config("missing key"),
+            DomainForgeError::llm_provider(crate::types::LlmProvider::OpenAi, "bad model", Some("404".to_string())),
+            DomainForgeError::network("bad gateway", Some(502), Some("https://rdap.org".to_string())),
+            DomainForgeError::timeout("RDAP query", 10),
+            DomainForgeError::io("denied", Some("out.json".to_string())),
+        ];
+        for err in errors {
+            let json: serde_json::Value = serde_json::from_str(&err.to_json()).unwrap();
+            assert_eq!(json["error"], err.error_code());
+
+            let parsed: DomainForgeError = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed.to_string(), err.to_string());
+            assert_eq!(parsed.error_type(), err.error_type());
+        }
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(
+            DomainForgeError::rate_limit("slow down", Some(30)).retry_delay(),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(DomainForgeError::rate_limit("slow down", None).retry_delay(), None);
+        assert_eq!(
+            DomainForgeError::timeout("RDAP query", 10).retry_delay(),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(DomainForgeError::network("bad gateway", Some(502), None).retry_delay(), None);
+    }
+}
@@ -2,23 +2,28 @@
 //!
 //! A simple and elegant CLI tool for generating domain names using AI and checking their availability.
 
+pub mod config;
 pub mod domain;
 pub mod error;
 pub mod llm;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod rdap;
 pub mod snipe;
 pub mod types;
 
 // Re-export commonly used types
+pub use config::Config;
 pub use error::{DomainForgeError, Result};
 pub use types::{
-    AvailabilityStatus, CheckConfig, DomainForgeResult, DomainResult,
-    DomainSuggestion, GenerationConfig, GenerationStyle, LlmProvider, LlmConfig,
-    PerformanceMetrics, MetricsSnapshot, DomainSession,
+    AvailabilityStatus, CheckConfig, CheckConfigBuilder, DomainForgeResult, DomainResult,
+    DomainSuggestion, GenerationConfig, GenerationConfigBuilder, GenerationStyle, LlmProvider, LlmConfig,
+    PerformanceMetrics, MetricsSnapshot, DomainSession, DomainScore, JsonOutput, RoundResult, SessionStatistics, WhoisConfig,
 };
 
 // Re-export main functionality
 pub use domain::DomainChecker;
+pub use domain::utils::{brandability_score, name_complexity, name_entropy, pronounceability_score};
 pub use llm::DomainGenerator;
 
 /// Library version
@@ -26,7 +31,19 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Initialize the library
 pub fn init() -> Result<()> {
-    // Load .env file if it exists
+    init_with_config(None)
+}
+
+/// Initialize the library, loading configuration from `config_file` if given
+pub fn init_with_config(config_file: Option<&std::path::Path>) -> Result<()> {
+    // Real environment variables override the config file
+    let mut config = config::load_file(config_file)?;
+    config.apply_env();
+
+    // Load .env file if it exists; it only fills gaps left by the config file
     dotenv::dotenv().ok();
+    config.fill_from_env();
+
+    config::set_current(config);
     Ok(())
 }
\ No newline at end of file
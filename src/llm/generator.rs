@@ -1,20 +1,40 @@
 //! Domain generator using LLM
 
-use crate::error::Result;
-use crate::llm::{LlmProvider, create_provider};
+use crate::error::{DomainForgeError, Result};
+use crate::domain::utils::phonetic_similarity;
+use crate::llm::{tld_recommender, LlmProvider, create_provider};
 use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig, PerformanceMetrics};
+use futures::future::join_all;
 use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+/// System message for [`DomainGenerator::generate_variations`]
+const VARIATIONS_SYSTEM_PROMPT: &str =
+    "You are a domain name generator. Generate close variations of a given domain name and return them as a JSON array.";
+
+/// System message for [`DomainGenerator::score_domains`]
+const SCORING_SYSTEM_PROMPT: &str =
+    "You are a domain name critic. Score the given domain names and return them as a JSON array.";
+
+/// Appended to the prompt when re-asking after an unparseable response
+const MALFORMED_JSON_NOTICE: &str =
+    "Your last response was malformed JSON. Please return only a valid JSON array, no markdown fences, no commentary.";
+
+/// Default time limit for a single provider in [`DomainGenerator::generate_with_all_providers`]
+pub const DEFAULT_PROVIDER_TIMEOUT: Duration = Duration::from_secs(60);
 
 /// Domain generator that uses LLM to generate domain suggestions
 /// Enhanced with thread-safe shared state and performance metrics
 #[derive(Clone)]
 pub struct DomainGenerator {
     providers: Arc<RwLock<HashMap<String, Arc<dyn LlmProvider>>>>,
+    /// Settings each provider was added with, for listing
+    configs: Arc<RwLock<HashMap<String, LlmConfig>>>,
     default_provider: Arc<RwLock<String>>,
     metrics: Arc<PerformanceMetrics>,
+    provider_timeout: Duration,
 }
 
 impl DomainGenerator {
@@ -22,16 +42,26 @@ impl DomainGenerator {
     pub fn new() -> Self {
         Self {
             providers: Arc::new(RwLock::new(HashMap::new())),
+            configs: Arc::new(RwLock::new(HashMap::new())),
             default_provider: Arc::new(RwLock::new("openai".to_string())),
             metrics: Arc::new(PerformanceMetrics::new()),
+            provider_timeout: DEFAULT_PROVIDER_TIMEOUT,
         }
     }
 
+    /// Set the per-provider time limit used when querying all providers
+    pub fn with_provider_timeout(mut self, timeout: Duration) -> Self {
+        self.provider_timeout = timeout;
+        self
+    }
+
     /// Add an LLM provider (thread-safe)
     pub fn add_provider(&self, config: &LlmConfig) -> Result<()> {
-        let provider = create_provider(config)?;
+        let mut provider = create_provider(config)?;
+        provider.attach_metrics(Arc::clone(&self.metrics));
         let mut providers = self.providers.write();
         providers.insert(config.provider.clone(), Arc::from(provider));
+        self.configs.write().insert(config.provider.clone(), config.clone());
         Ok(())
     }
     
@@ -57,6 +87,17 @@ impl DomainGenerator {
         provider_name: &str,
     ) -> Result<Vec<DomainSuggestion>> {
         let start_time = Instant::now();
+
+        let resolved;
+        let config = if tld_recommender::is_auto(&config.tlds) {
+            resolved = GenerationConfig {
+                tlds: tld_recommender::recommend_tlds(&config.description),
+                ..config.clone()
+            };
+            &resolved
+        } else {
+            config
+        };
         
         // Record API call
         self.metrics.increment_api_calls();
@@ -71,9 +112,39 @@ impl DomainGenerator {
                 .clone()
         };
         
-        // Call the provider's generate_domains method (no lock held)
-        let result = provider.generate_domains(config).await;
+        // Call the provider's generate_domains method (no lock held),
+        // re-prompting while the response cannot be parsed
+        let mut retries: u8 = 0;
+        let mut attempt_config = None;
+        let result = loop {
+            match provider.generate_domains(attempt_config.as_ref().unwrap_or(config)).await {
+                Err(DomainForgeError::Parse { message, content, .. }) if retries < config.parse_retry_attempts => {
+                    retries += 1;
+                    tracing::warn!(
+                        provider = %provider_name,
+                        retry = retries,
+                        error = %message,
+                        content = %content.as_deref().unwrap_or_default(),
+                        "Unparseable LLM response, retrying"
+                    );
+                    self.metrics.increment_api_calls();
+                    attempt_config = Some(malformed_json_retry_config(config, content.as_deref()));
+                }
+                Err(DomainForgeError::Parse { message, content, .. }) => {
+                    break Err(DomainForgeError::Parse { message, content, retry_count: retries });
+                }
+                other => break other,
+            }
+        };
         
+        let result = result.map(|domains| {
+            let domains = match &config.content_filter {
+                Some(filter) => filter.filter(domains),
+                None => domains,
+            };
+            score_phonetics(domains, config.target_phonetics.as_deref())
+        });
+
         match &result {
             Ok(domains) => {
                 self.metrics.increment_domains_generated();
@@ -81,6 +152,7 @@ impl DomainGenerator {
                 tracing::info!(
                     provider = %provider_name,
                     domains_count = %domains.len(),
+                    parse_retries = retries,
                     duration_ms = %elapsed.as_millis(),
                     "Domain generation completed"
                 );
@@ -99,8 +171,58 @@ impl DomainGenerator {
         result
     }
     
-    /// Generate with fallback to other providers (enhanced with metrics)
+    /// Generate with fallback to other providers (enhanced with metrics).
+    ///
+    /// With `use_llm_scoring`, the suggestions are re-ranked by
+    /// [`DomainGenerator::score_domains`]; if scoring fails they are returned
+    /// in their original order.
     pub async fn generate_with_fallback(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+        let suggestions = self.generate_unscored_with_fallback(config).await?;
+        if !config.use_llm_scoring || suggestions.is_empty() {
+            return Ok(suggestions);
+        }
+
+        match self.score_domains(&suggestions, config).await {
+            Ok(scored) => Ok(scored.into_iter().map(|(suggestion, _)| suggestion).collect()),
+            Err(e) => {
+                tracing::warn!(error = %e, "LLM scoring failed, keeping generation order");
+                Ok(suggestions)
+            }
+        }
+    }
+
+    /// Ask the LLM to score each suggestion from 0 to 10 on memorability,
+    /// relevance to the description and brandability.
+    ///
+    /// Returns every suggestion with its score, best first; suggestions the
+    /// LLM leaves out score 0.0 and names it invents are ignored.
+    pub async fn score_domains(
+        &self,
+        suggestions: &[DomainSuggestion],
+        config: &GenerationConfig,
+    ) -> Result<Vec<(DomainSuggestion, f32)>> {
+        let scores: HashMap<String, f32> = self
+            .generate_unscored_with_fallback(&scoring_config(suggestions, config))
+            .await?
+            .into_iter()
+            .map(|scored| (scored.get_full_domain().to_lowercase(), scored.confidence.clamp(0.0, 10.0)))
+            .collect();
+
+        let mut scored: Vec<(DomainSuggestion, f32)> = suggestions
+            .iter()
+            .map(|suggestion| {
+                let score = scores
+                    .get(&suggestion.get_full_domain().to_lowercase())
+                    .copied()
+                    .unwrap_or(0.0);
+                (suggestion.clone(), score)
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(scored)
+    }
+
+    async fn generate_unscored_with_fallback(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
         let mut last_error = None;
         let overall_start = Instant::now();
 
@@ -157,12 +279,128 @@ impl DomainGenerator {
         }))
     }
 
+    /// Query every configured provider concurrently and merge the results.
+    ///
+    /// Duplicates keep the highest-confidence instance; the merged list is
+    /// sorted by confidence, highest first. Fails only if every provider fails.
+    pub async fn generate_with_all_providers(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+        let names = self.available_providers();
+        if names.is_empty() {
+            return Err(DomainForgeError::config("No providers configured".to_string()));
+        }
+
+        let overall_start = Instant::now();
+        let results = join_all(names.iter().map(|name| async move {
+            match tokio::time::timeout(self.provider_timeout, self.generate_with_provider(config, name)).await {
+                Ok(result) => result,
+                Err(_) => {
+                    tracing::warn!(provider = %name, timeout_secs = self.provider_timeout.as_secs(), "Provider timed out");
+                    Err(DomainForgeError::timeout(
+                        format!("{} domain generation", name),
+                        self.provider_timeout.as_secs(),
+                    ))
+                }
+            }
+        }))
+        .await;
+
+        let mut batches = Vec::new();
+        let mut last_error = None;
+        for result in results {
+            match result {
+                Ok(batch) => batches.push(batch),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        if batches.is_empty() {
+            return Err(last_error.unwrap_or_else(|| DomainForgeError::internal("No provider returned results")));
+        }
+
+        let merged = merge_suggestions(batches);
+        tracing::info!(
+            providers = names.len(),
+            domains_count = merged.len(),
+            duration_ms = %overall_start.elapsed().as_millis(),
+            "Merged domains from all providers"
+        );
+        Ok(merged)
+    }
+
+    /// Run every configured provider's health check concurrently.
+    ///
+    /// Returns each provider's result keyed by name; checks that take longer
+    /// than the provider timeout fail with a timeout error.
+    pub async fn check_all_providers(&self) -> HashMap<String, Result<()>> {
+        let providers: Vec<(String, Arc<dyn LlmProvider>)> = self
+            .providers
+            .read()
+            .iter()
+            .map(|(name, provider)| (name.clone(), Arc::clone(provider)))
+            .collect();
+
+        let checks = providers.into_iter().map(|(name, provider)| async move {
+            let result = match tokio::time::timeout(self.provider_timeout, provider.health_check()).await {
+                Ok(result) => result,
+                Err(_) => Err(DomainForgeError::timeout(
+                    format!("{} health check", name),
+                    self.provider_timeout.as_secs(),
+                )),
+            };
+            if let Err(e) = &result {
+                tracing::warn!(provider = %name, error = %e, "Provider health check failed");
+            }
+            (name, result)
+        });
+        join_all(checks).await.into_iter().collect()
+    }
+
+    /// Ask the LLM for variations of one suggestion, e.g. `sparkfire.io` →
+    /// `getsparkfire.com`, `spark-fire.com`, `sparkfireapp.io`.
+    ///
+    /// `config` supplies the provider settings, count and TLDs (the base TLD is
+    /// always included). The base domain itself is never returned, and every
+    /// suggestion's `reasoning` names the transformation applied.
+    pub async fn generate_variations(
+        &self,
+        base_domain: &DomainSuggestion,
+        config: &GenerationConfig,
+    ) -> Result<Vec<DomainSuggestion>> {
+        let base = base_domain.get_full_domain().to_lowercase();
+        let variation_config = variations_config(base_domain, config);
+
+        let variations = self
+            .generate_with_fallback(&variation_config)
+            .await?
+            .into_iter()
+            .filter(|d| d.get_full_domain().to_lowercase() != base)
+            .map(|mut d| {
+                if d.reasoning.as_deref().is_none_or(|r| r.trim().is_empty()) {
+                    d.reasoning = Some(format!("Variation of {}", base));
+                }
+                d
+            })
+            .collect();
+        Ok(variations)
+    }
+
+    /// Get the default provider name (thread-safe)
+    pub fn default_provider(&self) -> String {
+        self.default_provider.read().clone()
+    }
+
     /// Get available providers (thread-safe)
     pub fn available_providers(&self) -> Vec<String> {
         let providers = self.providers.read();
         providers.keys().cloned().collect()
     }
 
+    /// Settings of every configured provider, sorted by name
+    pub fn provider_configs(&self) -> Vec<LlmConfig> {
+        let mut configs: Vec<LlmConfig> = self.configs.read().values().cloned().collect();
+        configs.sort_by(|a, b| a.provider.cmp(&b.provider));
+        configs
+    }
+
     /// Check if provider is available (thread-safe)
     pub fn has_provider(&self, provider: &str) -> bool {
         let providers = self.providers.read();
@@ -192,5 +430,416 @@ impl Default for DomainGenerator {
     }
 }
 
+/// Set `phonetic_score` to the best similarity against any word of `target`
+fn score_phonetics(mut domains: Vec<DomainSuggestion>, target: Option<&str>) -> Vec<DomainSuggestion> {
+    let Some(target) = target.filter(|t| !t.trim().is_empty()) else {
+        return domains;
+    };
+    for domain in &mut domains {
+        let score = target
+            .split_whitespace()
+            .map(|word| phonetic_similarity(&domain.name, word))
+            .fold(0.0, f32::max);
+        domain.phonetic_score = Some(score);
+    }
+    domains
+}
+
+/// Copy of `config` whose prompt quotes the bad response and asks for plain JSON
+fn malformed_json_retry_config(config: &GenerationConfig, bad_content: Option<&str>) -> GenerationConfig {
+    let mut notice = String::new();
+    if let Some(suffix) = config.user_prompt_suffix.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        notice.push_str(suffix);
+        notice.push_str("\n\n");
+    }
+    if let Some(content) = bad_content.map(str::trim).filter(|s| !s.is_empty()) {
+        notice.push_str("Your last response was:\n");
+        notice.push_str(content);
+        notice.push_str("\n\n");
+    }
+    notice.push_str(MALFORMED_JSON_NOTICE);
+
+    GenerationConfig {
+        user_prompt_suffix: Some(notice),
+        ..config.clone()
+    }
+}
+
+/// Copy of `config` whose prompt asks for variations of `base` instead of new names
+fn variations_config(base: &DomainSuggestion, config: &GenerationConfig) -> GenerationConfig {
+    let configured = if tld_recommender::is_auto(&config.tlds) {
+        tld_recommender::recommend_tlds(&config.description)
+    } else {
+        config.tlds.clone()
+    };
+    let mut tlds = vec![base.tld.to_lowercase()];
+    for tld in configured {
+        if !tlds.contains(&tld) {
+            tlds.push(tld);
+        }
+    }
+
+    let instructions = format!(
+        "Base domain: {full}\n\
+         Only produce variations of \"{name}\", using these transformations:\n\
+         - same name with a different TLD\n\
+         - prefixed with the, my, get or try (e.g. get{name})\n\
+         - suffixed with app, hq or pro (e.g. {name}app)\n\
+         - with or without hyphens between words\n\
+         Do not return {full} itself. In \"reasoning\", name the transformation applied (e.g. \"prefix: get\", \"suffix: app\", \"TLD: .com\", \"hyphenated\").",
+        full = base.get_full_domain(),
+        name = base.name,
+    );
+
+    GenerationConfig {
+        description: format!("variations of the domain name {}", base.get_full_domain()),
+        tlds,
+        avoid_names: Vec::new(),
+        system_prompt_template: Some(VARIATIONS_SYSTEM_PROMPT.to_string()),
+        user_prompt_suffix: Some(instructions),
+        target_phonetics: None,
+        ..config.clone()
+    }
+}
+
+/// Copy of `config` whose prompt asks for 0-10 scores of `suggestions`,
+/// returned in the `confidence` field
+fn scoring_config(suggestions: &[DomainSuggestion], config: &GenerationConfig) -> GenerationConfig {
+    let domains: Vec<String> = suggestions.iter().map(DomainSuggestion::get_full_domain).collect();
+    let mut tlds: Vec<String> = Vec::new();
+    for suggestion in suggestions {
+        if !tlds.contains(&suggestion.tld) {
+            tlds.push(suggestion.tld.clone());
+        }
+    }
+
+    let instructions = format!(
+        "Do not suggest new names. Score each of these domain names instead:\n{}\n\n\
+         Give every domain a score from 0 to 10 (10 is best) for how memorable it is, \
+         how relevant it is to \"{}\" and how brandable it is. \
+         Put the score in \"confidence\" and explain it briefly in \"reasoning\".",
+        domains.join("\n"),
+        config.description,
+    );
+
+    GenerationConfig {
+        count: suggestions.len(),
+        description: format!("scoring these domain names for: {}", config.description),
+        tlds,
+        avoid_names: Vec::new(),
+        system_prompt_template: Some(SCORING_SYSTEM_PROMPT.to_string()),
+        user_prompt_suffix: Some(instructions),
+        min_name_length: None,
+        max_name_length: None,
+        target_phonetics: None,
+        content_filter: None,
+        examples: Vec::new(),
+        negative_examples: Vec::new(),
+        use_llm_scoring: false,
+        ..config.clone()
+    }
+}
+
+/// Deduplicate by full domain (keeping the higher confidence) and sort by confidence
+fn merge_suggestions(batches: Vec<Vec<DomainSuggestion>>) -> Vec<DomainSuggestion> {
+    let mut best: HashMap<String, DomainSuggestion> = HashMap::new();
+    for suggestion in batches.into_iter().flatten() {
+        let key = suggestion.get_full_domain().to_lowercase();
+        match best.get(&key) {
+            Some(existing) if existing.confidence >= suggestion.confidence => {}
+            _ => {
+                best.insert(key, suggestion);
+            }
+        }
+    }
+
+    let mut merged: Vec<DomainSuggestion> = best.into_values().collect();
+    merged.sort_by(|a, b| {
+        b.confidence
+            .total_cmp(&a.confidence)
+            .then_with(|| a.get_full_domain().cmp(&b.get_full_domain()))
+    });
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ContentFilter;
+    use async_trait::async_trait;
+
+    struct MockProvider {
+        name: &'static str,
+        suggestions: Vec<(&'static str, f32)>,
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl LlmProvider for MockProvider {
+        async fn generate_domains(&self, _config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+            tokio::time::sleep(self.delay).await;
+            Ok(self
+                .suggestions
+                .iter()
+                .map(|(name, confidence)| DomainSuggestion::new(*name, "com", *confidence, None::<String>))
+                .collect())
+        }
+
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn model(&self) -> &str {
+            "mock"
+        }
+
+        fn is_ready(&self) -> bool {
+            true
+        }
+
+        async fn health_check(&self) -> Result<()> {
+            tokio::time::sleep(self.delay).await;
+            if self.suggestions.is_empty() {
+                return Err(DomainForgeError::network("Authentication failed (401)", Some(401), None));
+            }
+            Ok(())
+        }
+    }
 
+    fn add_mock(generator: &DomainGenerator, name: &'static str, suggestions: Vec<(&'static str, f32)>, delay: Duration) {
+        generator
+            .providers
+            .write()
+            .insert(name.to_string(), Arc::new(MockProvider { name, suggestions, delay }));
+    }
+
+    #[tokio::test]
+    async fn test_generate_with_all_providers_merges() {
+        let generator = DomainGenerator::new().with_provider_timeout(Duration::from_millis(200));
+        add_mock(&generator, "a", vec![("forge", 0.6), ("spark", 0.9)], Duration::ZERO);
+        add_mock(&generator, "b", vec![("forge", 0.8), ("pixel", 0.7)], Duration::ZERO);
+        add_mock(&generator, "slow", vec![("never", 1.0)], Duration::from_secs(5));
+
+        let merged = generator.generate_with_all_providers(&GenerationConfig::default()).await.unwrap();
+        let names: Vec<_> = merged.iter().map(|d| (d.get_full_domain(), d.confidence)).collect();
+        assert_eq!(names, vec![
+            ("spark.com".to_string(), 0.9),
+            ("forge.com".to_string(), 0.8),
+            ("pixel.com".to_string(), 0.7),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_phonetic_scores_with_target() {
+        let generator = DomainGenerator::new();
+        add_mock(&generator, "a", vec![("smyth", 0.8), ("zebra", 0.7)], Duration::ZERO);
+
+        let domains = generator.generate_with_provider(&GenerationConfig::default(), "a").await.unwrap();
+        assert!(domains.iter().all(|d| d.phonetic_score.is_none()));
+
+        let config = GenerationConfig { target_phonetics: Some("Smith tools".to_string()), ..Default::default() };
+        let domains = generator.generate_with_provider(&config, "a").await.unwrap();
+        assert_eq!(domains[0].phonetic_score, Some(1.0));
+        assert!(domains[1].phonetic_score.unwrap() < 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_content_filter_applied() {
+        let generator = DomainGenerator::new();
+        add_mock(&generator, "a", vec![("forge", 0.8), ("acmeforge", 0.7)], Duration::ZERO);
+
+        let config = GenerationConfig {
+            content_filter: Some(ContentFilter::new().with_extra_words(vec!["ACME".to_string()])),
+            ..Default::default()
+        };
+        let domains = generator.generate_with_provider(&config, "a").await.unwrap();
+        assert_eq!(domains.len(), 1);
+        assert_eq!(domains[0].name, "forge");
+    }
+
+    #[tokio::test]
+    async fn test_check_all_providers() {
+        let generator = DomainGenerator::new().with_provider_timeout(Duration::from_millis(200));
+        add_mock(&generator, "healthy", vec![("forge", 0.8)], Duration::ZERO);
+        add_mock(&generator, "bad_key", vec![], Duration::ZERO);
+        add_mock(&generator, "slow", vec![("never", 1.0)], Duration::from_secs(5));
+
+        let report = generator.check_all_providers().await;
+        assert_eq!(report.len(), 3);
+        assert!(report["healthy"].is_ok());
+        assert!(matches!(report["bad_key"], Err(DomainForgeError::Network { status_code: Some(401), .. })));
+        assert!(matches!(report["slow"], Err(DomainForgeError::Timeout { .. })));
+    }
 
+    #[test]
+    fn test_provider_configs() {
+        let generator = DomainGenerator::new();
+        for (provider, api_key) in [("openai", "sk-proj-abcdef123456"), ("anthropic", "short")] {
+            let config = LlmConfig {
+                provider: provider.to_string(),
+                api_key: api_key.to_string(),
+                ..Default::default()
+            };
+            generator.add_provider(&config).unwrap();
+        }
+
+        let configs = generator.provider_configs();
+        let names: Vec<&str> = configs.iter().map(|c| c.provider.as_str()).collect();
+        assert_eq!(names, ["anthropic", "openai"]);
+        assert_eq!(configs[0].redacted_api_key(), "*****");
+        assert_eq!(configs[1].redacted_api_key(), "sk-...456");
+        assert_eq!(LlmConfig::default().redacted_api_key(), "(none)");
+    }
+
+    #[tokio::test]
+    async fn test_generate_with_all_providers_requires_providers() {
+        let generator = DomainGenerator::new();
+        assert!(generator.generate_with_all_providers(&GenerationConfig::default()).await.is_err());
+    }
+
+    /// Returns garbage for the first `bad_responses` calls and records the prompts it saw
+    struct MalformedProvider {
+        bad_responses: usize,
+        prompts: parking_lot::Mutex<Vec<Option<String>>>,
+    }
+
+    #[async_trait]
+    impl LlmProvider for MalformedProvider {
+        async fn generate_domains(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+            let mut prompts = self.prompts.lock();
+            prompts.push(config.user_prompt_suffix.clone());
+            if prompts.len() <= self.bad_responses {
+                return crate::llm::providers::parse_domain_suggestions("```json\n[{\"name\": ", config);
+            }
+            Ok(vec![DomainSuggestion::new("forge", "com", 0.9, None::<String>)])
+        }
+
+        fn name(&self) -> &'static str {
+            "malformed"
+        }
+
+        fn model(&self) -> &str {
+            "mock"
+        }
+
+        fn is_ready(&self) -> bool {
+            true
+        }
+
+        async fn health_check(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn add_malformed(generator: &DomainGenerator, bad_responses: usize) -> Arc<MalformedProvider> {
+        let provider = Arc::new(MalformedProvider { bad_responses, prompts: Default::default() });
+        generator.providers.write().insert("malformed".to_string(), provider.clone());
+        provider
+    }
+
+    #[tokio::test]
+    async fn test_parse_retry_with_modified_prompt() {
+        let generator = DomainGenerator::new();
+        let provider = add_malformed(&generator, 1);
+
+        let domains = generator.generate_with_provider(&GenerationConfig::default(), "malformed").await.unwrap();
+        assert_eq!(domains[0].get_full_domain(), "forge.com");
+
+        let prompts = provider.prompts.lock();
+        assert_eq!(prompts.len(), 2);
+        assert!(prompts[0].is_none());
+        let retry_prompt = prompts[1].as_deref().unwrap();
+        assert!(retry_prompt.contains("Your last response was:\n[{\"name\":"));
+        assert!(retry_prompt.ends_with(MALFORMED_JSON_NOTICE));
+    }
+
+    #[tokio::test]
+    async fn test_generate_variations() {
+        let generator = DomainGenerator::new();
+        add_mock(&generator, "a", vec![("sparkfire", 0.9), ("getsparkfire", 0.8)], Duration::ZERO);
+
+        let base = DomainSuggestion::new("sparkfire", "com", 0.9, None::<String>);
+        let variations = generator.generate_variations(&base, &GenerationConfig::default()).await.unwrap();
+        assert_eq!(variations.len(), 1);
+        assert_eq!(variations[0].get_full_domain(), "getsparkfire.com");
+        assert_eq!(variations[0].reasoning.as_deref(), Some("Variation of sparkfire.com"));
+    }
+
+    #[test]
+    fn test_variations_prompt() {
+        let base = DomainSuggestion::new("sparkfire", "io", 0.9, None::<String>);
+        let config = GenerationConfig { tlds: vec!["com".to_string(), "io".to_string()], ..Default::default() };
+        let variation = variations_config(&base, &config);
+
+        assert_eq!(variation.tlds, vec!["io", "com"]);
+        assert_eq!(variation.system_prompt_template.as_deref(), Some(VARIATIONS_SYSTEM_PROMPT));
+        let prompt = crate::llm::providers::build_domain_prompt(&variation);
+        assert!(prompt.contains("variations of the domain name sparkfire.io"));
+        assert!(prompt.contains("prefixed with the, my, get or try"));
+        assert!(prompt.contains("suffixed with app, hq or pro"));
+        assert!(prompt.contains("Do not return sparkfire.io itself"));
+    }
+
+    #[tokio::test]
+    async fn test_score_domains_ordering() {
+        let generator = DomainGenerator::new();
+        // The mock answers every prompt alike; its confidences act as the LLM's scores
+        add_mock(&generator, "a", vec![("forge", 3.0), ("spark", 9.5), ("invented", 7.0), ("pixel", 42.0)], Duration::ZERO);
+
+        let suggestions: Vec<_> = ["forge", "spark", "anvil", "pixel"]
+            .iter()
+            .map(|name| DomainSuggestion::new(*name, "com", 0.8, None::<String>))
+            .collect();
+        let scored = generator.score_domains(&suggestions, &GenerationConfig::default()).await.unwrap();
+        let ranked: Vec<_> = scored.iter().map(|(d, score)| (d.name.as_str(), *score)).collect();
+        assert_eq!(ranked, vec![("pixel", 10.0), ("spark", 9.5), ("forge", 3.0), ("anvil", 0.0)]);
+        // The suggestions themselves are unchanged
+        assert!(scored.iter().all(|(d, _)| d.confidence == 0.8));
+
+        let config = GenerationConfig { use_llm_scoring: true, ..Default::default() };
+        let ranked = generator.generate_with_fallback(&config).await.unwrap();
+        let names: Vec<_> = ranked.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["pixel", "spark", "invented", "forge"]);
+
+        let unscored = generator.generate_with_fallback(&GenerationConfig::default()).await.unwrap();
+        assert_eq!(unscored[0].name, "forge");
+    }
+
+    #[test]
+    fn test_scoring_prompt() {
+        let suggestions = vec![
+            DomainSuggestion::new("forge", "com", 0.8, None::<String>),
+            DomainSuggestion::new("anvil", "io", 0.7, None::<String>),
+        ];
+        let config = GenerationConfig {
+            description: "metalworking tools".to_string(),
+            use_llm_scoring: true,
+            max_name_length: Some(3),
+            ..Default::default()
+        };
+        let scoring = scoring_config(&suggestions, &config);
+
+        assert_eq!(scoring.count, 2);
+        assert_eq!(scoring.tlds, vec!["com", "io"]);
+        assert!(!scoring.use_llm_scoring);
+        assert!(scoring.max_name_length.is_none());
+        assert_eq!(scoring.system_prompt_template.as_deref(), Some(SCORING_SYSTEM_PROMPT));
+        let prompt = crate::llm::providers::build_domain_prompt(&scoring);
+        assert!(prompt.contains("Score each of these domain names instead:\nforge.com\nanvil.io"));
+        assert!(prompt.contains("score from 0 to 10"));
+        assert!(prompt.contains("memorable"));
+        assert!(prompt.contains("relevant it is to \"metalworking tools\""));
+        assert!(prompt.contains("brandable"));
+    }
+
+    #[tokio::test]
+    async fn test_parse_retry_gives_up() {
+        let generator = DomainGenerator::new();
+        let provider = add_malformed(&generator, usize::MAX);
+
+        let config = GenerationConfig { parse_retry_attempts: 1, ..Default::default() };
+        let err = generator.generate_with_provider(&config, "malformed").await.unwrap_err();
+        assert!(matches!(err, DomainForgeError::Parse { retry_count: 1, .. }));
+        assert_eq!(provider.prompts.lock().len(), 2);
+    }
+}
@@ -3,14 +3,17 @@
 //! Simple and elegant interface for generating domain names using AI.
 
 pub mod generator;
+pub mod pricing;
 pub mod providers;
+pub mod tld_recommender;
 
 // Re-export main functionality
 pub use generator::DomainGenerator;
 
 use crate::error::Result;
-use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig};
+use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig, PerformanceMetrics};
 use async_trait::async_trait;
+use std::sync::Arc;
 
 /// Core trait for all LLM providers
 #[async_trait]
@@ -26,13 +29,19 @@ pub trait LlmProvider: Send + Sync {
     
     /// Check if provider is configured and ready
     fn is_ready(&self) -> bool;
+
+    /// Verify the API key and model with a minimal request
+    async fn health_check(&self) -> Result<()>;
+
+    /// Report token usage to these metrics
+    fn attach_metrics(&mut self, _metrics: Arc<PerformanceMetrics>) {}
 }
 
 
 
 /// Get available LLM providers
 pub fn available_providers() -> Vec<&'static str> {
-    vec!["openai", "anthropic", "gemini", "ollama"]
+    vec!["openai", "anthropic", "gemini", "ollama", "openrouter", "generic"]
 }
 
 /// Create an LLM provider from configuration
@@ -42,6 +51,8 @@ pub fn create_provider(config: &LlmConfig) -> Result<Box<dyn LlmProvider>> {
         "anthropic" => Ok(Box::new(providers::AnthropicProvider::new(config)?)),
         "gemini" => Ok(Box::new(providers::GeminiProvider::new(config)?)),
         "ollama" => Ok(Box::new(providers::OllamaProvider::new(config)?)),
+        "openrouter" => Ok(Box::new(providers::OpenRouterProvider::new(config)?)),
+        "generic" => Ok(Box::new(providers::GenericProvider::new(config)?)),
         _ => Err(crate::error::DomainForgeError::config(
             format!("Unsupported LLM provider: {}. Supported providers: {}",
                 config.provider,
@@ -0,0 +1,75 @@
+//! Approximate per-model LLM pricing for cost estimates
+//!
+//! Prices are USD per million tokens and only meant as rough feedback;
+//! unknown and local models are treated as free.
+
+/// (model prefix, input price, output price) in USD per million tokens
+const PRICING: &[(&str, f64, f64)] = &[
+    ("gpt-4.1-nano", 0.10, 0.40),
+    ("gpt-4.1-mini", 0.40, 1.60),
+    ("gpt-4.1", 2.00, 8.00),
+    ("gpt-4o-mini", 0.15, 0.60),
+    ("gpt-4o", 2.50, 10.00),
+    ("claude-opus-4", 15.00, 75.00),
+    ("claude-4-opus", 15.00, 75.00),
+    ("claude-sonnet-4", 3.00, 15.00),
+    ("claude-4-sonnet", 3.00, 15.00),
+    ("claude-3-5-haiku", 0.80, 4.00),
+    ("gemini-2.5-pro", 1.25, 10.00),
+    ("gemini-2.5-flash", 0.30, 2.50),
+];
+
+/// Price of a model in USD per million tokens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelPricing {
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+/// Look up pricing by the longest matching model prefix.
+///
+/// Vendor-namespaced names (`openai/gpt-4.1-mini`, as used by OpenRouter)
+/// are matched on the part after the slash.
+pub fn lookup(model: &str) -> Option<ModelPricing> {
+    let model = model.to_lowercase();
+    let model = model.rsplit('/').next().unwrap_or_default();
+    PRICING
+        .iter()
+        .filter(|(prefix, _, _)| model.starts_with(prefix))
+        .max_by_key(|(prefix, _, _)| prefix.len())
+        .map(|&(_, input, output)| ModelPricing {
+            input_per_million: input,
+            output_per_million: output,
+        })
+}
+
+/// Estimated cost of a request in microdollars (0 for unknown models)
+pub fn estimate_cost_microdollars(model: &str, prompt_tokens: u64, completion_tokens: u64) -> u64 {
+    // Price per million tokens in USD equals price per token in microdollars
+    lookup(model)
+        .map(|p| {
+            (prompt_tokens as f64 * p.input_per_million + completion_tokens as f64 * p.output_per_million).round()
+                as u64
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_prefix_wins() {
+        assert_eq!(lookup("gpt-4.1-mini-2025-04-14").unwrap().input_per_million, 0.40);
+        assert_eq!(lookup("gpt-4.1").unwrap().input_per_million, 2.00);
+        assert_eq!(lookup("openai/gpt-4o-mini").unwrap().output_per_million, 0.60);
+        assert!(lookup("deepseek-r1").is_none());
+    }
+
+    #[test]
+    fn test_estimate_cost() {
+        // 1000 * $0.40/M + 500 * $1.60/M = $0.0012
+        assert_eq!(estimate_cost_microdollars("gpt-4.1-mini", 1000, 500), 1200);
+        assert_eq!(estimate_cost_microdollars("llama3", 1000, 500), 0);
+    }
+}
@@ -4,13 +4,53 @@
 
 use crate::error::{DomainForgeError, Result};
 use crate::llm::LlmProvider;
-use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig};
+use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig, PerformanceMetrics};
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Duration;
 
-use super::{build_domain_prompt, parse_domain_suggestions};
+use super::{
+    build_domain_prompt, build_system_prompt, generation_max_tokens, parse_domain_suggestions_lenient, record_usage,
+    suggestions_from_raw, StructuredSuggestions,
+};
+
+const DEFAULT_MAX_TOKENS: u32 = 1000;
+
+/// Tool Claude is asked to call with its suggestions
+const SUGGESTIONS_TOOL: &str = "generate_domain_suggestions";
+
+/// Definition of [`SUGGESTIONS_TOOL`]; its input schema is the tool-use
+/// equivalent of the JSON array requested in text mode
+fn suggestions_tool() -> serde_json::Value {
+    serde_json::json!({
+        "name": SUGGESTIONS_TOOL,
+        "description": "Return the generated domain name suggestions.",
+        "input_schema": {
+            "type": "object",
+            "properties": {
+                "domains": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "name": {
+                                "type": "string",
+                                "pattern": "^[^.\\s]+\\.[^\\s]+$",
+                                "description": "Complete domain name with TLD, e.g. example.com"
+                            },
+                            "reasoning": { "type": "string" },
+                            "confidence": { "type": "number", "minimum": 0, "maximum": 1 }
+                        },
+                        "required": ["name", "confidence"]
+                    }
+                }
+            },
+            "required": ["domains"]
+        }
+    })
+}
 
 /// Anthropic provider implementation
 pub struct AnthropicProvider {
@@ -19,6 +59,9 @@ pub struct AnthropicProvider {
     model: String,
     base_url: String,
     temperature: f32,
+    max_tokens: Option<u32>,
+    metrics: Option<Arc<PerformanceMetrics>>,
+    structured_output: bool,
 }
 
 impl AnthropicProvider {
@@ -38,32 +81,128 @@ impl AnthropicProvider {
             model: config.model.clone(),
             base_url: config.base_url.clone().unwrap_or_else(|| "https://api.anthropic.com/v1".to_string()),
             temperature: config.temperature,
+            max_tokens: config.max_tokens,
+            metrics: None,
+            structured_output: config.use_structured_output,
         })
     }
+
+    /// Generate suggestions through a forced call to the suggestions tool,
+    /// whose input Claude must shape after the tool's JSON schema
+    pub async fn generate_with_tools(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+        let request = AnthropicRequest {
+            tools: vec![suggestions_tool()],
+            tool_choice: Some(serde_json::json!({ "type": "tool", "name": SUGGESTIONS_TOOL })),
+            ..self.generation_request(config)
+        };
+        let response = self.send(&request).await?;
+        self.record(&response);
+        tool_suggestions(&response, config)
+    }
+
+    /// Generate suggestions by parsing JSON from a text reply
+    async fn generate_with_text(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+        let response = self.send(&self.generation_request(config)).await?;
+        self.record(&response);
+
+        let content = response.content.iter()
+            .find(|block| block.kind == "text")
+            .ok_or_else(|| DomainForgeError::internal("No response from Anthropic API".to_string()))?
+            .text.clone();
+
+        parse_domain_suggestions_lenient(&content, config)
+    }
+
+    fn generation_request(&self, config: &GenerationConfig) -> AnthropicRequest {
+        AnthropicRequest {
+            model: self.model.clone(),
+            system: build_system_prompt(config),
+            messages: vec![AnthropicMessage {
+                role: "user".to_string(),
+                content: build_domain_prompt(config),
+            }],
+            temperature: self.temperature,
+            max_tokens: generation_max_tokens(self.max_tokens, DEFAULT_MAX_TOKENS, config.count),
+            tools: Vec::new(),
+            tool_choice: None,
+        }
+    }
+
+    fn record(&self, response: &AnthropicResponse) {
+        if let Some(usage) = &response.usage {
+            record_usage(self.metrics.as_deref(), &self.model, usage.input_tokens, usage.output_tokens);
+        }
+    }
+}
+
+/// Suggestions from the `tool_use` block calling [`SUGGESTIONS_TOOL`]
+fn tool_suggestions(response: &AnthropicResponse, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+    let input = response.content.iter()
+        .find(|block| block.kind == "tool_use" && block.name.as_deref() == Some(SUGGESTIONS_TOOL))
+        .and_then(|block| block.input.clone())
+        .ok_or_else(|| DomainForgeError::parse(
+            format!("Anthropic response has no {} tool call", SUGGESTIONS_TOOL),
+            None,
+        ))?;
+    let content = input.to_string();
+    let parsed: StructuredSuggestions = serde_json::from_value(input)
+        .map_err(|e| DomainForgeError::parse(format!("Tool input did not match the schema: {}", e), Some(content.clone())))?;
+    suggestions_from_raw(parsed.domains, &content, config)
 }
 
 #[async_trait]
 impl LlmProvider for AnthropicProvider {
     async fn generate_domains(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
-        let prompt = build_domain_prompt(config);
-        
+        if self.structured_output {
+            self.generate_with_tools(config).await
+        } else {
+            self.generate_with_text(config).await
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "anthropic"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    fn attach_metrics(&mut self, metrics: Arc<PerformanceMetrics>) {
+        self.metrics = Some(metrics);
+    }
+
+    fn is_ready(&self) -> bool {
+        !self.api_key.is_empty()
+    }
+
+    async fn health_check(&self) -> Result<()> {
         let request = AnthropicRequest {
             model: self.model.clone(),
+            system: String::new(),
             messages: vec![AnthropicMessage {
                 role: "user".to_string(),
-                content: prompt,
+                content: "ping".to_string(),
             }],
             temperature: self.temperature,
-            max_tokens: 1000,
+            max_tokens: 1,
+            tools: Vec::new(),
+            tool_choice: None,
         };
+        self.send(&request).await.map(|_| ())
+    }
+}
 
+impl AnthropicProvider {
+    /// POST to `/messages`, mapping HTTP failures to network errors
+    async fn send(&self, request: &AnthropicRequest) -> Result<AnthropicResponse> {
         let url = format!("{}/messages", self.base_url);
         let response = self.client
             .post(&url)
             .header("x-api-key", &self.api_key)
             .header("anthropic-version", "2023-06-01")
             .header("Content-Type", "application/json")
-            .json(&request)
+            .json(request)
             .send()
             .await
             .map_err(|e| DomainForgeError::network(
@@ -91,26 +230,8 @@ impl LlmProvider for AnthropicProvider {
             ));
         }
 
-        let anthropic_response: AnthropicResponse = response.json().await
-            .map_err(|e| DomainForgeError::parse(e.to_string(), None))?;
-
-        let content = anthropic_response.content.get(0)
-            .ok_or_else(|| DomainForgeError::internal("No response from Anthropic API".to_string()))?
-            .text.clone();
-
-        parse_domain_suggestions(&content, config)
-    }
-
-    fn name(&self) -> &'static str {
-        "anthropic"
-    }
-
-    fn model(&self) -> &str {
-        &self.model
-    }
-
-    fn is_ready(&self) -> bool {
-        !self.api_key.is_empty()
+        response.json().await
+            .map_err(|e| DomainForgeError::parse(e.to_string(), None))
     }
 }
 
@@ -118,9 +239,15 @@ impl LlmProvider for AnthropicProvider {
 #[derive(Serialize)]
 struct AnthropicRequest {
     model: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    system: String,
     messages: Vec<AnthropicMessage>,
     temperature: f32,
     max_tokens: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tools: Vec<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<serde_json::Value>,
 }
 
 #[derive(Serialize)]
@@ -132,9 +259,95 @@ struct AnthropicMessage {
 #[derive(Deserialize)]
 struct AnthropicResponse {
     content: Vec<AnthropicContent>,
+    usage: Option<AnthropicUsage>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicUsage {
+    #[serde(default)]
+    input_tokens: u64,
+    #[serde(default)]
+    output_tokens: u64,
 }
 
+/// A content block: `text`, or `tool_use` with the tool's `name` and `input`
 #[derive(Deserialize)]
 struct AnthropicContent {
+    #[serde(rename = "type", default)]
+    kind: String,
+    #[serde(default)]
     text: String,
+    name: Option<String>,
+    input: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(body: serde_json::Value) -> AnthropicResponse {
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn test_tool_use_response() {
+        let reply = response(serde_json::json!({
+            "content": [
+                { "type": "text", "text": "Here are some ideas." },
+                {
+                    "type": "tool_use",
+                    "id": "toolu_01",
+                    "name": "generate_domain_suggestions",
+                    "input": { "domains": [
+                        { "name": "forge.com", "reasoning": "Short", "confidence": 0.9 },
+                        { "name": "anvil.io", "confidence": 0.7 }
+                    ] }
+                }
+            ],
+            "stop_reason": "tool_use",
+            "usage": { "input_tokens": 120, "output_tokens": 40 }
+        }));
+        let suggestions = tool_suggestions(&reply, &GenerationConfig::default()).unwrap();
+        assert_eq!(suggestions.len(), 2);
+        assert_eq!(suggestions[0].get_full_domain(), "forge.com");
+        assert_eq!(suggestions[0].reasoning.as_deref(), Some("Short"));
+        assert_eq!(suggestions[1].confidence, 0.7);
+
+        let text_only = response(serde_json::json!({ "content": [{ "type": "text", "text": "[]" }] }));
+        assert!(matches!(
+            tool_suggestions(&text_only, &GenerationConfig::default()),
+            Err(DomainForgeError::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tool_request() {
+        let provider = AnthropicProvider::new(&LlmConfig { api_key: "key".to_string(), ..Default::default() }).unwrap();
+        assert!(provider.structured_output);
+
+        let tool = suggestions_tool();
+        assert_eq!(tool["name"], SUGGESTIONS_TOOL);
+        let item = &tool["input_schema"]["properties"]["domains"]["items"];
+        assert_eq!(item["required"], serde_json::json!(["name", "confidence"]));
+        assert_eq!(item["properties"]["confidence"]["maximum"], 1);
+        let pattern = regex::Regex::new(item["properties"]["name"]["pattern"].as_str().unwrap()).unwrap();
+        assert!(pattern.is_match("forge.com"));
+        assert!(!pattern.is_match("forge"));
+        assert!(!pattern.is_match(".com"));
+
+        // Text mode leaves the tool fields out of the request entirely
+        let request = serde_json::to_value(provider.generation_request(&GenerationConfig::default())).unwrap();
+        assert!(request.get("tools").is_none());
+        assert!(request.get("tool_choice").is_none());
+        assert_eq!(request["max_tokens"], DEFAULT_MAX_TOKENS);
+
+        let provider = AnthropicProvider::new(&LlmConfig {
+            api_key: "key".to_string(),
+            max_tokens: Some(4096),
+            ..Default::default()
+        })
+        .unwrap();
+        let request = serde_json::to_value(provider.generation_request(&GenerationConfig::default())).unwrap();
+        assert_eq!(request["max_tokens"], 4096);
+    }
 }
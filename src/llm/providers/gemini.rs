@@ -4,13 +4,16 @@
 
 use crate::error::{DomainForgeError, Result};
 use crate::llm::LlmProvider;
-use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig};
+use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig, PerformanceMetrics};
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Duration;
 
-use super::{build_domain_prompt, parse_domain_suggestions};
+use super::{build_domain_prompt, build_system_prompt, generation_max_tokens, parse_domain_suggestions_lenient, record_usage};
+
+const DEFAULT_MAX_TOKENS: u32 = 1000;
 
 /// Google Gemini provider implementation
 pub struct GeminiProvider {
@@ -19,6 +22,8 @@ pub struct GeminiProvider {
     model: String,
     base_url: String,
     temperature: f32,
+    max_tokens: Option<u32>,
+    metrics: Option<Arc<PerformanceMetrics>>,
 }
 
 impl GeminiProvider {
@@ -38,6 +43,8 @@ impl GeminiProvider {
             model: config.model.clone(),
             base_url: config.base_url.clone().unwrap_or_else(|| "https://generativelanguage.googleapis.com/v1beta".to_string()),
             temperature: config.temperature,
+            max_tokens: config.max_tokens,
+            metrics: None,
         })
     }
 }
@@ -48,6 +55,11 @@ impl LlmProvider for GeminiProvider {
         let prompt = build_domain_prompt(config);
         
         let request = GeminiRequest {
+            system_instruction: Some(GeminiContent {
+                parts: vec![GeminiPart {
+                    text: build_system_prompt(config),
+                }],
+            }),
             contents: vec![GeminiContent {
                 parts: vec![GeminiPart {
                     text: prompt,
@@ -55,17 +67,67 @@ impl LlmProvider for GeminiProvider {
             }],
             generation_config: GeminiGenerationConfig {
                 temperature: self.temperature,
-                max_output_tokens: 1000,
+                max_output_tokens: generation_max_tokens(self.max_tokens, DEFAULT_MAX_TOKENS, config.count),
+            },
+        };
+
+        let gemini_response = self.send(&request).await?;
+
+        if let Some(usage) = &gemini_response.usage_metadata {
+            record_usage(self.metrics.as_deref(), &self.model, usage.prompt_token_count, usage.candidates_token_count);
+        }
+        
+        let content = gemini_response.candidates.first()
+            .and_then(|c| c.content.parts.first())
+            .map(|p| p.text.clone())
+            .ok_or_else(|| DomainForgeError::internal("No response from Gemini API".to_string()))?;
+
+        parse_domain_suggestions_lenient(&content, config)
+    }
+
+    fn name(&self) -> &'static str {
+        "gemini"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    fn attach_metrics(&mut self, metrics: Arc<PerformanceMetrics>) {
+        self.metrics = Some(metrics);
+    }
+
+    fn is_ready(&self) -> bool {
+        !self.api_key.is_empty()
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        let request = GeminiRequest {
+            system_instruction: None,
+            contents: vec![GeminiContent {
+                parts: vec![GeminiPart {
+                    text: "ping".to_string(),
+                }],
+            }],
+            generation_config: GeminiGenerationConfig {
+                temperature: self.temperature,
+                max_output_tokens: 1,
             },
         };
+        self.send(&request).await.map(|_| ())
+    }
+}
 
+impl GeminiProvider {
+    /// POST to `:generateContent`, mapping HTTP failures to network errors
+    async fn send(&self, request: &GeminiRequest) -> Result<GeminiResponse> {
         let url = format!("{}/models/{}:generateContent?key={}", 
             self.base_url, self.model, self.api_key);
         
         let response = self.client
             .post(&url)
             .header("Content-Type", "application/json")
-            .json(&request)
+            .json(request)
             .send()
             .await
             .map_err(|e| DomainForgeError::network(
@@ -93,33 +155,16 @@ impl LlmProvider for GeminiProvider {
             ));
         }
 
-        let gemini_response: GeminiResponse = response.json().await
-            .map_err(|e| DomainForgeError::parse(e.to_string(), None))?;
-        
-        let content = gemini_response.candidates.get(0)
-            .and_then(|c| c.content.parts.get(0))
-            .map(|p| p.text.clone())
-            .ok_or_else(|| DomainForgeError::internal("No response from Gemini API".to_string()))?;
-
-        parse_domain_suggestions(&content, config)
-    }
-
-    fn name(&self) -> &'static str {
-        "gemini"
-    }
-
-    fn model(&self) -> &str {
-        &self.model
-    }
-
-    fn is_ready(&self) -> bool {
-        !self.api_key.is_empty()
+        response.json().await
+            .map_err(|e| DomainForgeError::parse(e.to_string(), None))
     }
 }
 
 // Gemini API structures
 #[derive(Serialize)]
 struct GeminiRequest {
+    #[serde(rename = "systemInstruction", skip_serializing_if = "Option::is_none")]
+    system_instruction: Option<GeminiContent>,
     contents: Vec<GeminiContent>,
     #[serde(rename = "generationConfig")]
     generation_config: GeminiGenerationConfig,
@@ -144,16 +189,30 @@ struct GeminiGenerationConfig {
 
 #[derive(Deserialize)]
 struct GeminiResponse {
+    #[serde(default)]
     candidates: Vec<GeminiCandidate>,
+    #[serde(rename = "usageMetadata")]
+    usage_metadata: Option<GeminiUsage>,
+}
+
+#[derive(Deserialize)]
+struct GeminiUsage {
+    #[serde(rename = "promptTokenCount", default)]
+    prompt_token_count: u64,
+    #[serde(rename = "candidatesTokenCount", default)]
+    candidates_token_count: u64,
 }
 
 #[derive(Deserialize)]
 struct GeminiCandidate {
+    // Missing when generation stops early (e.g. the one-token health check)
+    #[serde(default)]
     content: GeminiResponseContent,
 }
 
-#[derive(Deserialize)]
+#[derive(Default, Deserialize)]
 struct GeminiResponseContent {
+    #[serde(default)]
     parts: Vec<GeminiResponsePart>,
 }
 
@@ -0,0 +1,58 @@
+//! Generic provider for any OpenAI-compatible endpoint
+//!
+//! For local inference servers (LM Studio, LocalAI, vLLM) and private
+//! proxies. The API key is optional.
+
+use crate::error::{DomainForgeError, Result};
+use crate::llm::LlmProvider;
+use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig, PerformanceMetrics};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use super::openai_compat::OpenAiCompatibleClient;
+
+/// Generic OpenAI-compatible provider
+pub struct GenericProvider {
+    client: OpenAiCompatibleClient,
+}
+
+impl GenericProvider {
+    pub fn new(config: &LlmConfig) -> Result<Self> {
+        let base_url = config
+            .base_url
+            .as_deref()
+            .filter(|url| !url.trim().is_empty())
+            .ok_or_else(|| DomainForgeError::config("Generic provider requires a base URL".to_string()))?;
+
+        Ok(Self {
+            client: OpenAiCompatibleClient::new(config, base_url, "LLM endpoint")?,
+        })
+    }
+}
+
+#[async_trait]
+impl LlmProvider for GenericProvider {
+    async fn generate_domains(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+        self.client.generate_domains(config).await
+    }
+
+    fn name(&self) -> &'static str {
+        "generic"
+    }
+
+    fn model(&self) -> &str {
+        self.client.model()
+    }
+
+    fn attach_metrics(&mut self, metrics: Arc<PerformanceMetrics>) {
+        self.client.attach_metrics(metrics);
+    }
+
+    fn is_ready(&self) -> bool {
+        true
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.client.health_check().await
+    }
+}
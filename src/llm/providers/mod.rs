@@ -5,17 +5,24 @@
 pub mod openai;
 pub mod anthropic;
 pub mod gemini;
+pub mod generic;
 pub mod ollama;
+pub mod openrouter;
+mod openai_compat;
 
 // Re-export providers for easy access
 pub use openai::OpenAiProvider;
 pub use anthropic::AnthropicProvider;
 pub use gemini::GeminiProvider;
+pub use generic::GenericProvider;
 pub use ollama::OllamaProvider;
+pub use openrouter::OpenRouterProvider;
 
-use crate::error::Result;
-use crate::types::{DomainSuggestion, GenerationConfig};
+use crate::error::{DomainForgeError, Result};
+use crate::types::{DomainSuggestion, GenerationConfig, GenerationStyle, PerformanceMetrics};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
 
 /// Common domain suggestion structure for parsing AI responses
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,8 +32,15 @@ pub struct DomainSuggestionRaw {
     pub confidence: Option<f32>,
 }
 
+/// Suggestions as returned by schema-constrained output (OpenAI structured
+/// output, Anthropic tool use), which must have an object at the root
+#[derive(Debug, Deserialize)]
+pub(crate) struct StructuredSuggestions {
+    pub domains: Vec<DomainSuggestionRaw>,
+}
+
 /// Parse domain suggestions from AI response - trust LLM completely
-pub fn parse_domain_suggestions(content: &str, _config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+pub fn parse_domain_suggestions(content: &str, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
     let json_start = content.find('[').unwrap_or(0);
     let json_end = content.rfind(']').map(|i| i + 1).unwrap_or(content.len());
     let json_content = &content[json_start..json_end];
@@ -37,7 +51,150 @@ pub fn parse_domain_suggestions(content: &str, _config: &GenerationConfig) -> Re
             Some(json_content.to_string())
         ))?;
 
+    suggestions_from_raw(raw_suggestions, content, config)
+}
+
+/// Parse domain suggestions, repairing common LLM formatting mistakes.
+///
+/// Strips markdown code fences and `//` / `/* */` comments, then tries the
+/// array as-is, then without trailing commas, and finally parses each
+/// `{...}` object on its own, skipping any that are still malformed. With
+/// `lenient_parsing` off this is [`parse_domain_suggestions`].
+pub fn parse_domain_suggestions_lenient(content: &str, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+    if !config.lenient_parsing {
+        return parse_domain_suggestions(content, config);
+    }
+
+    let cleaned = strip_comments(strip_code_fences(content));
+    let json_start = cleaned.find('[').unwrap_or(0);
+    let json_end = cleaned.rfind(']').map(|i| i + 1).unwrap_or(cleaned.len());
+    let json_content = cleaned.get(json_start..json_end).unwrap_or(&cleaned);
+
+    let raw_suggestions = match serde_json::from_str::<Vec<DomainSuggestionRaw>>(json_content) {
+        Ok(raw) => raw,
+        Err(_) => {
+            static TRAILING_COMMA: OnceLock<Regex> = OnceLock::new();
+            let trailing_comma = TRAILING_COMMA.get_or_init(|| Regex::new(r",\s*([\]}])").unwrap());
+            let repaired = trailing_comma.replace_all(json_content, "$1");
+            match serde_json::from_str::<Vec<DomainSuggestionRaw>>(&repaired) {
+                Ok(raw) => raw,
+                Err(e) => {
+                    let mut raw = Vec::new();
+                    collect_objects(&repaired, &mut raw);
+                    if raw.is_empty() {
+                        return Err(DomainForgeError::parse(
+                            format!("Failed to parse AI response as JSON: {}", e),
+                            Some(content.to_string()),
+                        ));
+                    }
+                    tracing::debug!(count = raw.len(), "Recovered suggestions from malformed JSON");
+                    raw
+                }
+            }
+        }
+    };
+
+    suggestions_from_raw(raw_suggestions, content, config)
+}
+
+/// The body of the first markdown code block, or the whole text without one
+fn strip_code_fences(content: &str) -> &str {
+    let Some(open) = content.find("```") else {
+        return content.trim();
+    };
+    // Skip the info string (```json)
+    let body_start = content[open..].find('\n').map_or(content.len(), |i| open + i + 1);
+    let body = &content[body_start..];
+    body.find("```").map_or(body, |close| &body[..close]).trim()
+}
+
+/// Remove `//` and `/* */` comments outside JSON strings
+fn strip_comments(json: &str) -> String {
+    let mut out = String::with_capacity(json.len());
+    let mut chars = json.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => {
+                while chars.next_if(|&c| c != '\n').is_some() {}
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Parse every balanced `{...}` in `text` that is a suggestion, looking
+/// inside objects that are not (e.g. a `{"domains": [...]}` wrapper)
+fn collect_objects(text: &str, out: &mut Vec<DomainSuggestionRaw>) {
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => {
+                if depth == 0 {
+                    start = i;
+                }
+                depth += 1;
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    let object = &text[start..=i];
+                    match serde_json::from_str::<DomainSuggestionRaw>(object) {
+                        Ok(raw) => out.push(raw),
+                        Err(_) => collect_objects(&object[1..object.len() - 1], out),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Turn parsed entries into suggestions, enforcing `name.tld` and length limits
+pub(crate) fn suggestions_from_raw(
+    raw_suggestions: Vec<DomainSuggestionRaw>,
+    content: &str,
+    config: &GenerationConfig,
+) -> Result<Vec<DomainSuggestion>> {
     let mut suggestions = Vec::new();
+    let mut length_filtered = 0;
 
     for raw in raw_suggestions {
         let confidence = raw.confidence.unwrap_or(0.8);
@@ -46,6 +203,11 @@ pub fn parse_domain_suggestions(content: &str, _config: &GenerationConfig) -> Re
         if raw.name.contains('.') {
             let parts: Vec<&str> = raw.name.splitn(2, '.').collect();
             if parts.len() == 2 {
+                if !name_length_allowed(parts[0], config) {
+                    tracing::debug!(domain = %raw.name, "Dropping suggestion outside name length limits");
+                    length_filtered += 1;
+                    continue;
+                }
                 suggestions.push(DomainSuggestion::new(
                     parts[0].to_string(),
                     parts[1].to_string(),
@@ -62,6 +224,13 @@ pub fn parse_domain_suggestions(content: &str, _config: &GenerationConfig) -> Re
         }
     }
 
+    if suggestions.is_empty() && length_filtered > 0 {
+        return Err(crate::error::DomainForgeError::parse(
+            format!("All {} suggestions violated length constraints", length_filtered),
+            Some(content.to_string())
+        ));
+    }
+
     if suggestions.is_empty() {
         return Err(crate::error::DomainForgeError::parse(
             "No valid complete domain names found in LLM response".to_string(),
@@ -72,6 +241,81 @@ pub fn parse_domain_suggestions(content: &str, _config: &GenerationConfig) -> Re
     Ok(suggestions)
 }
 
+/// Whether the name part satisfies `min_name_length`/`max_name_length`
+fn name_length_allowed(name: &str, config: &GenerationConfig) -> bool {
+    let length = name.chars().count();
+    config.min_name_length.is_none_or(|min| length >= min)
+        && config.max_name_length.is_none_or(|max| length <= max)
+}
+
+/// Prompt line describing the name length limits, if any
+fn length_guidance(config: &GenerationConfig) -> String {
+    let limit = match (config.min_name_length, config.max_name_length) {
+        (Some(min), Some(max)) if min == max => format!("exactly {} characters", min),
+        (Some(min), Some(max)) => format!("between {} and {} characters", min, max),
+        (Some(min), None) => format!("at least {} characters", min),
+        (None, Some(max)) => format!("at most {} characters", max),
+        (None, None) => return String::new(),
+    };
+    format!("\nName length: {} before the TLD", limit)
+}
+
+/// Counts above this get a response budget scaled by [`TOKENS_PER_DOMAIN`]
+pub const MAX_TOKENS_SCALE_THRESHOLD: usize = 20;
+/// Rough response tokens needed per suggested domain
+pub const TOKENS_PER_DOMAIN: u32 = 50;
+
+/// Response token limit for generating `count` domains: the configured
+/// limit, or the provider's `default` raised to cover large counts
+pub(crate) fn generation_max_tokens(configured: Option<u32>, default: u32, count: usize) -> u32 {
+    if let Some(max_tokens) = configured {
+        return max_tokens;
+    }
+    if count > MAX_TOKENS_SCALE_THRESHOLD {
+        let scaled = u32::try_from(count).unwrap_or(u32::MAX).saturating_mul(TOKENS_PER_DOMAIN);
+        default.max(scaled)
+    } else {
+        default
+    }
+}
+
+/// Record token usage and its estimated cost from an LLM response
+pub(crate) fn record_usage(metrics: Option<&PerformanceMetrics>, model: &str, prompt_tokens: u64, completion_tokens: u64) {
+    let Some(metrics) = metrics else {
+        return;
+    };
+    metrics.add_prompt_tokens(prompt_tokens);
+    metrics.add_completion_tokens(completion_tokens);
+    metrics.add_llm_cost_microdollars(crate::llm::pricing::estimate_cost_microdollars(
+        model,
+        prompt_tokens,
+        completion_tokens,
+    ));
+    tracing::debug!(model = %model, prompt_tokens, completion_tokens, "LLM token usage");
+}
+
+/// Default system message sent with every generation request
+pub const DEFAULT_SYSTEM_PROMPT: &str =
+    "You are a domain name generator. Generate creative domain names and return them as a JSON array.";
+
+/// Few-shot examples (description → good domain) used by default
+pub const DEFAULT_EXAMPLES: &[(&str, &str)] = &[
+    ("Project management tool for remote teams", "taskflow.io"),
+    ("Organic coffee roastery and cafe", "beanhaven.com"),
+    ("AI assistant for writing legal contracts", "clausely.ai"),
+    ("Online store for handmade jewelry", "gemcraft.shop"),
+    ("Personal finance app for students", "budgetly.app"),
+    ("Nonprofit restoring local wetlands", "marshkeep.org"),
+];
+
+/// Build the system message: the configured template, or the default
+pub fn build_system_prompt(config: &GenerationConfig) -> String {
+    config
+        .system_prompt_template
+        .clone()
+        .unwrap_or_else(|| DEFAULT_SYSTEM_PROMPT.to_string())
+}
+
 /// Build domain generation prompt - trust LLM's intelligence completely
 pub fn build_domain_prompt(config: &GenerationConfig) -> String {
     let avoid_guidance = if !config.avoid_names.is_empty() {
@@ -80,11 +324,40 @@ pub fn build_domain_prompt(config: &GenerationConfig) -> String {
         String::new()
     };
 
-    format!(
+    let examples_guidance = if config.examples.is_empty() {
+        String::new()
+    } else {
+        let pairs: Vec<String> = config
+            .examples
+            .iter()
+            .map(|(description, domain)| format!("- {} → {}", description, domain))
+            .collect();
+        format!(
+            "\n\nHere are examples of good domain names for similar descriptions:\n{}",
+            pairs.join("\n")
+        )
+    };
+
+    let negative_guidance = if config.negative_examples.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "\n\nExamples of bad domain names to avoid: {}",
+            config.negative_examples.join(", ")
+        )
+    };
+
+    let style_guidance = match config.style {
+        GenerationStyle::Acronym => "\nGenerate domain names that are abbreviations or acronyms derived from the description, typically 3-6 characters",
+        GenerationStyle::Portmanteau => "\nBlend two relevant words together to create a new word, similar to how 'breakfast' + 'lunch' = 'brunch'. Prefer short blends of 5-8 characters",
+        _ => "",
+    };
+
+    let mut prompt = format!(
         "Generate {} domain names for: {}
 
-Style: {}
-Available TLDs: {}{}
+Style: {}{}
+Available TLDs: {}{}{}{}{}
 
 Return complete domain names as JSON:
 [
@@ -97,7 +370,164 @@ Return complete domain names as JSON:
         config.count,
         config.description,
         config.style,
+        style_guidance,
         config.tlds.join(", "),
-        avoid_guidance
-    )
+        length_guidance(config),
+        avoid_guidance,
+        examples_guidance,
+        negative_guidance
+    );
+
+    if let Some(suffix) = config.user_prompt_suffix.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        prompt.push_str("\n\n");
+        prompt.push_str(suffix);
+    }
+
+    prompt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_prompt_template() {
+        let mut config = GenerationConfig::default();
+        assert_eq!(build_system_prompt(&config), DEFAULT_SYSTEM_PROMPT);
+
+        config.system_prompt_template = Some("Only suggest 5-letter names.".to_string());
+        assert_eq!(build_system_prompt(&config), "Only suggest 5-letter names.");
+    }
+
+    #[test]
+    fn test_few_shot_examples() {
+        let mut config = GenerationConfig {
+            description: "dog walking service".to_string(),
+            ..Default::default()
+        };
+        let prompt = build_domain_prompt(&config);
+        let examples = prompt
+            .find("Here are examples of good domain names for similar descriptions:\n")
+            .unwrap();
+        assert!(examples < prompt.find("Return complete domain names as JSON").unwrap());
+        for (description, domain) in DEFAULT_EXAMPLES {
+            assert!(prompt.contains(&format!("\n- {} → {}", description, domain)));
+        }
+        assert!(!prompt.contains("bad domain names"));
+
+        config.examples = vec![("Bike repair shop".to_string(), "spokesmith.com".to_string())];
+        config.negative_examples = vec!["bestbikerepair4u.com".to_string(), "bkrpr.io".to_string()];
+        let prompt = build_domain_prompt(&config);
+        assert!(prompt.contains("similar descriptions:\n- Bike repair shop → spokesmith.com\n\n"));
+        assert!(prompt.contains("Examples of bad domain names to avoid: bestbikerepair4u.com, bkrpr.io"));
+        assert!(!prompt.contains("taskflow.io"));
+
+        config.examples.clear();
+        config.negative_examples.clear();
+        assert!(!build_domain_prompt(&config).contains("examples"));
+    }
+
+    #[test]
+    fn test_user_prompt_suffix() {
+        let mut config = GenerationConfig {
+            description: "coffee shop".to_string(),
+            ..Default::default()
+        };
+        let base = build_domain_prompt(&config);
+
+        config.user_prompt_suffix = Some("Avoid names that sound like existing brands.".to_string());
+        let prompt = build_domain_prompt(&config);
+        assert!(prompt.starts_with(&base));
+        assert!(prompt.ends_with("\n\nAvoid names that sound like existing brands."));
+    }
+
+    #[test]
+    fn test_name_length_constraints() {
+        let config = GenerationConfig {
+            min_name_length: Some(4),
+            max_name_length: Some(6),
+            ..Default::default()
+        };
+        assert!(build_domain_prompt(&config).contains("Name length: between 4 and 6 characters before the TLD"));
+
+        let content = r#"[{"name": "abc.com"}, {"name": "forge.io"}, {"name": "toolong.com"}]"#;
+        let suggestions = parse_domain_suggestions(content, &config).unwrap();
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].get_full_domain(), "forge.io");
+
+        let err = parse_domain_suggestions(r#"[{"name": "abc.com"}]"#, &config).unwrap_err();
+        assert!(err.to_string().contains("violated length constraints"));
+    }
+
+    #[test]
+    fn test_lenient_parsing() {
+        let config = GenerationConfig::default();
+        let names = |content: &str| -> Vec<String> {
+            parse_domain_suggestions_lenient(content, &config)
+                .unwrap()
+                .iter()
+                .map(|s| s.get_full_domain())
+                .collect()
+        };
+
+        let fenced = "Here you go:\n```json\n[{\"name\": \"forge.com\"}]\n```\nEnjoy!";
+        assert_eq!(names(fenced), vec!["forge.com"]);
+
+        let trailing = r#"[{"name": "forge.com", "confidence": 0.9,}, {"name": "anvil.io"},]"#;
+        assert_eq!(names(trailing), vec!["forge.com", "anvil.io"]);
+
+        let commented = "[\n  // best pick\n  {\"name\": \"forge.com\", \"reasoning\": \"see https://forge.com\"} /* short */\n]";
+        let parsed = parse_domain_suggestions_lenient(commented, &config).unwrap();
+        assert_eq!(parsed[0].reasoning.as_deref(), Some("see https://forge.com"));
+
+        // Broken array: recover the objects that do parse
+        let broken = r#"{"domains": [{"name": "forge.com"} {"name": "anvil.io", "confidence": } {"name": "smith.ai"}"#;
+        assert_eq!(names(broken), vec!["forge.com", "smith.ai"]);
+
+        assert!(parse_domain_suggestions_lenient("no json here", &config).is_err());
+
+        let strict = GenerationConfig { lenient_parsing: false, ..Default::default() };
+        assert!(parse_domain_suggestions_lenient(fenced, &strict).is_ok());
+        assert!(parse_domain_suggestions_lenient(trailing, &strict).is_err());
+    }
+
+    #[test]
+    fn test_style_guidance_prompt() {
+        let mut config = GenerationConfig {
+            description: "AI-powered code review tool".to_string(),
+            ..Default::default()
+        };
+        assert!(!build_domain_prompt(&config).contains("acronyms"));
+
+        config.style = GenerationStyle::Acronym;
+        let prompt = build_domain_prompt(&config);
+        assert!(prompt.contains("Style: acronym\nGenerate domain names that are abbreviations or acronyms"));
+
+        config.style = GenerationStyle::Portmanteau;
+        let prompt = build_domain_prompt(&config);
+        assert!(prompt.contains("'breakfast' + 'lunch' = 'brunch'"));
+        assert!(prompt.contains("5-8 characters"));
+    }
+
+    #[test]
+    fn test_generation_max_tokens() {
+        assert_eq!(generation_max_tokens(None, 2000, 20), 2000);
+        assert_eq!(generation_max_tokens(None, 1000, 21), 1050);
+        assert_eq!(generation_max_tokens(None, 2000, 100), 5000);
+        // An explicit limit wins, even when smaller
+        assert_eq!(generation_max_tokens(Some(300), 2000, 100), 300);
+        assert_eq!(generation_max_tokens(None, 1000, usize::MAX), u32::MAX);
+    }
+
+    #[test]
+    fn test_record_usage() {
+        let metrics = PerformanceMetrics::default();
+        record_usage(Some(&metrics), "gpt-4.1-mini", 1000, 500);
+        record_usage(Some(&metrics), "llama3", 200, 100);
+        record_usage(None, "gpt-4.1-mini", 1000, 500);
+
+        let stats = metrics.get_stats();
+        assert_eq!(stats.total_tokens_used, 1800);
+        assert!((stats.estimated_cost_usd - 0.0012).abs() < 1e-9);
+    }
 }
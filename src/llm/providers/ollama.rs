@@ -4,13 +4,14 @@
 
 use crate::error::{DomainForgeError, Result};
 use crate::llm::LlmProvider;
-use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig};
+use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig, PerformanceMetrics};
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Duration;
 
-use super::{build_domain_prompt, parse_domain_suggestions};
+use super::{build_domain_prompt, build_system_prompt, parse_domain_suggestions_lenient, record_usage};
 
 /// Ollama provider implementation for local LLM inference
 pub struct OllamaProvider {
@@ -18,6 +19,8 @@ pub struct OllamaProvider {
     model: String,
     base_url: String,
     temperature: f32,
+    max_tokens: Option<u32>,
+    metrics: Option<Arc<PerformanceMetrics>>,
 }
 
 impl OllamaProvider {
@@ -32,6 +35,8 @@ impl OllamaProvider {
             model: config.model.clone(),
             base_url: config.base_url.clone().unwrap_or_else(|| "http://localhost:11434".to_string()),
             temperature: config.temperature,
+            max_tokens: config.max_tokens,
+            metrics: None,
         })
     }
 }
@@ -43,9 +48,12 @@ impl LlmProvider for OllamaProvider {
         
         let request = OllamaRequest {
             model: self.model.clone(),
+            system: build_system_prompt(config),
             prompt,
             temperature: self.temperature,
             stream: false,
+            // Ollama has no default limit, so only a configured one is sent
+            options: self.max_tokens.map(|num_predict| OllamaOptions { num_predict }),
         };
 
         let url = format!("{}/api/generate", self.base_url);
@@ -81,7 +89,14 @@ impl LlmProvider for OllamaProvider {
         let ollama_response: OllamaResponse = response.json().await
             .map_err(|e| DomainForgeError::parse(e.to_string(), None))?;
 
-        parse_domain_suggestions(&ollama_response.response, config)
+        record_usage(
+            self.metrics.as_deref(),
+            &self.model,
+            ollama_response.prompt_eval_count,
+            ollama_response.eval_count,
+        );
+
+        parse_domain_suggestions_lenient(&ollama_response.response, config)
     }
 
     fn name(&self) -> &'static str {
@@ -92,21 +107,67 @@ impl LlmProvider for OllamaProvider {
         &self.model
     }
 
+    fn attach_metrics(&mut self, metrics: Arc<PerformanceMetrics>) {
+        self.metrics = Some(metrics);
+    }
+
     fn is_ready(&self) -> bool {
         true // Ollama doesn't need API key
     }
+
+    /// Ask the server about the model, which fails if Ollama is down or the
+    /// model has not been pulled, without running inference
+    async fn health_check(&self) -> Result<()> {
+        let url = format!("{}/api/show", self.base_url);
+        let response = self.client
+            .post(&url)
+            .json(&serde_json::json!({ "model": self.model }))
+            .send()
+            .await
+            .map_err(|e| DomainForgeError::network(
+                format!("Failed to connect to Ollama: {}", e),
+                None,
+                Some(url.clone())
+            ))?;
+
+        match response.status().as_u16() {
+            200..=299 => Ok(()),
+            404 => Err(DomainForgeError::network(
+                format!("Model '{}' not found. Please pull the model first: ollama pull {}", self.model, self.model),
+                Some(404),
+                Some(url),
+            )),
+            status => Err(DomainForgeError::network(
+                format!("Ollama health check failed ({})", status),
+                Some(status),
+                Some(url),
+            )),
+        }
+    }
 }
 
 // Ollama API structures
 #[derive(Serialize)]
 struct OllamaRequest {
     model: String,
+    system: String,
     prompt: String,
     temperature: f32,
     stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<OllamaOptions>,
+}
+
+#[derive(Serialize)]
+struct OllamaOptions {
+    num_predict: u32,
 }
 
 #[derive(Deserialize)]
 struct OllamaResponse {
     response: String,
+    #[serde(default)]
+    prompt_eval_count: u64,
+    #[serde(default)]
+    eval_count: u64,
 }
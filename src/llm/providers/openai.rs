@@ -1,24 +1,67 @@
 //! OpenAI provider implementation
 //! 
-//! Supports OpenAI API and OpenAI-compatible APIs (OpenRouter, OneAPI, etc.)
+//! Supports OpenAI API and OpenAI-compatible APIs (OneAPI, etc.)
 
 use crate::error::{DomainForgeError, Result};
 use crate::llm::LlmProvider;
-use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig};
+use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig, PerformanceMetrics};
 use async_trait::async_trait;
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::sync::Arc;
 
-use super::{build_domain_prompt, parse_domain_suggestions};
+use super::openai_compat::OpenAiCompatibleClient;
+use super::{suggestions_from_raw, StructuredSuggestions};
+
+/// Model name prefixes that accept `response_format: json_schema`
+const STRUCTURED_OUTPUT_MODELS: &[&str] = &["gpt-4o", "gpt-4.1", "gpt-5", "o1", "o3", "o4"];
+
+/// Snapshots of those models that predate structured output
+const UNSTRUCTURED_SNAPSHOTS: &[&str] = &["gpt-4o-2024-05-13", "o1-mini", "o1-preview"];
+
+/// Whether `model` supports JSON-schema structured output
+pub fn supports_structured_output(model: &str) -> bool {
+    let model = model.trim().to_lowercase();
+    STRUCTURED_OUTPUT_MODELS.iter().any(|prefix| model.starts_with(prefix))
+        && !UNSTRUCTURED_SNAPSHOTS.iter().any(|prefix| model.starts_with(prefix))
+}
+
+/// `response_format` constraining the reply to a list of suggestions.
+///
+/// Structured output requires an object at the root, so the
+/// `DomainSuggestionRaw` list is wrapped in `{"domains": [...]}`.
+fn domain_suggestions_format() -> serde_json::Value {
+    serde_json::json!({
+        "type": "json_schema",
+        "json_schema": {
+            "name": "domain_suggestions",
+            "strict": true,
+            "schema": {
+                "type": "object",
+                "properties": {
+                    "domains": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "name": { "type": "string", "description": "Complete domain name, e.g. example.com" },
+                                "reasoning": { "type": ["string", "null"] },
+                                "confidence": { "type": ["number", "null"] }
+                            },
+                            "required": ["name", "reasoning", "confidence"],
+                            "additionalProperties": false
+                        }
+                    }
+                },
+                "required": ["domains"],
+                "additionalProperties": false
+            }
+        }
+    })
+}
 
 /// OpenAI provider implementation
 pub struct OpenAiProvider {
-    client: Client,
-    api_key: String,
-    model: String,
-    base_url: String,
-    temperature: f32,
+    client: OpenAiCompatibleClient,
+    structured_output: bool,
 }
 
 impl OpenAiProvider {
@@ -27,93 +70,29 @@ impl OpenAiProvider {
             return Err(DomainForgeError::config("OpenAI API key is required".to_string()));
         }
 
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .build()
-            .map_err(|e| DomainForgeError::network(e.to_string(), None, None))?;
-
         Ok(Self {
-            client,
-            api_key: config.api_key.clone(),
-            model: config.model.clone(),
-            base_url: config.base_url.clone().unwrap_or_else(|| "https://api.openai.com/v1".to_string()),
-            temperature: config.temperature,
+            client: OpenAiCompatibleClient::new(config, "https://api.openai.com/v1", "API")?,
+            structured_output: config.use_structured_output && supports_structured_output(&config.model),
         })
     }
 
-    /// Intelligently constructs the full API URL
-    fn build_url(&self, endpoint: &str) -> String {
-        let base_url = self.base_url.trim_end_matches('/');
-        if base_url.ends_with("/v1") {
-            format!("{}{}", base_url, endpoint)
-        } else {
-            format!("{}/v1{}", base_url, endpoint)
-        }
+    /// Generate suggestions with a JSON schema enforced by the API
+    pub async fn generate_with_schema(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+        let content = self.client.complete(config, Some(domain_suggestions_format())).await?;
+        let parsed: StructuredSuggestions = serde_json::from_str(&content)
+            .map_err(|e| DomainForgeError::parse(format!("Structured output did not match the schema: {}", e), Some(content.clone())))?;
+        suggestions_from_raw(parsed.domains, &content, config)
     }
 }
 
 #[async_trait]
 impl LlmProvider for OpenAiProvider {
     async fn generate_domains(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
-        let prompt = build_domain_prompt(config);
-        
-        let request = OpenAiRequest {
-            model: self.model.clone(),
-            messages: vec![
-                OpenAiMessage {
-                    role: "system".to_string(),
-                    content: "You are a domain name generator. Generate creative domain names and return them as a JSON array.".to_string(),
-                },
-                OpenAiMessage {
-                    role: "user".to_string(),
-                    content: prompt,
-                },
-            ],
-            temperature: self.temperature,
-            max_tokens: 2000,
-        };
-
-        let url = self.build_url("/chat/completions");
-        let response = self.client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.api_key))
-            .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
-            .await
-            .map_err(|e| DomainForgeError::network(
-                format!("Failed to connect to API: {}", e),
-                None,
-                Some(url.clone())
-            ))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-            
-            let error_msg = match status.as_u16() {
-                401 => format!("Authentication failed (401). Please check your API key for {}", self.base_url),
-                403 => format!("Access forbidden (403). Your API key may not have permission for this endpoint"),
-                429 => format!("Rate limit exceeded (429). Please try again later"),
-                500..=599 => format!("Server error ({}). The API service is experiencing issues", status),
-                _ => format!("API request failed ({}): {}", status, error_text),
-            };
-            
-            return Err(DomainForgeError::network(
-                error_msg,
-                Some(status.as_u16()),
-                Some(url),
-            ));
+        if self.structured_output {
+            self.generate_with_schema(config).await
+        } else {
+            self.client.generate_domains(config).await
         }
-
-        let openai_response: OpenAiResponse = response.json().await
-            .map_err(|e| DomainForgeError::parse(e.to_string(), None))?;
-        
-        let content = openai_response.choices.get(0)
-            .ok_or_else(|| DomainForgeError::internal("No response from OpenAI API".to_string()))?
-            .message.content.clone();
-
-        parse_domain_suggestions(&content, config)
     }
 
     fn name(&self) -> &'static str {
@@ -121,37 +100,56 @@ impl LlmProvider for OpenAiProvider {
     }
 
     fn model(&self) -> &str {
-        &self.model
+        self.client.model()
     }
 
-    fn is_ready(&self) -> bool {
-        !self.api_key.is_empty()
+    fn attach_metrics(&mut self, metrics: Arc<PerformanceMetrics>) {
+        self.client.attach_metrics(metrics);
     }
-}
 
-// OpenAI API structures
-#[derive(Serialize)]
-struct OpenAiRequest {
-    model: String,
-    messages: Vec<OpenAiMessage>,
-    temperature: f32,
-    max_tokens: u32,
-}
+    fn is_ready(&self) -> bool {
+        self.client.has_api_key()
+    }
 
-#[derive(Serialize, Deserialize)]
-struct OpenAiMessage {
-    role: String,
-    content: String,
+    async fn health_check(&self) -> Result<()> {
+        self.client.health_check().await
+    }
 }
 
-#[derive(Deserialize)]
-struct OpenAiResponse {
-    choices: Vec<OpenAiChoice>,
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_structured_output_detection() {
+        assert!(supports_structured_output("gpt-4o"));
+        assert!(supports_structured_output("gpt-4o-mini-2024-07-18"));
+        assert!(supports_structured_output("GPT-4.1-mini"));
+        assert!(supports_structured_output("o3-mini"));
+        assert!(!supports_structured_output("gpt-4o-2024-05-13"));
+        assert!(!supports_structured_output("gpt-3.5-turbo"));
+        assert!(!supports_structured_output("o1-preview"));
+
+        let config = LlmConfig { api_key: "key".to_string(), ..Default::default() };
+        assert!(OpenAiProvider::new(&config).unwrap().structured_output);
+        let config = LlmConfig { use_structured_output: false, ..config };
+        assert!(!OpenAiProvider::new(&config).unwrap().structured_output);
+        let config = LlmConfig { model: "gpt-3.5-turbo".to_string(), use_structured_output: true, ..config };
+        assert!(!OpenAiProvider::new(&config).unwrap().structured_output);
+    }
 
-#[derive(Deserialize)]
-struct OpenAiChoice {
-    message: OpenAiMessage,
+    #[test]
+    fn test_schema_matches_raw_suggestions() {
+        let format = domain_suggestions_format();
+        assert_eq!(format["type"], "json_schema");
+        assert_eq!(format["json_schema"]["name"], "domain_suggestions");
+        let item = &format["json_schema"]["schema"]["properties"]["domains"]["items"];
+        assert_eq!(item["required"], serde_json::json!(["name", "reasoning", "confidence"]));
+
+        let reply = r#"{"domains": [{"name": "forge.com", "reasoning": null, "confidence": 0.9}]}"#;
+        let parsed: StructuredSuggestions = serde_json::from_str(reply).unwrap();
+        let suggestions = suggestions_from_raw(parsed.domains, reply, &GenerationConfig::default()).unwrap();
+        assert_eq!(suggestions[0].get_full_domain(), "forge.com");
+        assert_eq!(suggestions[0].confidence, 0.9);
+    }
 }
-
-
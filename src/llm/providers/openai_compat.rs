@@ -0,0 +1,272 @@
+//! Shared client for OpenAI-compatible chat/completions APIs
+//!
+//! Used by the OpenAI, OpenRouter and generic providers, which differ only in
+//! base URL, authentication and a few extra headers.
+
+use crate::error::{DomainForgeError, Result};
+use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig, PerformanceMetrics};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{build_domain_prompt, build_system_prompt, generation_max_tokens, parse_domain_suggestions_lenient, record_usage};
+
+const DEFAULT_MAX_TOKENS: u32 = 2000;
+
+/// HTTP client speaking the OpenAI chat/completions format
+pub(crate) struct OpenAiCompatibleClient {
+    client: Client,
+    api_key: Option<String>,
+    model: String,
+    base_url: String,
+    temperature: f32,
+    max_tokens: Option<u32>,
+    headers: Vec<(&'static str, String)>,
+    /// Service name used in error messages
+    label: &'static str,
+    metrics: Option<Arc<PerformanceMetrics>>,
+}
+
+impl OpenAiCompatibleClient {
+    pub(crate) fn new(config: &LlmConfig, default_base_url: &str, label: &'static str) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|e| DomainForgeError::network(e.to_string(), None, None))?;
+
+        Ok(Self {
+            client,
+            api_key: Some(config.api_key.clone()).filter(|k| !k.is_empty()),
+            model: config.model.clone(),
+            base_url: config.base_url.clone().unwrap_or_else(|| default_base_url.to_string()),
+            temperature: config.temperature,
+            max_tokens: config.max_tokens,
+            headers: Vec::new(),
+            label,
+            metrics: None,
+        })
+    }
+
+    /// Send an extra header with every request
+    pub(crate) fn with_header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    pub(crate) fn attach_metrics(&mut self, metrics: Arc<PerformanceMetrics>) {
+        self.metrics = Some(metrics);
+    }
+
+    pub(crate) fn model(&self) -> &str {
+        &self.model
+    }
+
+    pub(crate) fn has_api_key(&self) -> bool {
+        self.api_key.is_some()
+    }
+
+    /// Intelligently constructs the full API URL
+    fn build_url(&self, endpoint: &str) -> String {
+        let base_url = self.base_url.trim_end_matches('/');
+        if base_url.ends_with("/v1") {
+            format!("{}{}", base_url, endpoint)
+        } else {
+            format!("{}/v1{}", base_url, endpoint)
+        }
+    }
+
+    /// Request domain suggestions via `/chat/completions`
+    pub(crate) async fn generate_domains(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+        let content = self.complete(config, None).await?;
+        parse_domain_suggestions_lenient(&content, config)
+    }
+
+    /// Send the generation prompt and return the reply text, optionally
+    /// constrained by an OpenAI `response_format`
+    pub(crate) async fn complete(&self, config: &GenerationConfig, response_format: Option<serde_json::Value>) -> Result<String> {
+        let request = self.chat_request(config, response_format);
+        let chat_response = self.send(&request).await?;
+
+        if let Some(usage) = &chat_response.usage {
+            record_usage(self.metrics.as_deref(), &self.model, usage.prompt_tokens, usage.completion_tokens);
+        }
+
+        chat_response.choices.first()
+            .map(|choice| choice.message.content.clone())
+            .ok_or_else(|| DomainForgeError::internal(format!("No response from {}", self.label)))
+    }
+
+    /// Build the generation request. A seeded config samples at its own
+    /// temperature rather than the provider's, so a shared config gives the
+    /// same output everywhere.
+    fn chat_request(&self, config: &GenerationConfig, response_format: Option<serde_json::Value>) -> ChatRequest {
+        ChatRequest {
+            model: self.model.clone(),
+            messages: vec![
+                ChatMessage {
+                    role: "system".to_string(),
+                    content: build_system_prompt(config),
+                },
+                ChatMessage {
+                    role: "user".to_string(),
+                    content: build_domain_prompt(config),
+                },
+            ],
+            temperature: if config.seed.is_some() { config.temperature } else { self.temperature },
+            max_tokens: generation_max_tokens(self.max_tokens, DEFAULT_MAX_TOKENS, config.count),
+            response_format,
+            seed: config.seed,
+        }
+    }
+
+    /// Request a single-token completion to verify the key and model
+    pub(crate) async fn health_check(&self) -> Result<()> {
+        let request = ChatRequest {
+            model: self.model.clone(),
+            messages: vec![ChatMessage {
+                role: "user".to_string(),
+                content: "ping".to_string(),
+            }],
+            temperature: self.temperature,
+            max_tokens: 1,
+            response_format: None,
+            seed: None,
+        };
+        self.send(&request).await.map(|_| ())
+    }
+
+    /// POST to `/chat/completions`, mapping HTTP failures to network errors
+    async fn send(&self, request: &ChatRequest) -> Result<ChatResponse> {
+        let url = self.build_url("/chat/completions");
+        let mut builder = self.client
+            .post(&url)
+            .header("Content-Type", "application/json");
+        if let Some(api_key) = &self.api_key {
+            builder = builder.header("Authorization", format!("Bearer {}", api_key));
+        }
+        for (name, value) in &self.headers {
+            builder = builder.header(*name, value);
+        }
+
+        let response = builder
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| DomainForgeError::network(
+                format!("Failed to connect to {}: {}", self.label, e),
+                None,
+                Some(url.clone())
+            ))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+
+            let error_msg = match status.as_u16() {
+                401 => format!("Authentication failed (401). Please check your API key for {}", self.base_url),
+                403 => "Access forbidden (403). Your API key may not have permission for this endpoint".to_string(),
+                429 => "Rate limit exceeded (429). Please try again later".to_string(),
+                500..=599 => format!("Server error ({}). The {} service is experiencing issues", status, self.label),
+                _ => format!("{} request failed ({}): {}", self.label, status, error_text),
+            };
+
+            return Err(DomainForgeError::network(
+                error_msg,
+                Some(status.as_u16()),
+                Some(url),
+            ));
+        }
+
+        response.json().await
+            .map_err(|e| DomainForgeError::parse(e.to_string(), None))
+    }
+}
+
+// OpenAI chat/completions structures
+#[derive(Serialize)]
+struct ChatRequest {
+    model: String,
+    messages: Vec<ChatMessage>,
+    temperature: f32,
+    max_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ChatMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+    usage: Option<ChatUsage>,
+}
+
+#[derive(Deserialize)]
+struct ChatUsage {
+    #[serde(default)]
+    prompt_tokens: u64,
+    #[serde(default)]
+    completion_tokens: u64,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatMessage,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn llm_config(base_url: Option<&str>) -> LlmConfig {
+        LlmConfig {
+            provider: "openai".to_string(),
+            base_url: base_url.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_url() {
+        let client = OpenAiCompatibleClient::new(&llm_config(None), "https://api.openai.com/v1", "OpenAI").unwrap();
+        assert_eq!(client.build_url("/chat/completions"), "https://api.openai.com/v1/chat/completions");
+
+        let client = OpenAiCompatibleClient::new(&llm_config(Some("http://localhost:1234/")), "unused", "LM Studio").unwrap();
+        assert_eq!(client.build_url("/chat/completions"), "http://localhost:1234/v1/chat/completions");
+        assert!(!client.has_api_key());
+    }
+
+    #[test]
+    fn test_seeded_request() {
+        let client = OpenAiCompatibleClient::new(&llm_config(None), "https://api.openai.com/v1", "OpenAI").unwrap();
+
+        let request = serde_json::to_value(client.chat_request(&GenerationConfig::default(), None)).unwrap();
+        assert!(request.get("seed").is_none());
+        assert!((request["temperature"].as_f64().unwrap() - 0.7).abs() < 1e-6);
+
+        let config = GenerationConfig::builder().description("Test app").deterministic(42).build().unwrap();
+        let request = serde_json::to_value(client.chat_request(&config, None)).unwrap();
+        assert_eq!(request["seed"], 42);
+        assert_eq!(request["temperature"], 0.0);
+    }
+
+    #[test]
+    fn test_max_tokens_request() {
+        let config = GenerationConfig::default();
+        let client = OpenAiCompatibleClient::new(&llm_config(None), "https://api.openai.com/v1", "OpenAI").unwrap();
+        let request = serde_json::to_value(client.chat_request(&config, None)).unwrap();
+        assert_eq!(request["max_tokens"], DEFAULT_MAX_TOKENS);
+
+        let llm = LlmConfig { max_tokens: Some(4096), ..llm_config(None) };
+        let client = OpenAiCompatibleClient::new(&llm, "https://api.openai.com/v1", "OpenAI").unwrap();
+        let request = serde_json::to_value(client.chat_request(&config, None)).unwrap();
+        assert_eq!(request["max_tokens"], 4096);
+    }
+}
@@ -0,0 +1,65 @@
+//! OpenRouter provider implementation
+//!
+//! OpenRouter proxies many models behind an OpenAI-compatible API. Model
+//! names are namespaced by vendor, e.g. `openai/gpt-4.1-mini` or
+//! `anthropic/claude-sonnet-4`.
+
+use crate::error::{DomainForgeError, Result};
+use crate::llm::LlmProvider;
+use crate::types::{DomainSuggestion, GenerationConfig, LlmConfig, PerformanceMetrics};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use super::openai_compat::OpenAiCompatibleClient;
+
+/// Default OpenRouter API endpoint
+pub const OPENROUTER_BASE_URL: &str = "https://openrouter.ai/api/v1";
+
+/// Default model when none is configured
+pub const OPENROUTER_DEFAULT_MODEL: &str = "openai/gpt-4.1-mini";
+
+/// OpenRouter provider implementation
+pub struct OpenRouterProvider {
+    client: OpenAiCompatibleClient,
+}
+
+impl OpenRouterProvider {
+    pub fn new(config: &LlmConfig) -> Result<Self> {
+        if config.api_key.is_empty() {
+            return Err(DomainForgeError::config("OpenRouter API key is required".to_string()));
+        }
+
+        let client = OpenAiCompatibleClient::new(config, OPENROUTER_BASE_URL, "OpenRouter")?
+            .with_header("HTTP-Referer", "https://github.com/voocel/domain-forge")
+            .with_header("X-Title", "domain-forge");
+
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl LlmProvider for OpenRouterProvider {
+    async fn generate_domains(&self, config: &GenerationConfig) -> Result<Vec<DomainSuggestion>> {
+        self.client.generate_domains(config).await
+    }
+
+    fn name(&self) -> &'static str {
+        "openrouter"
+    }
+
+    fn model(&self) -> &str {
+        self.client.model()
+    }
+
+    fn attach_metrics(&mut self, metrics: Arc<PerformanceMetrics>) {
+        self.client.attach_metrics(metrics);
+    }
+
+    fn is_ready(&self) -> bool {
+        self.client.has_api_key()
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.client.health_check().await
+    }
+}
@@ -0,0 +1,95 @@
+//! Rule-based TLD suggestions from a project description
+//!
+//! Used when no TLDs are given (or just `auto`), so it must work without an
+//! LLM or API key.
+
+/// TLD list value that asks for recommendations
+pub const AUTO: &str = "auto";
+
+/// Used when no rule matches the description
+pub const FALLBACK_TLDS: &[&str] = &["com", "org", "io"];
+
+/// Keywords (matched against whole words, or word prefixes for keywords of
+/// four or more letters) and the TLDs they suggest, most specific first
+const RULES: &[(&[&str], &[&str])] = &[
+    (&["tech", "software", "developer", "api", "apis", "cloud"], &["com", "io", "dev", "app"]),
+    (&["startup", "ai"], &["com", "io", "ai"]),
+    (&["nonprofit", "charity", "charities"], &["org", "com"]),
+    (&["ecommerce", "shop", "store"], &["com", "shop", "store"]),
+];
+
+/// Whether `tlds` asks for recommendations (empty, or just `auto`)
+pub fn is_auto(tlds: &[String]) -> bool {
+    match tlds {
+        [] => true,
+        [only] => only.trim().eq_ignore_ascii_case(AUTO),
+        _ => false,
+    }
+}
+
+fn matches_keyword(word: &str, keyword: &str) -> bool {
+    word == keyword || (keyword.len() >= 4 && word.starts_with(keyword))
+}
+
+/// Recommend TLDs for a description by keyword matching.
+///
+/// TLDs from every matching rule are combined in rule order without
+/// duplicates; [`FALLBACK_TLDS`] is returned when nothing matches.
+pub fn recommend_tlds(description: &str) -> Vec<String> {
+    // "e-commerce" and "non-profit" match like "ecommerce" and "nonprofit"
+    let words: Vec<String> = description
+        .split(|c: char| !c.is_alphanumeric() && c != '-')
+        .map(|word| word.replace('-', "").to_lowercase())
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let mut tlds: Vec<String> = Vec::new();
+    for (keywords, suggested) in RULES {
+        let matched = words
+            .iter()
+            .any(|word| keywords.iter().any(|keyword| matches_keyword(word, keyword)));
+        if matched {
+            for tld in *suggested {
+                if !tlds.iter().any(|t| t == tld) {
+                    tlds.push(tld.to_string());
+                }
+            }
+        }
+    }
+
+    if tlds.is_empty() {
+        FALLBACK_TLDS.iter().map(|s| s.to_string()).collect()
+    } else {
+        tlds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_rules() {
+        assert_eq!(recommend_tlds("Cloud software for developers"), vec!["com", "io", "dev", "app"]);
+        assert_eq!(recommend_tlds("AI startup"), vec!["com", "io", "ai"]);
+        assert_eq!(recommend_tlds("local animal charity"), vec!["org", "com"]);
+        assert_eq!(recommend_tlds("Non-profit food bank"), vec!["org", "com"]);
+        assert_eq!(recommend_tlds("e-commerce for sneakers"), vec!["com", "shop", "store"]);
+    }
+
+    #[test]
+    fn test_combined_and_fallback() {
+        assert_eq!(recommend_tlds("API startup"), vec!["com", "io", "dev", "app", "ai"]);
+        assert_eq!(recommend_tlds("family bakery"), FALLBACK_TLDS);
+        // Whole words only: "email" and "maintain" are not "ai"
+        assert_eq!(recommend_tlds("email maintenance"), FALLBACK_TLDS);
+    }
+
+    #[test]
+    fn test_is_auto() {
+        assert!(is_auto(&[]));
+        assert!(is_auto(&["AUTO".to_string()]));
+        assert!(!is_auto(&["com".to_string()]));
+        assert!(!is_auto(&["auto".to_string(), "com".to_string()]));
+    }
+}
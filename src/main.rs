@@ -3,20 +3,28 @@
 //! A simple and elegant CLI tool for generating creative domain names using AI
 //! and checking their availability in real-time.
 
+mod cli;
+
+use clap::Parser;
+use cli::{CheckArgs, Cli, Command, ConfigArgs, ConfigCommand, GenerateArgs, OutputFormat, ProvidersArgs, ProvidersCommand, RdapArgs, RecheckArgs, RecheckFormat, ResultFilterArgs, SnipeArgs, SnipeCommand, TldsArgs, TldsCommand, ValidateArgs, VariationsArgs, WatchlistArgs, WatchlistCommand};
 use domain_forge::{
-    domain::DomainChecker,
+    config::{self, Config, LlmSettings, SnipeSettings},
+    domain::{CsvExporter, DomainChecker, DomainValidator, ExporterFactory},
     llm::DomainGenerator,
-    snipe::{DomainSniper, SnipeConfig, Charset, ScanState, ScanMode},
-    types::{GenerationConfig, LlmConfig, DomainSuggestion, AvailabilityStatus, DomainSession, DomainResult},
+    rdap::registry::supported_tlds,
+    snipe::{DomainSniper, RecheckReport, SnipeConfig, Charset, ScanControl, ScanState, ScanMode, SnipedDomain, Watchlist, WordGenerator},
+    types::{AvailabilityStatus, CheckConfig, JsonOutput, MetricsSnapshot, GenerationConfig, LlmConfig, DomainSuggestion, DomainSession, DomainResult},
     Result,
 };
+use futures::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
 use inquire::Select;
 use rand::Rng;
 use std::env;
-use std::io;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
 use std::process;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone)]
 enum MenuOption {
@@ -39,35 +47,61 @@ impl std::fmt::Display for MenuOption {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize the library
-    if let Err(e) = domain_forge::init() {
-        eprintln!("Failed to initialize: {}", e);
-        process::exit(1);
-    }
+    let cli = Cli::parse();
 
-    // Get command line arguments
-    let args: Vec<String> = env::args().collect();
-
-    // Check for help
-    if args.len() > 1 && (args[1] == "--help" || args[1] == "-h") {
-        print_help();
-        return Ok(());
+    if cli.no_color {
+        // Honoured by the terminal styling used for progress bars
+        env::set_var("NO_COLOR", "1");
+        env::set_var("CLICOLOR", "0");
     }
 
-    // Check for snipe subcommand
-    if args.len() > 1 && args[1] == "snipe" {
-        return run_snipe_command(&args[2..]).await;
+    // Initialize the library (config file, environment, .env)
+    if let Err(e) = domain_forge::init_with_config(cli.config_file.as_deref()) {
+        eprintln!("Failed to initialize: {}", e);
+        process::exit(1);
     }
 
-    // Determine if user provided a description
-    let description = if args.len() > 1 {
-        args[1..].join(" ")
-    } else {
-        String::new()
+    let quiet = cli.quiet;
+    let json = cli.json;
+    let health_check = !cli.skip_health_check;
+    let metrics = cli.metrics;
+    let started = Instant::now();
+    // Filled in by the commands that collect metrics, printed with --metrics
+    let mut run_metrics: Option<MetricsSnapshot> = None;
+    let result = match cli.command {
+        Some(Command::Generate(args)) => run_domain_forge(&args, quiet, json, health_check, &mut run_metrics).await,
+        Some(Command::Snipe(args)) => match &args.command {
+            Some(SnipeCommand::Recheck(recheck)) => run_snipe_recheck_command(recheck, &args, json).await,
+            Some(SnipeCommand::Diff { old, new }) => run_snipe_diff_command(old, new),
+            Some(SnipeCommand::List { tag, dir }) => run_snipe_list_command(tag.as_deref(), dir),
+            Some(SnipeCommand::Merge { first, second, output }) => run_snipe_merge_command(first, second, output),
+            Some(SnipeCommand::Pause { dir }) => run_snipe_pause_command(dir),
+            None => run_snipe_command(&args, quiet, json, &mut run_metrics).await,
+        },
+        Some(Command::Check(args)) => {
+            let code = run_check_command(&args, quiet, json, &mut run_metrics).await.unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                CHECK_EXIT_ERROR
+            });
+            if metrics {
+                print_run_metrics(run_metrics.as_ref(), started.elapsed());
+            }
+            process::exit(code);
+        }
+        Some(Command::Variations(args)) => run_variations_command(&args, quiet).await,
+        Some(Command::Validate(args)) => run_validate_command(&args),
+        Some(Command::Rdap(args)) => run_rdap_command(&args).await,
+        Some(Command::Watchlist(args)) => run_watchlist_command(&args, quiet).await,
+        Some(Command::Config(args)) => run_config_command(&args),
+        Some(Command::Providers(args)) => run_providers_command(&args, json).await,
+        Some(Command::Tlds(args)) => run_tlds_command(&args, quiet, json),
+        None => run_domain_forge(&GenerateArgs::from_description(cli.description), quiet, json, health_check, &mut run_metrics).await,
     };
 
-    // Run the main flow
-    if let Err(e) = run_domain_forge(&description).await {
+    if metrics {
+        print_run_metrics(run_metrics.as_ref(), started.elapsed());
+    }
+    if let Err(e) = result {
         eprintln!("Error: {}", e);
         process::exit(1);
     }
@@ -75,41 +109,110 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Print the metrics a command collected, for `--metrics`
+fn print_run_metrics(snapshot: Option<&MetricsSnapshot>, elapsed: Duration) {
+    match snapshot {
+        Some(snapshot) => eprint!("\n{}", snapshot.table(elapsed)),
+        None => eprintln!("\nNo metrics collected by this command."),
+    }
+}
+
 /// Main domain forge workflow
-async fn run_domain_forge(description: &str) -> Result<()> {
+async fn run_domain_forge(
+    args: &GenerateArgs,
+    quiet: bool,
+    json: bool,
+    health_check: bool,
+    run_metrics: &mut Option<MetricsSnapshot>,
+) -> Result<()> {
+    if let (Some(min), Some(max)) = (args.min_length, args.max_length) {
+        if min > max {
+            return Err(domain_forge::DomainForgeError::cli(format!(
+                "--min-length ({}) cannot exceed --max-length ({})",
+                min, max
+            )));
+        }
+    }
+
+    if json {
+        return run_generate_json(args, run_metrics).await;
+    }
+
     // Show welcome message
-    println!("🔥 Domain Forge - AI-powered domain name generation");
-    println!("═══════════════════════════════════════════════════");
-    println!();
+    if !quiet {
+        println!("🔥 Domain Forge - AI-powered domain name generation");
+        println!("═══════════════════════════════════════════════════");
+        println!();
+    }
 
     // Set up LLM generator
     let mut generator = DomainGenerator::new();
-    setup_llm_providers(&mut generator)?;
+    setup_llm_providers(&mut generator, args.max_tokens, quiet)?;
+    if health_check {
+        verify_providers(&generator, quiet).await?;
+    }
 
     // Initialize session state
-    let mut session = DomainSession::new();
-    let final_description = if description.is_empty() {
-        get_random_description()
+    let mut session = match &args.resume {
+        Some(path) => {
+            let session = DomainSession::load(path)?;
+            println!("📂 Resumed session: {} rounds, {} available, {} taken",
+                session.round_count, session.available_domains.len(), session.taken_domains.len());
+            println!();
+            session
+        }
+        None => DomainSession::new(),
+    };
+    let description = args.description();
+    let final_description = if !description.is_empty() {
+        description
+    } else if let Some(previous) = session.description.clone() {
+        previous
     } else {
-        description.to_string()
+        get_random_description()
     };
+    session.description = Some(final_description.clone());
+    let previous_cost = session.api_cost_usd;
+
+    // Keep one checker across rounds so repeated suggestions hit the cache
+    let checker = DomainChecker::with_config(CheckConfig {
+        cache_ttl: Some(Duration::from_secs(600)),
+        ..config::current().check_config()
+    });
+
+    let output_format = args.output_format(OutputFormat::Txt);
+    let mut all_results: Vec<DomainResult> = Vec::new();
 
     // Main generation loop
     loop {
         // Generate domains for this round
         let round_start = std::time::Instant::now();
-        let domains = generate_domains_for_round(&generator, &final_description, &session).await?;
+        let domains = generate_domains_for_round(&generator, &final_description, args, &session, quiet).await?;
         
         if domains.is_empty() {
             println!("❌ No domains were generated. Please check your API configuration.");
             break;
         }
 
+        // The LLM does not always honour the avoid list, so don't recheck earlier results
+        let generated = domains.len();
+        let domains: Vec<DomainSuggestion> = domains
+            .into_iter()
+            .filter(|d| !session.is_checked(&d.get_full_domain()))
+            .collect();
+        if domains.is_empty() {
+            println!("❌ All {} suggestions were already checked in this session.", generated);
+            break;
+        }
+        if domains.len() < generated {
+            println!("⏭️  Skipping {} domains already checked in this session", generated - domains.len());
+        }
+        warn_confusables(domains.iter().map(|d| d.get_full_domain()));
+
         // Check domain availability with beautiful progress
-        let checker = DomainChecker::new();
         let domain_names: Vec<String> = domains.iter().map(|d| d.get_full_domain()).collect();
 
-        let check_pb = ProgressBar::new_spinner();
+        let check_pb = if quiet { ProgressBar::hidden() } else { ProgressBar::new_spinner() };
         check_pb.set_style(
             ProgressStyle::default_spinner()
                 .tick_strings(&["🔍", "🔎", "🕵️", "🔍", "🔎", "🕵️"])
@@ -119,15 +222,40 @@ async fn run_domain_forge(description: &str) -> Result<()> {
         check_pb.enable_steady_tick(Duration::from_millis(100));
         check_pb.set_message(format!("🔍 Checking {} domains for availability...", domain_names.len()));
 
-        let results = checker.check_domains(&domain_names).await?;
+        // Show results as they arrive, then line them up with the suggestions
+        let mut checked: Vec<Option<DomainResult>> = vec![None; domain_names.len()];
+        let mut available = 0;
+        let mut done = 0;
+        let mut stream = std::pin::pin!(checker.check_stream(&domain_names));
+        while let Some((index, result)) = stream.next().await {
+            done += 1;
+            let result = result.unwrap_or_else(|e| {
+                tracing::warn!(domain = %domain_names[index], error = %e, "Failed to check domain");
+                DomainResult::error(domain_names[index].trim().to_lowercase(), e.to_string())
+            });
+            if result.status == AvailabilityStatus::Available {
+                available += 1;
+                check_pb.println(format!("   🎉 {} is available", result.domain));
+            }
+            checked[index] = Some(result);
+            check_pb.set_message(format!(
+                "🔍 Checked {}/{} domains • {} available so far",
+                done,
+                domain_names.len(),
+                available
+            ));
+        }
         check_pb.finish_with_message("✅ Domain availability check complete!");
         let round_time = round_start.elapsed();
 
-        // Update session with results
+        // Update session with results (one per domain, in the same order);
+        // the stream yields every index exactly once
+        let results: Vec<DomainResult> = checked.into_iter().flatten().collect();
         session.add_round_results(&domains, &results, round_time);
+        all_results.extend(results);
 
         // Display beautiful results
-        render_results_panel(&session, &domains, &results, round_time);
+        render_results_panel(&session, &domains, round_time);
 
         // Show menu and get user choice
         match show_menu_and_get_choice()? {
@@ -137,12 +265,12 @@ async fn run_domain_forge(description: &str) -> Result<()> {
             }
             MenuOption::ShowAvailable => {
                 // Show available domains only
-                show_available_domains_only(&session);
+                show_available_domains_only(&mut session);
                 // Show menu again after displaying available domains
                 match show_menu_and_get_choice()? {
                     MenuOption::GenerateMore => continue,
                     MenuOption::SaveToFile => {
-                        if let Err(e) = save_results_to_file(&session, &final_description) {
+                        if let Err(e) = save_results_to_file(&session, &all_results, &args.output_dir, output_format) {
                             eprintln!("❌ Failed to save file: {}", e);
                         }
                         break;
@@ -152,7 +280,7 @@ async fn run_domain_forge(description: &str) -> Result<()> {
             }
             MenuOption::SaveToFile => {
                 // Download results to file
-                if let Err(e) = save_results_to_file(&session, &final_description) {
+                if let Err(e) = save_results_to_file(&session, &all_results, &args.output_dir, output_format) {
                     eprintln!("❌ Failed to save file: {}", e);
                 }
                 break;
@@ -164,6 +292,18 @@ async fn run_domain_forge(description: &str) -> Result<()> {
         }
     }
 
+    let stats = generator.get_metrics_snapshot().combined(&checker.get_metrics_snapshot());
+    *run_metrics = Some(stats.clone());
+    session.api_cost_usd = previous_cost + stats.estimated_cost_usd;
+
+    // Keep the session so a later run can --resume it
+    let session_file = args.output_dir.join(format!("session_{}.json",
+        chrono::Utc::now().format("%Y%m%d_%H%M%S")));
+    match session.save(&session_file) {
+        Ok(()) => println!("💾 Session saved to: {}", session_file.display()),
+        Err(e) => eprintln!("❌ Failed to save session: {}", e),
+    }
+
     // Final summary
     if !session.available_domains.is_empty() {
         println!();
@@ -174,12 +314,68 @@ async fn run_domain_forge(description: &str) -> Result<()> {
         println!("👋 Session ended. No available domains found.");
     }
 
+    let summary = session.statistics();
+    println!();
+    println!("📊 Session Statistics");
+    println!("   Rounds:          {}", summary.total_rounds);
+    println!("   Checked:         {}", summary.total_checked);
+    println!("   Available:       {} ({:.1} per round)", summary.total_available, summary.avg_available_per_round);
+    println!("   Taken:           {}", summary.total_taken);
+    if let Some(best) = summary.best_round.and_then(|n| session.rounds.iter().find(|r| r.round_number == n)) {
+        println!("   Best round:      #{} ({} available)", best.round_number, best.available.len());
+    }
+    if summary.total_api_cost_estimate_usd > 0.0 {
+        println!("   Est. API cost:   ${:.4}", summary.total_api_cost_estimate_usd);
+    }
+
+    println!("📈 {}", stats);
+
     Ok(())
 }
 
-/// Get a random description for when no user input is provided
-fn get_random_description() -> String {
-    let random_prompts = vec![
+/// Run a single generation round and print the results as JSON
+async fn run_generate_json(args: &GenerateArgs, run_metrics: &mut Option<MetricsSnapshot>) -> Result<()> {
+    let mut generator = DomainGenerator::new();
+    setup_llm_providers(&mut generator, args.max_tokens, true)?;
+
+    let description = args.description();
+    let description = if description.is_empty() {
+        random_description().to_string()
+    } else {
+        description
+    };
+
+    let mut session = match &args.resume {
+        Some(path) => DomainSession::load(path)?,
+        None => DomainSession::new(),
+    };
+    let round_start = std::time::Instant::now();
+    let domains: Vec<DomainSuggestion> = generate_domains_for_round(&generator, &description, args, &session, true)
+        .await?
+        .into_iter()
+        .filter(|d| !session.is_checked(&d.get_full_domain()))
+        .collect();
+
+    let checker = DomainChecker::with_config(config::current().check_config());
+    let domain_names: Vec<String> = domains.iter().map(|d| d.get_full_domain()).collect();
+    let results: Vec<DomainResult> = checker
+        .check_domains_ordered(&domain_names)
+        .await?
+        .into_iter()
+        .map(|(_, result)| result)
+        .collect();
+    session.add_round_results(&domains, &results, round_start.elapsed());
+    *run_metrics = Some(generator.get_metrics_snapshot().combined(&checker.get_metrics_snapshot()));
+
+    let output = JsonOutput::from_session(&session, &description);
+    println!("{}", serde_json::to_string_pretty(&output)?);
+
+    Ok(())
+}
+
+/// Pick a random description for when no user input is provided
+fn random_description() -> &'static str {
+    let random_prompts = [
         "innovative tech startup",
         "creative digital agency", 
         "modern e-commerce platform",
@@ -191,14 +387,45 @@ fn get_random_description() -> String {
     ];
     
     let mut rng = rand::thread_rng();
-    let prompt = random_prompts[rng.gen_range(0..random_prompts.len())];
+    random_prompts[rng.gen_range(0..random_prompts.len())]
+}
+
+/// Get a random description for when no user input is provided
+fn get_random_description() -> String {
+    let prompt = random_description();
     println!("🎲 Generating random domains for: \"{}\"", prompt);
     prompt.to_string()
 }
 
+/// Health-check providers before a session; fails only if none of them work
+async fn verify_providers(generator: &DomainGenerator, quiet: bool) -> Result<()> {
+    let pb = create_ai_progress_bar(quiet);
+    pb.set_message("Checking LLM providers...");
+    let report = generator.check_all_providers().await;
+    pb.finish_and_clear();
+
+    let mut failed: Vec<(&String, &domain_forge::DomainForgeError)> = report
+        .iter()
+        .filter_map(|(name, result)| result.as_ref().err().map(|e| (name, e)))
+        .collect();
+    failed.sort_by_key(|(name, _)| *name);
+
+    if !failed.is_empty() && failed.len() == report.len() {
+        let details: Vec<String> = failed.iter().map(|(name, e)| format!("{}: {}", name, e)).collect();
+        return Err(domain_forge::DomainForgeError::config(format!(
+            "No LLM provider passed its health check ({}). Use --skip-health-check to start anyway.",
+            details.join("; ")
+        )));
+    }
+    for (name, e) in failed {
+        eprintln!("⚠️  Provider {} failed its health check: {}", name, e);
+    }
+    Ok(())
+}
+
 /// Create a beautiful progress bar for AI generation
-fn create_ai_progress_bar() -> ProgressBar {
-    let pb = ProgressBar::new_spinner();
+fn create_ai_progress_bar(quiet: bool) -> ProgressBar {
+    let pb = if quiet { ProgressBar::hidden() } else { ProgressBar::new_spinner() };
     pb.set_style(
         ProgressStyle::default_spinner()
             .tick_strings(&["🤖", "🧠", "💭", "✨", "🎯", "🔮", "⚡", "🚀"])
@@ -212,22 +439,28 @@ fn create_ai_progress_bar() -> ProgressBar {
 
 
 /// Generate domains for a single round, considering previous session state
-async fn generate_domains_for_round(generator: &DomainGenerator, description: &str, session: &DomainSession) -> Result<Vec<DomainSuggestion>> {
+async fn generate_domains_for_round(
+    generator: &DomainGenerator,
+    description: &str,
+    args: &GenerateArgs,
+    session: &DomainSession,
+    quiet: bool,
+) -> Result<Vec<DomainSuggestion>> {
     // Let LLM handle everything - it's smart enough to understand user intent
-    let tlds = vec!["com".to_string(), "org".to_string(), "io".to_string(), "ai".to_string(), "tech".to_string(), "dev".to_string(), "app".to_string()];
-
     let config = GenerationConfig {
         description: description.to_string(),
-        count: 20,
-        style: domain_forge::types::GenerationStyle::Creative,
-        tlds,
+        count: args.count,
+        style: args.style,
+        tlds: args.tld_list(),
         temperature: 0.7,
         avoid_names: session.get_taken_domain_names(), // Smart avoidance!
+        min_name_length: args.min_length,
+        max_name_length: args.max_length,
         ..Default::default()
     };
 
     // Show beautiful progress for AI generation
-    let pb = create_ai_progress_bar();
+    let pb = create_ai_progress_bar(quiet);
     if session.round_count == 0 {
         pb.set_message("🎨 AI is crafting creative domain names...");
     } else {
@@ -242,18 +475,35 @@ async fn generate_domains_for_round(generator: &DomainGenerator, description: &s
 }
 
 /// Setup LLM providers from environment variables
-fn setup_llm_providers(generator: &mut DomainGenerator) -> Result<()> {
+fn setup_llm_providers(generator: &mut DomainGenerator, max_tokens: Option<u32>, quiet: bool) -> Result<()> {
+    // Provider from the config file (already merged with environment overrides)
+    let max_tokens = max_tokens.or_else(|| config::current().llm.max_tokens);
+    if let Some(config) = config::current().llm_config() {
+        let config = LlmConfig { max_tokens, ..config };
+        if !config.api_key.is_empty() || matches!(config.provider.as_str(), "ollama" | "generic") {
+            let name = config.provider.clone();
+            generator.add_provider(&config)?;
+            generator.set_default_provider(&name);
+            if !quiet {
+                println!("✅ {} provider configured from config file", name);
+            }
+        }
+    }
+    let from_config = generator.is_ready();
+
     // Try to add OpenAI provider
-    if let Ok(api_key) = env::var("OPENAI_API_KEY") {
+    if let (false, Ok(api_key)) = (generator.has_provider("openai"), env::var("OPENAI_API_KEY")) {
         let base_url = env::var("OPENAI_BASE_URL").ok();
         let model = env::var("OPENAI_MODEL").unwrap_or_else(|_| "gpt-4.1-mini".to_string());
 
         // Debug information
-        println!("🔧 Debug: API Key length: {}", api_key.len());
-        if let Some(ref url) = base_url {
-            println!("🔧 Debug: Base URL: {}", url);
+        if !quiet {
+            println!("🔧 Debug: API Key length: {}", api_key.len());
+            if let Some(ref url) = base_url {
+                println!("🔧 Debug: Base URL: {}", url);
+            }
+            println!("🔧 Debug: Model: {}", model);
         }
-        println!("🔧 Debug: Model: {}", model);
 
         let config = LlmConfig {
             provider: "openai".to_string(),
@@ -261,108 +511,122 @@ fn setup_llm_providers(generator: &mut DomainGenerator) -> Result<()> {
             api_key,
             base_url,
             temperature: 0.7,
+            use_structured_output: true,
+            max_tokens,
         };
         generator.add_provider(&config)?;
-        generator.set_default_provider("openai");
-        println!("✅ OpenAI provider configured");
+        if !from_config {
+            generator.set_default_provider("openai");
+        }
+        if !quiet {
+            println!("✅ OpenAI provider configured");
+        }
     }
 
     // Try to add Anthropic provider
-    if let Ok(api_key) = env::var("ANTHROPIC_API_KEY") {
+    if let (false, Ok(api_key)) = (generator.has_provider("anthropic"), env::var("ANTHROPIC_API_KEY")) {
         let config = LlmConfig {
             provider: "anthropic".to_string(),
             model: env::var("ANTHROPIC_MODEL").unwrap_or_else(|_| "claude-4-sonnet".to_string()),
             api_key,
             base_url: None,
             temperature: 0.7,
+            use_structured_output: true,
+            max_tokens,
         };
         generator.add_provider(&config)?;
-        if !generator.has_provider("openai") {
+        if !from_config && !generator.has_provider("openai") {
             generator.set_default_provider("anthropic");
         }
-        println!("✅ Anthropic provider configured");
+        if !quiet {
+            println!("✅ Anthropic provider configured");
+        }
     }
 
     // Try to add Gemini provider
-    if let Ok(api_key) = env::var("GEMINI_API_KEY") {
+    if let (false, Ok(api_key)) = (generator.has_provider("gemini"), env::var("GEMINI_API_KEY")) {
         let config = LlmConfig {
             provider: "gemini".to_string(),
             model: env::var("GEMINI_MODEL").unwrap_or_else(|_| "gemini-2.5-flash".to_string()),
             api_key,
             base_url: None,
             temperature: 0.7,
+            use_structured_output: true,
+            max_tokens,
         };
         generator.add_provider(&config)?;
-        if !generator.has_provider("openai") && !generator.has_provider("anthropic") {
+        if !from_config && !generator.has_provider("openai") && !generator.has_provider("anthropic") {
             generator.set_default_provider("gemini");
         }
-        println!("✅ Gemini provider configured");
+        if !quiet {
+            println!("✅ Gemini provider configured");
+        }
+    }
+
+    // Try to add OpenRouter provider
+    if let (false, Ok(api_key)) = (generator.has_provider("openrouter"), env::var("OPENROUTER_API_KEY")) {
+        let config = LlmConfig {
+            provider: "openrouter".to_string(),
+            model: env::var("OPENROUTER_MODEL")
+                .unwrap_or_else(|_| domain_forge::llm::providers::openrouter::OPENROUTER_DEFAULT_MODEL.to_string()),
+            api_key,
+            base_url: env::var("OPENROUTER_BASE_URL").ok(),
+            temperature: 0.7,
+            use_structured_output: true,
+            max_tokens,
+        };
+        generator.add_provider(&config)?;
+        if !from_config && !["openai", "anthropic", "gemini"].iter().any(|p| generator.has_provider(p)) {
+            generator.set_default_provider("openrouter");
+        }
+        if !quiet {
+            println!("✅ OpenRouter provider configured");
+        }
+    }
+
+    // Try to add a generic OpenAI-compatible endpoint (LM Studio, LocalAI, vLLM, ...)
+    if let (false, Ok(base_url)) = (generator.has_provider("generic"), env::var("GENERIC_LLM_BASE_URL")) {
+        let config = LlmConfig {
+            provider: "generic".to_string(),
+            model: env::var("GENERIC_LLM_MODEL").unwrap_or_else(|_| "default".to_string()),
+            api_key: env::var("GENERIC_LLM_API_KEY").unwrap_or_default(),
+            base_url: Some(base_url),
+            temperature: 0.7,
+            use_structured_output: true,
+            max_tokens,
+        };
+        generator.add_provider(&config)?;
+        if !from_config && !["openai", "anthropic", "gemini", "openrouter"].iter().any(|p| generator.has_provider(p)) {
+            generator.set_default_provider("generic");
+        }
+        if !quiet {
+            println!("✅ Generic LLM provider configured ({})", config.base_url.as_deref().unwrap_or_default());
+        }
     }
 
     if !generator.is_ready() {
         return Err(domain_forge::DomainForgeError::config(
-            "No LLM providers configured. Please set OPENAI_API_KEY, ANTHROPIC_API_KEY, or GEMINI_API_KEY environment variable.".to_string()
+            "No LLM providers configured. Please set OPENAI_API_KEY, ANTHROPIC_API_KEY, GEMINI_API_KEY, OPENROUTER_API_KEY, or GENERIC_LLM_BASE_URL environment variable.".to_string()
         ));
     }
 
     Ok(())
 }
 
-/// Print help information
-fn print_help() {
-    println!("Domain Forge - AI-powered domain name generation");
-    println!("=================================================");
-    println!();
-    println!("USAGE:");
-    println!("    domain-forge [DESCRIPTION]       Generate domains for description");
-    println!("    domain-forge snipe [OPTIONS]     Scan for available short domains");
-    println!("    domain-forge snipe recheck <RESULT_JSON...>  Recheck & update saved results in-place");
-    println!();
-    println!("SNIPE MODES:");
-    println!("    domain-forge snipe                    Full 4-letter scan (all 456k)");
-    println!("    domain-forge snipe -p                 4-letter pronounceable (~137k)");
-    println!("    domain-forge snipe -w                 5-letter meaningful words (~5k)");
-    println!("    domain-forge snipe --six              6-letter pronounceable (~351k)");
-    println!("    domain-forge snipe -R                 5-letter readable names (~27k)");
-    println!();
-    println!("SNIPE OPTIONS:");
-    println!("    -l, --length <N>      Domain length to scan (2-10, default: 4)");
-    println!("    -w, --words           Scan 5-letter meaningful words (recommended!)");
-    println!("    -p, --pronounceable   Scan 4-letter pronounceable patterns");
-    println!("        --six             Scan 6-letter pronounceable patterns");
-    println!("    -R, --readable        Scan 5-letter readable/brandable names (~27k)");
-    println!("    -t, --tld <TLD>       TLDs to scan (comma-separated, default: com)");
-    println!("    -a, --alphanumeric    Include digits (a-z, 0-9)");
-    println!("    -c, --concurrency <N> Concurrent checks (default: 20)");
-    println!("    --rate <MS>           Delay between batches in ms (default: 500)");
-    println!("    -r, --resume          Resume previous scan");
-    println!("    -e, --expiring <DAYS> Days threshold for expiring soon (default: 7)");
-    println!();
-    println!("SNIPE RECHECK:");
-    println!("    domain-forge snipe recheck output/snipe_results_*.json");
-    println!();
-    println!("EXAMPLES:");
-    println!("    domain-forge snipe -l 3 --tld ai       # 3-letter domains on .ai");
-    println!("    domain-forge snipe -w --tld com,io    # 5-letter words on .com/.io");
-    println!("    domain-forge snipe -w -c 30           # 5-letter words, 30 concurrent");
-    println!("    domain-forge \"AI productivity app\"    # AI-generated domains");
-    println!();
-    println!("Made with Rust");
-}
-
 // ===== Beautiful Terminal UI Functions =====
 
 /// Render a beautiful results panel for the current round
-fn render_results_panel(session: &DomainSession, round_domains: &[DomainSuggestion], round_results: &[DomainResult], round_time: std::time::Duration) {
-    let round_available: Vec<&DomainSuggestion> = round_domains.iter().zip(round_results.iter())
-        .filter(|(_, result)| result.status == AvailabilityStatus::Available)
-        .map(|(domain, _)| domain)
-        .collect();
-    
-    let round_taken: Vec<&DomainSuggestion> = round_domains.iter().zip(round_results.iter())
-        .filter(|(_, result)| result.status == AvailabilityStatus::Taken)
-        .map(|(domain, _)| domain)
-        .collect();
+fn render_results_panel(session: &DomainSession, round_domains: &[DomainSuggestion], round_time: std::time::Duration) {
+    let Some(round) = session.rounds.last() else {
+        return;
+    };
+    let in_round = |names: &[String]| -> Vec<&DomainSuggestion> {
+        round_domains.iter().filter(|d| names.contains(&d.get_full_domain())).collect()
+    };
+
+    let mut round_available = in_round(&round.available);
+    domain_forge::domain::scoring::sort_by_score(&mut round_available);
+    let round_taken = in_round(&round.taken);
 
     println!();
     println!("╭─ Round {} Results ─────────────────────────────────────╮", session.round_count);
@@ -381,17 +645,7 @@ fn render_results_panel(session: &DomainSession, round_domains: &[DomainSuggesti
     if round_available.is_empty() {
         println!("│  │  (none found this round)                    │  │");
     } else {
-        for chunk in round_available.chunks(3) {
-            print!("│  │  ");
-            for domain in chunk {
-                print!("✅ {:<12}", domain.get_full_domain());
-            }
-            // Fill remaining space
-            for _ in chunk.len()..3 {
-                print!("             ");
-            }
-            println!(" │  │");
-        }
+        print_scored_domains(&round_available);
     }
     
     println!("│  └─────────────────────────────────────────────────┘  │");
@@ -419,16 +673,7 @@ fn render_results_panel(session: &DomainSession, round_domains: &[DomainSuggesti
     if session.round_count > 1 && !session.available_domains.is_empty() {
         println!("│  🏆 Total Available Domains ({:<2})                    │", session.available_domains.len());
         println!("│  ┌─────────────────────────────────────────────────┐  │");
-        for chunk in session.available_domains.chunks(3) {
-            print!("│  │  ");
-            for domain in chunk {
-                print!("✅ {:<12}", domain.get_full_domain());
-            }
-            for _ in chunk.len()..3 {
-                print!("             ");
-            }
-            println!(" │  │");
-        }
+        print_scored_domains(&session.ranked_available());
         println!("│  └─────────────────────────────────────────────────┘  │");
         println!("│                                                       │");
     }
@@ -449,6 +694,38 @@ fn render_results_panel(session: &DomainSession, round_domains: &[DomainSuggesti
     println!("╰───────────────────────────────────────────────────────╯");
 }
 
+/// Width of a row inside the panels' inner `┌─┐` box, between its padding
+const INNER_ROW_WIDTH: usize = 46;
+
+/// Print available domains two per row, best score first, each with its score
+fn print_scored_domains(domains: &[&DomainSuggestion]) {
+    for chunk in domains.chunks(2) {
+        let cells: Vec<String> = chunk
+            .iter()
+            .map(|domain| format!("✅ {:<14} {:.2}", domain.get_full_domain(), domain.score()))
+            .collect();
+        let row = cells.join("  ");
+        // Each ✅ is one char but two terminal columns wide
+        let width = row.chars().count() + cells.len();
+        println!("│  │  {}{} │  │", row, " ".repeat(INNER_ROW_WIDTH.saturating_sub(width)));
+    }
+}
+
+/// Print a yellow warning for each domain containing look-alike characters
+fn warn_confusables<I: IntoIterator<Item = String>>(domains: I) {
+    for domain in domains {
+        let found = DomainValidator::detect_confusables(&domain);
+        if found.is_empty() {
+            continue;
+        }
+        let details: Vec<String> = found.iter().map(|c| c.to_string()).collect();
+        println!(
+            "{}",
+            console::style(format!("⚠️  {} contains confusable characters: {}", domain, details.join("; "))).yellow()
+        );
+    }
+}
+
 /// Show interactive menu and get user choice
 fn show_menu_and_get_choice() -> Result<MenuOption> {
     println!();
@@ -469,7 +746,7 @@ fn show_menu_and_get_choice() -> Result<MenuOption> {
 }
 
 /// Show only available domains in a clean format
-fn show_available_domains_only(session: &DomainSession) {
+fn show_available_domains_only(session: &mut DomainSession) {
     println!();
     println!("╭─ Available Domains Summary ───────────────────────────╮");
     println!("│                                                       │");
@@ -480,18 +757,8 @@ fn show_available_domains_only(session: &DomainSession) {
     } else {
         println!("│  🎉 Found {} Available Domains:                      │", session.available_domains.len());
         println!("│  ┌─────────────────────────────────────────────────┐  │");
-        
-        for chunk in session.available_domains.chunks(3) {
-            print!("│  │  ");
-            for domain in chunk {
-                print!("✅ {:<12}", domain.get_full_domain());
-            }
-            for _ in chunk.len()..3 {
-                print!("             ");
-            }
-            println!(" │  │");
-        }
-        
+        session.sort_available_by_confidence();
+        print_scored_domains(&session.available_domains.iter().collect::<Vec<_>>());
         println!("│  └─────────────────────────────────────────────────┘  │");
     }
     
@@ -504,166 +771,113 @@ fn show_available_domains_only(session: &DomainSession) {
 }
 
 /// Save results to a file
-fn save_results_to_file(session: &DomainSession, description: &str) -> io::Result<()> {
+fn save_results_to_file(
+    session: &DomainSession,
+    results: &[DomainResult],
+    output_dir: &Path,
+    format: OutputFormat,
+) -> Result<()> {
     use std::fs;
 
     // Ensure output directory exists
-    fs::create_dir_all("output")?;
+    fs::create_dir_all(output_dir)
+        .map_err(|e| domain_forge::DomainForgeError::io(e.to_string(), Some(output_dir.display().to_string())))?;
 
     let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
-    let filename = format!("output/domains_{}.txt", timestamp);
-    
-    let mut content = String::new();
-    content.push_str(&format!("Domain Forge Results\n"));
-    content.push_str(&format!("Generated: {}\n", chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")));
-    content.push_str(&format!("Search: {}\n", description));
-    content.push_str(&format!("Rounds: {}\n", session.round_count));
-    content.push_str(&format!("Total Time: {:.1}s\n", session.total_time.as_secs_f32()));
-    content.push_str(&format!("Total Checked: {}\n\n", session.total_domains_checked()));
-    
-    content.push_str(&format!("=== AVAILABLE DOMAINS ({}) ===\n", session.available_domains.len()));
-    if session.available_domains.is_empty() {
-        content.push_str("None found.\n");
-    } else {
-        for domain in &session.available_domains {
-            content.push_str(&format!("{}\n", domain.get_full_domain()));
-        }
-    }
-    
-    content.push_str(&format!("\n=== TAKEN DOMAINS ({}) ===\n", session.taken_domains.len()));
-    for domain in &session.taken_domains {
-        content.push_str(&format!("{}\n", domain));
-    }
-    
-    if !session.error_domains.is_empty() {
-        content.push_str(&format!("\n=== ERRORS ({}) ===\n", session.error_domains.len()));
-        for (domain, error) in &session.error_domains {
-            content.push_str(&format!("{}: {}\n", domain, error));
-        }
+    let filename = output_dir.join(format!("domains_{}.{}", timestamp, format.extension()));
+
+    match format {
+        // Check results keep the registrar and expiry details a session drops
+        OutputFormat::Csv => CsvExporter::new().with_bom(cfg!(windows)).save(&filename, results)?,
+        _ => ExporterFactory::for_extension(format.extension())?.save(session, &filename)?,
     }
-    
-    fs::write(&filename, content)?;
 
     println!();
-    println!("File saved to: {}", filename);
+    println!("File saved to: {}", filename.display());
     println!("  {} available domains", session.available_domains.len());
     println!("  {} taken domains", session.taken_domains.len());
 
     Ok(())
 }
 
-// ===== Snipe Command =====
-
-/// Parse snipe command arguments
-fn parse_snipe_args(args: &[String]) -> SnipeConfig {
-    let mut config = SnipeConfig::default();
-
-    let mut i = 0;
-    while i < args.len() {
-        match args[i].as_str() {
-            "--tld" | "-t" => {
-                if i + 1 < args.len() {
-                    config.tlds = args[i + 1]
-                        .split(',')
-                        .map(|s| s.trim().to_lowercase())
-                        .collect();
-                    i += 1;
-                }
-            }
-            "--length" | "-l" => {
-                if i + 1 < args.len() {
-                    if let Ok(n) = args[i + 1].parse::<usize>() {
-                        if n >= 2 && n <= 10 {
-                            config.length = n;
-                        }
-                    }
-                    i += 1;
-                }
-            }
-            "--resume" | "-r" => {
-                config.state_file = Some(ScanState::default_path(config.length));
-            }
-            "--alphanumeric" | "-a" => {
-                config.charset = Charset::Alphanumeric;
-            }
-            "--pronounceable" | "-p" => {
-                config.mode = ScanMode::Pronounceable;
-            }
-            "--words" | "-w" => {
-                config.mode = ScanMode::Words;
-            }
-            "--six" | "-6" => {
-                config.mode = ScanMode::Six;
-            }
-            "--readable" | "-R" => {
-                config.mode = ScanMode::Readable;
-            }
-            "--concurrency" | "-c" => {
-                if i + 1 < args.len() {
-                    if let Ok(n) = args[i + 1].parse() {
-                        config.concurrency = n;
-                    }
-                    i += 1;
-                }
-            }
-            "--expiring" | "-e" => {
-                if i + 1 < args.len() {
-                    if let Ok(n) = args[i + 1].parse() {
-                        config.expiring_days = n;
-                    }
-                    i += 1;
-                }
-            }
-            "--rate" => {
-                if i + 1 < args.len() {
-                    if let Ok(n) = args[i + 1].parse() {
-                        config.rate_limit_ms = n;
-                    }
-                    i += 1;
-                }
-            }
-            _ => {}
+/// Distinct names of `domains`, in list order
+fn names_in_order(domains: &[SnipedDomain]) -> Vec<&str> {
+    let mut names: Vec<&str> = Vec::new();
+    for domain in domains {
+        if !names.contains(&domain.domain.as_str()) {
+            names.push(&domain.domain);
         }
-        i += 1;
     }
+    names
+}
 
-    config
+/// Scan ETA as `2h 34m`, `5m 12s` or `42s`
+fn format_eta(eta: Option<Duration>) -> String {
+    let Some(eta) = eta else {
+        return "--".to_string();
+    };
+    let secs = eta.as_secs();
+    match (secs / 3600, secs % 3600 / 60, secs % 60) {
+        (0, 0, s) => format!("{}s", s),
+        (0, m, s) => format!("{}m {}s", m, s),
+        (h, m, _) => format!("{}h {}m", h, m),
+    }
 }
 
+// ===== Snipe Command =====
+
 /// Run the snipe command
-async fn run_snipe_command(args: &[String]) -> Result<()> {
-    // Subcommand: recheck expiring_soon in existing result files
-    if args.first().map(|s| s.as_str()) == Some("recheck") {
-        return run_snipe_recheck_command(&args[1..]).await;
+async fn run_snipe_command(
+    args: &SnipeArgs,
+    quiet: bool,
+    json: bool,
+    run_metrics: &mut Option<MetricsSnapshot>,
+) -> Result<()> {
+    let format = args.output_format(OutputFormat::Json);
+
+    let mut config = args.to_config(config::current().snipe_config());
+    if let Some(path) = &args.words_from {
+        config.word_list = Some(WordGenerator::read_words(path)?);
+    }
+    for path in &args.skip_checked {
+        let state = ScanState::load(path)?;
+        config.skip_domains.extend(state.known_domains().map(str::to_string));
     }
 
-    let config = parse_snipe_args(args);
+    if json {
+        return run_snipe_json(config, &args.output_dir, format, &args.filter, run_metrics).await;
+    }
 
     // Check for unsupported TLDs
-    let supported_tlds = ["com", "net", "org", "io", "ai", "tech", "app", "dev", "xyz", "co", "me"];
     let unsupported: Vec<_> = config.tlds.iter()
-        .filter(|tld| !supported_tlds.contains(&tld.as_str()))
+        .filter(|tld| config.rdap_base_url(tld).is_none())
         .collect();
-
     if !unsupported.is_empty() {
         println!("⚠️  Warning: Unsupported TLDs will be skipped: {}",
             unsupported.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", "));
-        println!("   Supported TLDs: {}", supported_tlds.join(", "));
+        println!("   Supported TLDs: {}", supported_tlds().collect::<Vec<_>>().join(", "));
         println!();
     }
 
-    let mode_title = match config.mode {
+    let mode_title = match &config.mode {
         ScanMode::Full => format!("{}-letter domain scanner", config.length),
         ScanMode::Pronounceable => "4-letter pronounceable scanner".to_string(),
         ScanMode::Words => "5-letter word scanner".to_string(),
         ScanMode::Six => "6-letter pronounceable scanner".to_string(),
         ScanMode::Readable => "5-letter readable name scanner".to_string(),
+        ScanMode::ThreeLetter => "3-letter domain scanner".to_string(),
+        ScanMode::Pattern(pattern) => format!("pattern scanner ({})", pattern),
     };
 
     println!("Domain Sniper - {}", mode_title);
     println!("{}", "=".repeat(18 + mode_title.len()));
     println!();
 
+    if !config.skip_domains.is_empty() {
+        println!("Skipping {} domains already in result files", config.skip_domains.len());
+        println!();
+    }
+
     // Check if resuming
     let is_resume = config.state_file.is_some()
         && config.state_file.as_ref().map(|p| p.exists()).unwrap_or(false);
@@ -682,43 +896,73 @@ async fn run_snipe_command(args: &[String]) -> Result<()> {
             Err(e) => {
                 println!("  Failed to resume: {}", e);
                 println!("  Starting fresh scan...");
-                DomainSniper::new(config.clone())
+                DomainSniper::new(config.clone())?
             }
         }
     } else {
-        DomainSniper::new(config.clone())
+        DomainSniper::new(config.clone())?
     };
 
     let total = sniper.state().total_combinations;
-    let mode_name = match config.mode {
-        ScanMode::Full => match config.charset {
+    let mode_name = match &config.mode {
+        ScanMode::Full | ScanMode::Pattern(_) => match config.charset {
             Charset::Letters => "all combinations (a-z)",
             Charset::Alphanumeric => "all combinations (a-z, 0-9)",
+            Charset::Custom(_) => "all combinations (custom charset)",
         },
         ScanMode::Pronounceable => "pronounceable patterns (CVCV)",
         ScanMode::Words => "meaningful 5-letter words",
         ScanMode::Six => "pronounceable 6-letter patterns (CVCVCV/VCVCVC)",
         ScanMode::Readable => "readable 5-letter names (CVCVC, ends with n/r/s/l)",
+        ScanMode::ThreeLetter => "3-letter domain scanner (a-z, 17,576 combos)",
     };
 
-    let length_display = match config.mode {
+    let length_display = match &config.mode {
         ScanMode::Words => "5".to_string(),
         ScanMode::Six => "6".to_string(),
         ScanMode::Pronounceable => "4".to_string(),
         ScanMode::Readable => "5".to_string(),
+        ScanMode::ThreeLetter => "3".to_string(),
         ScanMode::Full => config.length.to_string(),
+        ScanMode::Pattern(pattern) => pattern.chars().count().to_string(),
     };
 
     println!("Scan Configuration:");
     println!("  Length:      {} characters", length_display);
     println!("  Mode:        {}", mode_name);
+    if let (ScanMode::Full | ScanMode::Pattern(_), Charset::Custom(chars)) = (&config.mode, &config.charset) {
+        println!("  Charset:     {}", chars.iter().collect::<String>());
+    }
+    if let (Some(path), Some(words)) = (&args.words_from, &config.word_list) {
+        println!("  Word list:   {} words from {}", words.len(), path.display());
+    }
     println!("  TLDs:        {}", config.tlds.join(", "));
+    if let Some(chunk) = config.chunk {
+        println!("  Chunk:       {} (state: {})", chunk, sniper.state_path().display());
+    }
+    if !config.skip_patterns.is_empty() {
+        println!("  Skipping:    names matching {}", config.skip_patterns.join(", "));
+    }
     println!("  Total:       {} domains", total);
     println!("  Concurrency: {}", config.concurrency);
+    if !config.tags.is_empty() {
+        println!("  Tags:        {}", config.tags.join(", "));
+    }
+    if let Some(smtp) = &config.smtp {
+        println!("  Email:       {} (via {})", smtp.to_address, smtp.host);
+    }
+    if let Some(webhook) = &config.webhook {
+        println!("  Webhook:     {}", webhook.url);
+    }
     println!();
 
+    if config.dry_run {
+        println!("{}", sniper.dry_run_report());
+        return Ok(());
+    }
+
     // Create progress bar
-    let pb = ProgressBar::new(total);
+    let pb = if quiet { ProgressBar::hidden() } else { ProgressBar::new(total) };
     pb.set_style(
         ProgressStyle::with_template(
             "{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({percent}%) | {msg}"
@@ -728,20 +972,60 @@ async fn run_snipe_command(args: &[String]) -> Result<()> {
     );
     pb.enable_steady_tick(Duration::from_millis(200));
 
+    let control = sniper.control();
+    if let Err(e) = control.listen(&sniper.state_path()) {
+        eprintln!("⚠️  {}", e);
+    }
+
     // Run the scan
+    let max_found = config.max_found;
     let result = sniper.run(|progress| {
+        if let Some(pause) = &progress.paused {
+            pb.set_message(format!("Paused: delete {} to resume", pause.display()));
+            return;
+        }
         pb.set_position(progress.current);
+        let mut per_tld: Vec<_> = progress.per_tld_counts.iter().collect();
+        per_tld.sort();
+        let per_tld = if per_tld.len() > 1 {
+            let counts: Vec<String> = per_tld.iter().map(|(tld, count)| format!(".{} {}", tld, count)).collect();
+            format!(" ({})", counts.join(", "))
+        } else {
+            String::new()
+        };
+        let available = match max_found {
+            Some(max) => format!("Found {} / {} target domains", progress.available_count, max),
+            None => format!("{} avail", progress.available_count),
+        };
         pb.set_message(format!(
-            "{:.1}/s | {} avail | {} expiring | {} expired | {} err",
-            progress.domains_per_second,
-            progress.available_count,
+            "ETA: {} | {:.1}/s | {}{} | {} deleting | {} expiring | {} expired | {} err",
+            format_eta(progress.eta),
+            progress.rolling_rate,
+            available,
+            per_tld,
+            progress.pending_delete_count,
             progress.expiring_count,
             progress.expired_count,
             progress.error_count
         ));
-    }).await;
+    }).await.map(|_| ());
 
-    pb.finish_with_message("Scan complete!");
+    if control.is_shutdown_requested() {
+        pb.finish_with_message("Scan paused");
+        println!();
+        println!("Progress saved to {}. Use --resume to continue.", sniper.state_path().display());
+        return Ok(());
+    }
+    if sniper.target_reached() {
+        pb.finish_with_message("Target reached");
+        println!();
+        println!("Progress saved to {}. Rerun with --resume and a higher --max-found to find more.", sniper.state_path().display());
+    } else {
+        pb.finish_with_message("Scan complete!");
+    }
+    filter_scan_results(&mut sniper, &args.filter);
+    *run_metrics = Some(sniper.metrics_snapshot());
+    let result = result.map(|()| sniper.state());
 
     match result {
         Ok(state) => {
@@ -755,8 +1039,33 @@ async fn run_snipe_command(args: &[String]) -> Result<()> {
                 println!("No available domains found.");
             } else {
                 println!("Available Domains ({}):", state.available.len());
-                for domain in &state.available {
-                    println!("  {} - {}", domain.full_domain, domain.found_at.format("%Y-%m-%d %H:%M"));
+                if config.tlds.len() > 1 {
+                    let groups = state.available_grouped_by_name();
+                    for name in names_in_order(&state.available) {
+                        let group = &groups[name];
+                        let tlds: Vec<String> = group.iter().map(|d| format!(".{}", d.tld)).collect();
+                        let best = group.iter().map(|d| d.score).fold(0.0, f32::max);
+                        println!("  {:<12} {:<20} score {:.2}", name, tlds.join(" "), best);
+                    }
+                } else {
+                    for domain in &state.available {
+                        println!(
+                            "  {:<20} score {:.2} - {}",
+                            domain.full_domain,
+                            domain.score,
+                            domain.found_at.format("%Y-%m-%d %H:%M")
+                        );
+                    }
+                }
+            }
+
+            // Show domains about to drop
+            if !state.pending_delete.is_empty() {
+                println!();
+                println!("Pending Delete ({}):", state.pending_delete.len());
+                for domain in &state.pending_delete {
+                    let registrar = domain.registrar.as_deref().unwrap_or("unknown");
+                    println!("  {} - {} ({})", domain.full_domain, domain.rdap_status.join(", "), registrar);
                 }
             }
 
@@ -764,32 +1073,40 @@ async fn run_snipe_command(args: &[String]) -> Result<()> {
             if !state.expiring_soon.is_empty() {
                 println!();
                 println!("Expiring Soon ({}):", state.expiring_soon.len());
-                for domain in &state.expiring_soon {
-                    let days = domain.days_until_expiry.unwrap_or(0);
-                    let registrar = domain.registrar.as_deref().unwrap_or("unknown");
-                    println!("  {} - {} days left ({})", domain.full_domain, days, registrar);
+                if config.tlds.len() > 1 {
+                    let groups = state.expiring_grouped_by_name();
+                    for name in names_in_order(&state.expiring_soon) {
+                        let entries: Vec<String> = groups[name]
+                            .iter()
+                            .map(|d| format!(
+                                ".{} {} ({})",
+                                d.tld,
+                                expiry_label(d.expires_in_days()),
+                                d.registrar.as_deref().unwrap_or("unknown")
+                            ))
+                            .collect();
+                        println!("  {} - {}", name, entries.join(", "));
+                    }
+                } else {
+                    for domain in &state.expiring_soon {
+                        let registrar = domain.registrar.as_deref().unwrap_or("unknown");
+                        println!("  {} - {} ({})", domain.full_domain, expiry_label(domain.expires_in_days()), registrar);
+                    }
                 }
             }
 
             // Summary
             println!();
-            println!("Summary:");
-            println!("  Checked:     {}", state.checked_count);
-            println!("  Available:   {}", state.available.len());
-            println!("  Expiring:    {}", state.expiring_soon.len());
-            println!("  Expired:     {}", state.expired.len());
-            println!("  Errors:      {}", state.error_count);
-            println!("  Elapsed:     {:?}", state.elapsed());
+            println!("{}", state.summary());
+            println!("{}", sniper.metrics_snapshot());
 
             // Save results
-            std::fs::create_dir_all("output").ok();
-            let results_file = format!("output/snipe_results_{}.json",
-                chrono::Utc::now().format("%Y%m%d_%H%M%S"));
-            if let Err(e) = std::fs::write(&results_file, serde_json::to_string_pretty(&state).unwrap_or_default()) {
-                eprintln!("Failed to save results: {}", e);
-            } else {
-                println!();
-                println!("Results saved to: {}", results_file);
+            match save_scan_results(state, &args.output_dir, format) {
+                Ok(results_file) => {
+                    println!();
+                    println!("Results saved to: {}", results_file.display());
+                }
+                Err(e) => eprintln!("Failed to save results: {}", e),
             }
         }
         Err(e) => {
@@ -802,87 +1119,859 @@ async fn run_snipe_command(args: &[String]) -> Result<()> {
     Ok(())
 }
 
-async fn run_snipe_recheck_command(args: &[String]) -> Result<()> {
-    // Minimal UX: only takes result files and updates them in-place.
-    // Defaults match snipe defaults.
-    let concurrency: usize = 15;
-    let expiring_days: u32 = 7;
+/// Run a snipe scan silently and print the final state as JSON
+async fn run_snipe_json(
+    config: SnipeConfig,
+    output_dir: &Path,
+    format: OutputFormat,
+    filter: &ResultFilterArgs,
+    run_metrics: &mut Option<MetricsSnapshot>,
+) -> Result<()> {
+    let is_resume = config.state_file.as_ref().map(|p| p.exists()).unwrap_or(false);
+    let dry_run = config.dry_run;
+    let mut sniper = if is_resume {
+        DomainSniper::resume(config.clone()).or_else(|_| DomainSniper::new(config))?
+    } else {
+        DomainSniper::new(config)?
+    };
+    if dry_run {
+        println!("{}", serde_json::to_string_pretty(&sniper.dry_run_report())?);
+        return Ok(());
+    }
+    if let Err(e) = sniper.control().listen(&sniper.state_path()) {
+        eprintln!("{}", e);
+    }
 
-    let files: Vec<&str> = args
-        .iter()
-        .map(|s| s.trim())
-        .filter(|s| !s.is_empty())
+    sniper.run(|progress| {
+        if let Some(pause) = &progress.paused {
+            eprintln!("Paused: delete {} to resume", pause.display());
+        }
+    }).await?;
+    filter_scan_results(&mut sniper, filter);
+    *run_metrics = Some(sniper.metrics_snapshot());
+    let state = sniper.state();
+    if let Err(e) = save_scan_results(state, output_dir, format) {
+        eprintln!("Failed to save results: {}", e);
+    }
+    println!("{}", serde_json::to_string_pretty(&state)?);
+
+    Ok(())
+}
+
+/// Narrow the results of a finished scan, whose state is already saved,
+/// to `--filter-registrar` / `--filter-min-days`
+fn filter_scan_results(sniper: &mut DomainSniper, filter: &ResultFilterArgs) {
+    if let Some(registrar) = &filter.filter_registrar {
+        sniper.filter_by_registrar(registrar);
+    }
+    if let Some(days) = filter.filter_min_days {
+        sniper.filter_by_min_days_until_expiry(days);
+    }
+}
+
+/// Save scan results to a timestamped file in the requested format
+fn save_scan_results(state: &ScanState, output_dir: &Path, format: OutputFormat) -> Result<PathBuf> {
+    std::fs::create_dir_all(output_dir)
+        .map_err(|e| domain_forge::DomainForgeError::io(e.to_string(), Some(output_dir.display().to_string())))?;
+    let results_file = output_dir.join(format!("snipe_results_{}.{}",
+        chrono::Utc::now().format("%Y%m%d_%H%M%S"), format.extension()));
+    ExporterFactory::for_extension(format.extension())?.save_scan(state, &results_file)?;
+
+    Ok(results_file)
+}
+
+/// Create the pause file that holds scans writing to `dir`
+fn run_snipe_pause_command(dir: &Path) -> Result<()> {
+    let (pause, _) = ScanControl::scan_files(dir);
+    let io_error = |e: std::io::Error| domain_forge::DomainForgeError::io(e.to_string(), Some(pause.display().to_string()));
+    std::fs::create_dir_all(dir).map_err(io_error)?;
+    std::fs::write(&pause, "").map_err(io_error)?;
+    println!("Scans in {} will pause after their current batch; delete {} to resume", dir.display(), pause.display());
+    Ok(())
+}
+
+/// List saved scans in `dir`, optionally only those tagged `tag`
+fn run_snipe_list_command(tag: Option<&str>, dir: &Path) -> Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| domain_forge::DomainForgeError::io(e.to_string(), Some(dir.display().to_string())))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+            name.starts_with("snipe_") && name.ends_with(".json")
+        })
         .collect();
+    paths.sort();
+
+    let mut scans = Vec::new();
+    for path in paths {
+        match ScanState::load(&path) {
+            Ok(state) if tag.is_none_or(|tag| state.has_tag(tag)) => scans.push((path, state)),
+            Ok(_) => {}
+            Err(e) => tracing::debug!(path = %path.display(), error = %e, "Skipping unreadable scan file"),
+        }
+    }
 
-    if files.is_empty() {
-        return Err(domain_forge::DomainForgeError::cli(
-            "No result files provided. Usage: domain-forge snipe recheck <RESULT_JSON...>".to_string(),
-        ));
+    if scans.is_empty() {
+        match tag {
+            Some(tag) => println!("No scans tagged '{}' in {}", tag, dir.display()),
+            None => println!("No scans in {}", dir.display()),
+        }
+        return Ok(());
     }
 
-    println!("Snipe Recheck - update saved results");
-    println!("====================================");
-    println!("  Files:       {}", files.len());
-    println!("  Concurrency: {}", concurrency);
-    println!("  Expiring:    {} days", expiring_days);
-    println!("  Write:       in-place");
-    println!();
+    println!(
+        "{:<36} {:<14} {:<20} {:<9} {:>7} {:>6} {:>6}",
+        "FILE", "MODE", "TAGS", "STATUS", "DONE", "AVAIL", "EXPIR"
+    );
+    for (path, state) in scans {
+        let file = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        let mode = state.mode.as_ref().map_or_else(|| "-".to_string(), |m| m.to_string());
+        let tags = if state.tags.is_empty() { "-".to_string() } else { state.tags.join(",") };
+        println!(
+            "{:<36} {:<14} {:<20} {:<9} {:>6.1}% {:>6} {:>6}",
+            file,
+            mode,
+            tags,
+            if state.completed { "complete" } else { "partial" },
+            state.progress_percent(),
+            state.available.len(),
+            state.expiring_soon.len()
+        );
+    }
 
-    for path in files {
-        println!("Rechecking: {}", path);
+    Ok(())
+}
+
+async fn run_snipe_recheck_command(args: &RecheckArgs, snipe: &SnipeArgs, json: bool) -> Result<()> {
+    let config = args.to_config(snipe, config::current().snipe_config());
+    let format = args.format(json);
+    let txt = format == RecheckFormat::Txt;
+    let mut csv = (format == RecheckFormat::Csv).then(|| csv::Writer::from_writer(io::stdout()));
+    let csv_error = |e: csv::Error| domain_forge::DomainForgeError::io(format!("Failed to write CSV: {}", e), None);
+
+    if txt {
+        println!("Snipe Recheck - update saved results");
+        println!("====================================");
+        println!("  Files:       {}", args.files.len());
+        println!("  Concurrency: {}", config.concurrency);
+        println!("  Expiring:    {} days", config.expiring_days);
+        println!("  Write:       in-place");
+        println!();
+    } else if let Some(csv) = &mut csv {
+        let columns = RecheckReport::default().counts().map(|(name, _)| name);
+        csv.write_field("file").map_err(csv_error)?;
+        csv.write_record(columns).map_err(csv_error)?;
+        csv.flush()?;
+    }
 
-        let mut state = ScanState::load(std::path::Path::new(path))?;
+    for path in &args.files {
+        if txt {
+            println!("Rechecking: {}", path.display());
+        }
+
+        let mut state = ScanState::load(path)?;
         let before_expired = state.expired.len();
         let before_expiring = state.expiring_soon.len();
         let before_available = state.available.len();
 
-        let report = domain_forge::snipe::recheck_expiring_soon(
-            &mut state,
-            expiring_days,
-            concurrency,
-        )
-        .await?;
+        let report = domain_forge::snipe::recheck_expiring_soon(&mut state, &config).await?;
 
-        // Pretty summary panel
-        println!("╭─ Recheck Summary ─────────────────────────────────────╮");
-        println!(
-            "│  expiring_soon: {:>5} → {:<5}  (→available {:<4}  →expired {:<4}  kept {:<4}) │",
-            before_expiring,
-            state.expiring_soon.len(),
-            report.expiring_now_available,
-            report.already_expired,
-            report.expiring_errors_kept
-        );
-        println!(
-            "│  expired:       {:>5} → {:<5}  (→available {:<4}  →expiring {:<4} kept {:<4}) │",
-            before_expired,
-            state.expired.len(),
-            report.expired_now_available,
-            report.expired_now_expiring,
-            report.expired_errors_kept
-        );
+        // Always overwrite the input file, with every recheck result kept
+        state.save(path)?;
+
+        // The filters only narrow what is shown, never what is saved
+        let before_filter = state.expiring_soon.len();
+        let mut shown = state.clone();
+        if let Some(registrar) = &args.filter.filter_registrar {
+            shown.filter_by_registrar(registrar);
+        }
+        if let Some(days) = args.filter.filter_min_days {
+            shown.filter_by_min_days_until_expiry(days);
+        }
+
+        match format {
+            RecheckFormat::Json => {
+                let mut value = serde_json::to_value(&report)?;
+                value["file"] = serde_json::Value::String(path.display().to_string());
+                println!("{}", serde_json::to_string(&value)?);
+            }
+            RecheckFormat::Csv => {
+                if let Some(csv) = &mut csv {
+                    csv.write_field(path.display().to_string()).map_err(csv_error)?;
+                    csv.write_record(report.counts().map(|(_, count)| count.to_string())).map_err(csv_error)?;
+                    csv.flush()?;
+                }
+            }
+            RecheckFormat::Txt => {
+                // Pretty summary panel
+                println!("╭─ Recheck Summary ─────────────────────────────────────╮");
+                println!(
+                    "│  expiring_soon: {:>5} → {:<5}  (→available {:<4}  →expired {:<4}  kept {:<4}) │",
+                    before_expiring,
+                    before_filter,
+                    report.expiring_now_available,
+                    report.already_expired,
+                    report.expiring_errors_kept
+                );
+                println!(
+                    "│  expired:       {:>5} → {:<5}  (→available {:<4}  →expiring {:<4} kept {:<4}) │",
+                    before_expired,
+                    state.expired.len(),
+                    report.expired_now_available,
+                    report.expired_now_expiring,
+                    report.expired_errors_kept
+                );
+                println!(
+                    "│  available:     {:>5} → {:<5}  (→expiring {:<4}  removed {:<4} kept {:<4}) │",
+                    before_available,
+                    state.available.len(),
+                    report.available_now_expiring,
+                    report.no_longer_available,
+                    report.available_errors_kept
+                );
+                println!(
+                    "│  updated_at: {}  (history: {}) │",
+                    state.updated_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                    state.update_times.len()
+                );
+                println!("╰───────────────────────────────────────────────────────╯");
+
+                if args.filter.filter_registrar.is_some() || args.filter.filter_min_days.is_some() {
+                    println!("  Matching filters: {} expiring domains", shown.expiring_soon.len());
+                    for domain in &shown.expiring_soon {
+                        let registrar = domain.registrar.as_deref().unwrap_or("unknown");
+                        println!("    {} - {} ({})", domain.full_domain, expiry_label(domain.expires_in_days()), registrar);
+                    }
+                }
+                println!("  Saved: {}", path.display());
+                println!();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run `snipe merge`
+fn run_snipe_merge_command(first_path: &Path, second_path: &Path, output: &Path) -> Result<()> {
+    let first = ScanState::load(first_path)?;
+    let second = ScanState::load(second_path)?;
+    let merged = first.merge(&second)?;
+    merged.save(output)?;
+
+    println!("Merged {} and {} into {}", first_path.display(), second_path.display(), output.display());
+    println!("  TLDs:          {}", merged.tlds.join(", "));
+    println!("  Checked:       {}", merged.checked_count);
+    if merged.skipped_count > 0 {
+        println!("  Skipped:       {}", merged.skipped_count);
+    }
+    println!("  Available:     {}", merged.available.len());
+    println!("  Pending del.:  {}", merged.pending_delete.len());
+    println!("  Expiring soon: {}", merged.expiring_soon.len());
+    println!("  Expired:       {}", merged.expired.len());
+    Ok(())
+}
+
+/// Compare two saved snipe result files
+fn run_snipe_diff_command(old_path: &Path, new_path: &Path) -> Result<()> {
+    let old = ScanState::load(old_path)?;
+    let new = ScanState::load(new_path)?;
+    let diff = domain_forge::snipe::diff::diff(&old, &new);
+
+    println!("Scan Diff");
+    println!("=========");
+    println!("  Old: {} ({})", old_path.display(), old.updated_at.format("%Y-%m-%d %H:%M UTC"));
+    println!("  New: {} ({})", new_path.display(), new.updated_at.format("%Y-%m-%d %H:%M UTC"));
+    println!();
+
+    println!("{:<22} {:>6}", "CHANGE", "COUNT");
+    println!("{:<22} {:>6}", "Newly available", diff.newly_available.len());
+    println!("{:<22} {:>6}", "Newly registered", diff.newly_registered.len());
+    println!("{:<22} {:>6}", "Newly expiring", diff.newly_expiring.len());
+    println!("{:<22} {:>6}", "No longer expiring", diff.no_longer_expiring.len());
+    println!("{:<22} {:>+6}", "Checked", diff.checked_delta);
+
+    if diff.is_empty() {
+        println!();
+        println!("No changes.");
+        return Ok(());
+    }
+
+    if !diff.newly_available.is_empty() {
+        println!();
+        println!("Newly Available:");
+        for domain in &diff.newly_available {
+            println!("  + {}", domain.full_domain);
+        }
+    }
+    if !diff.newly_registered.is_empty() {
+        println!();
+        println!("Newly Registered:");
+        for domain in &diff.newly_registered {
+            println!("  - {}", domain);
+        }
+    }
+    if !diff.newly_expiring.is_empty() {
+        println!();
+        println!("Newly Expiring:");
+        for domain in &diff.newly_expiring {
+            println!("  + {} - {}", domain.full_domain, expiry_label(domain.expires_in_days()));
+        }
+    }
+    if !diff.no_longer_expiring.is_empty() {
+        println!();
+        println!("No Longer Expiring:");
+        for domain in &diff.no_longer_expiring {
+            println!("  - {}", domain);
+        }
+    }
+
+    Ok(())
+}
+
+// ===== Check / Validate / Config / Providers Commands =====
+
+/// Domains to check: arguments, then `--file`, else stdin when piped
+fn check_domain_list(args: &CheckArgs) -> Result<Vec<String>> {
+    let mut domains = args.domains.clone();
+    if let Some(path) = &args.file {
+        let text = std::fs::read_to_string(path)?;
+        domains.extend(cli::domain_lines(&text));
+    }
+    if domains.is_empty() && !io::stdin().is_terminal() {
+        let text = io::read_to_string(io::stdin())?;
+        domains.extend(cli::domain_lines(&text));
+    }
+    if domains.is_empty() {
+        return Err(domain_forge::DomainForgeError::cli(
+            "No domains to check; pass them as arguments, with --file, or on stdin",
+        ));
+    }
+    Ok(domains)
+}
+
+/// "Expires in 12 days", "Expired 3 days ago" or "-" without a date
+fn expiry_label(days: Option<i64>) -> String {
+    match days {
+        None => "-".to_string(),
+        Some(0) => "Expires today".to_string(),
+        Some(1) => "Expires in 1 day".to_string(),
+        Some(days) if days > 0 => format!("Expires in {} days", days),
+        Some(-1) => "Expired 1 day ago".to_string(),
+        Some(days) => format!("Expired {} days ago", -days),
+    }
+}
+
+/// Check specific domains; the exit code is [`CHECK_EXIT_AVAILABLE`] if any
+/// is available, [`CHECK_EXIT_ERROR`] if none is but some could not be
+/// checked, and [`CHECK_EXIT_TAKEN`] otherwise
+async fn run_check_command(
+    args: &CheckArgs,
+    quiet: bool,
+    json: bool,
+    run_metrics: &mut Option<MetricsSnapshot>,
+) -> Result<i32> {
+    let domains = check_domain_list(args)?;
+    let checker = DomainChecker::with_config(config::current().check_config());
+
+    if args.rdap_raw {
+        return print_rdap_records(&checker, &domains).await;
+    }
+
+    let pb = if quiet || json { ProgressBar::hidden() } else { ProgressBar::new_spinner() };
+    pb.set_message(format!("Checking {} domains...", domains.len()));
+    pb.enable_steady_tick(Duration::from_millis(100));
+    let results: Vec<DomainResult> = checker
+        .check_domains_ordered(&domains)
+        .await?
+        .into_iter()
+        .map(|(_, result)| result)
+        .collect();
+    pb.finish_and_clear();
+    *run_metrics = Some(checker.get_metrics_snapshot());
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    } else if args.detailed {
         println!(
-            "│  available:     {:>5} → {:<5}  (→expiring {:<4}  removed {:<4} kept {:<4}) │",
-            before_available,
-            state.available.len(),
-            report.available_now_expiring,
-            report.no_longer_available,
-            report.available_errors_kept
+            "{:<32} {:<10} {:<8} {:<24} {:<18} AGE",
+            "DOMAIN", "STATUS", "METHOD", "REGISTRAR", "EXPIRES"
         );
+        for result in &results {
+            let age = result.registration_age_days().map(|days| format!("{} days", days));
+            println!(
+                "{:<32} {:<10} {:<8} {:<24} {:<18} {}",
+                result.domain,
+                result.status.to_string(),
+                result.method.to_string(),
+                result.registrar.as_deref().unwrap_or("-"),
+                expiry_label(result.days_until_expiry()),
+                age.as_deref().unwrap_or("-")
+            );
+        }
+    } else {
+        println!("{:<32} {:<10} {:<8}", "DOMAIN", "STATUS", "METHOD");
+        for result in &results {
+            println!("{:<32} {:<10} {:<8}", result.domain, result.status.to_string(), result.method.to_string());
+        }
+    }
+
+    let statuses: Vec<AvailabilityStatus> = results.iter().map(|result| result.status).collect();
+    Ok(check_exit_code(&statuses))
+}
+
+/// Print a domain's RDAP record from its registry, with the status it
+/// implies on stderr
+async fn run_rdap_command(args: &RdapArgs) -> Result<()> {
+    let checker = DomainChecker::with_config(config::current().check_config());
+
+    if args.raw {
+        match checker.rdap_body(&args.domain).await? {
+            Some(body) => println!("{}", body),
+            None => println!("{}", RDAP_NOT_FOUND),
+        }
+        return Ok(());
+    }
+
+    let (result, record) = checker.check_domain_raw(&args.domain).await?;
+    eprintln!("{}: {}", result.domain, result.status);
+    let contacts = [
+        ("Registrant", &result.registrant_name),
+        ("Admin", &result.admin_contact),
+        ("Tech", &result.tech_contact),
+        ("Abuse", &result.abuse_email),
+    ];
+    for (label, value) in contacts {
+        if let Some(value) = value {
+            eprintln!("  {:<11} {}", format!("{}:", label), value);
+        }
+    }
+    if record.is_null() {
+        println!("{}", RDAP_NOT_FOUND);
+    } else {
+        println!("{}", serde_json::to_string_pretty(&record)?);
+    }
+    Ok(())
+}
+
+/// Printed by `rdap` when the registry has no record (HTTP 404)
+const RDAP_NOT_FOUND: &str = r#"{"status": "not_found"}"#;
+
+/// Print raw RDAP records as one JSON object keyed by domain (`null` for
+/// unregistered domains, an `error` object when the lookup failed)
+async fn print_rdap_records(checker: &DomainChecker, domains: &[String]) -> Result<i32> {
+    let mut records = serde_json::Map::new();
+    let mut statuses = Vec::new();
+    for domain in domains {
+        let (record, status) = match checker.check_domain_raw(domain).await {
+            Ok((result, record)) => (record, result.status),
+            Err(e) => (serde_json::json!({ "error": e.to_string() }), AvailabilityStatus::Error),
+        };
+        records.insert(domain.trim().to_lowercase(), record);
+        statuses.push(status);
+    }
+    println!("{}", serde_json::to_string_pretty(&records)?);
+    Ok(check_exit_code(&statuses))
+}
+
+const CHECK_EXIT_AVAILABLE: i32 = 0;
+const CHECK_EXIT_TAKEN: i32 = 1;
+const CHECK_EXIT_ERROR: i32 = 2;
+
+fn check_exit_code(statuses: &[AvailabilityStatus]) -> i32 {
+    if statuses.contains(&AvailabilityStatus::Available) {
+        CHECK_EXIT_AVAILABLE
+    } else if statuses
+        .iter()
+        .any(|status| matches!(status, AvailabilityStatus::Unknown | AvailabilityStatus::Error))
+    {
+        CHECK_EXIT_ERROR
+    } else {
+        CHECK_EXIT_TAKEN
+    }
+}
+
+/// Generate variations of a domain and check which are available
+async fn run_variations_command(args: &VariationsArgs, quiet: bool) -> Result<()> {
+    let validated = DomainValidator::new().validate(&args.domain)?;
+    let base = DomainSuggestion::new(validated.name, validated.tld, 1.0, None::<String>);
+
+    let mut generator = DomainGenerator::new();
+    setup_llm_providers(&mut generator, None, quiet)?;
+
+    let config = GenerationConfig {
+        description: format!("variations of {}", base.get_full_domain()),
+        count: args.count,
+        tlds: domain_forge::domain::tld_presets::expand_presets(&args.tlds),
+        ..Default::default()
+    };
+
+    let pb = create_ai_progress_bar(quiet);
+    pb.set_message(format!("🎨 Generating variations of {}...", base.get_full_domain()));
+    let variations = generator.generate_variations(&base, &config).await;
+    pb.finish_and_clear();
+    let variations = variations?;
+    if variations.is_empty() {
+        println!("No variations generated.");
+        return Ok(());
+    }
+
+    let names: Vec<String> = variations.iter().map(|d| d.get_full_domain()).collect();
+    warn_confusables(names.iter().cloned());
+    let checker = DomainChecker::with_config(config::current().check_config());
+    let pb = if quiet { ProgressBar::hidden() } else { ProgressBar::new_spinner() };
+    pb.set_message(format!("Checking {} variations...", names.len()));
+    pb.enable_steady_tick(Duration::from_millis(100));
+    let checked = checker.check_domains_ordered(&names).await?;
+    pb.finish_and_clear();
+
+    println!("{:<32} {:<10} TRANSFORMATION", "DOMAIN", "STATUS");
+    for (variation, (domain, result)) in variations.iter().zip(&checked) {
         println!(
-            "│  updated_at: {}  (history: {}) │",
-            state.updated_at.format("%Y-%m-%d %H:%M:%S UTC"),
-            state.update_times.len()
+            "{:<32} {:<10} {}",
+            domain,
+            result.status.to_string(),
+            variation.reasoning.as_deref().unwrap_or_default()
         );
-        println!("╰───────────────────────────────────────────────────────╯");
+    }
+
+    Ok(())
+}
 
-        // Always overwrite the input file.
-        state.save(std::path::Path::new(path))?;
-        println!("  Saved: {}", path);
+/// Manage and recheck the domain watchlist
+async fn run_watchlist_command(args: &WatchlistArgs, quiet: bool) -> Result<()> {
+    let mut watchlist = Watchlist::load(&args.file)?;
+
+    match &args.command {
+        WatchlistCommand::Add { domains } => {
+            let validator = DomainValidator::new();
+            for domain in domains {
+                match validator.validate(domain) {
+                    Ok(validated) if watchlist.add(&validated.full_domain) => println!("➕ {}", validated.full_domain),
+                    Ok(validated) => println!("   {} is already watched", validated.full_domain),
+                    Err(e) => println!("❌ {}: {}", domain, e),
+                }
+            }
+            watchlist.save(&args.file)?;
+        }
+        WatchlistCommand::Remove { domains } => {
+            for domain in domains {
+                if watchlist.remove(domain) {
+                    println!("➖ {}", domain);
+                } else {
+                    println!("   {} is not watched", domain);
+                }
+            }
+            watchlist.save(&args.file)?;
+        }
+        WatchlistCommand::Check => {
+            if watchlist.is_empty() {
+                println!("Watchlist is empty. Add domains with `domain-forge watchlist add <DOMAIN>`.");
+                return Ok(());
+            }
+
+            let checker = DomainChecker::with_config(config::current().check_config());
+            let pb = if quiet { ProgressBar::hidden() } else { ProgressBar::new_spinner() };
+            pb.set_message(format!("Checking {} watched domains...", watchlist.len()));
+            pb.enable_steady_tick(Duration::from_millis(100));
+            let results = checker.check_domains(&watchlist.domain_names(), None).await?;
+            pb.finish_and_clear();
+
+            let changes: Vec<_> = results.iter().filter_map(|result| watchlist.record(result)).collect();
+            watchlist.save(&args.file)?;
+
+            println!("{:<32} {:<16} {:<16}", "DOMAIN", "PREVIOUS", "NOW");
+            for change in &changes {
+                let previous = change.previous.map_or("-".to_string(), |s| s.to_string());
+                let marker = if change.is_changed() { "*" } else { "" };
+                println!("{:<32} {:<16} {}{}", change.full_domain, previous, change.current, marker);
+            }
 
+            let unchecked = watchlist.len() - changes.len();
+            if unchecked > 0 {
+                println!();
+                println!("⚠️  {} domains could not be checked", unchecked);
+            }
+
+            for change in changes.iter().filter(|c| c.became_available()) {
+                println!();
+                println!("🚨🚨🚨 {} IS NOW AVAILABLE! 🚨🚨🚨", change.full_domain.to_uppercase());
+            }
+        }
+        WatchlistCommand::Show => {
+            if watchlist.is_empty() {
+                println!("Watchlist is empty.");
+                return Ok(());
+            }
+            println!("{:<32} {:<16} {:<18} {:>6}", "DOMAIN", "STATUS", "LAST CHECKED", "CHECKS");
+            for entry in watchlist.entries() {
+                let status = entry.last_status.map_or("-".to_string(), |s| s.to_string());
+                let checked = entry
+                    .last_checked_at
+                    .map_or("never".to_string(), |t| t.format("%Y-%m-%d %H:%M").to_string());
+                println!("{:<32} {:<16} {:<18} {:>6}", entry.full_domain, status, checked, entry.check_count);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate domain name format
+fn run_validate_command(args: &ValidateArgs) -> Result<()> {
+    let mut domains = args.domains.clone();
+    if let Some(path) = &args.batch_file {
+        let text = std::fs::read_to_string(path)?;
+        domains.extend(cli::domain_lines(&text));
+    }
+
+    let mut validator = DomainValidator::new();
+    if !args.allowed_tlds.is_empty() {
+        let allowed = args.allowed_tlds.iter().map(|tld| tld.trim().trim_start_matches('.').to_string());
+        validator = validator.with_tld_whitelist(allowed.collect());
+    }
+
+    // Batch files can be long, so only their failures are listed
+    let batch = args.batch_file.is_some();
+    let mut invalid = 0;
+    for result in validator.validate_batch(&domains) {
+        match result.result {
+            Ok(validated) if !batch => {
+                println!("✅ {}", validated.full_domain);
+                for warning in &validated.warnings {
+                    println!("   ⚠️  {}", warning);
+                }
+                warn_confusables([validated.full_domain]);
+            }
+            Ok(_) => {}
+            Err(e) => {
+                invalid += 1;
+                println!("❌ {}: {}", result.domain, e);
+                if args.fix {
+                    let fixes: Vec<String> = domain_forge::domain::validator::utils::suggest_corrections(&result.domain)
+                        .into_iter()
+                        .filter(|fix| validator.validate(fix).is_ok())
+                        .collect();
+                    if !fixes.is_empty() {
+                        println!("   💡 Did you mean: {}", fixes.join(", "));
+                    }
+                }
+            }
+        }
+    }
+
+    if batch {
         println!();
+        println!("{} valid, {} invalid", domains.len() - invalid, invalid);
+    }
+
+    if invalid > 0 {
+        return Err(domain_forge::DomainForgeError::validation(format!(
+            "{} of {} domains are invalid",
+            invalid,
+            domains.len()
+        )));
     }
 
     Ok(())
 }
 
+/// Show or initialize configuration
+fn run_config_command(args: &ConfigArgs) -> Result<()> {
+    match &args.command {
+        ConfigCommand::Show => {
+            let current = config::current();
+            let check = current.check_config();
+            let snipe = current.snipe_config();
+            let key_status = |var: &str| if env::var(var).is_ok() { "set" } else { "not set" };
+
+            match Config::discover() {
+                Some(path) => println!("Config file: {}", path.display()),
+                None => println!("Config file: (none)"),
+            }
+            println!();
+            println!("LLM:");
+            if let Some(llm) = current.llm_config() {
+                println!("  Provider:          {}", llm.provider);
+                println!("  Model:             {}", llm.model);
+                println!("  API key:           {}", if llm.api_key.is_empty() { "not set" } else { "set" });
+            }
+            println!("  OPENAI_API_KEY:    {}", key_status("OPENAI_API_KEY"));
+            println!("  ANTHROPIC_API_KEY: {}", key_status("ANTHROPIC_API_KEY"));
+            println!("  GEMINI_API_KEY:    {}", key_status("GEMINI_API_KEY"));
+            println!();
+            println!("Domain check:");
+            println!("  Concurrency: {}", check.concurrent_checks);
+            println!("  Timeout:     {}s", check.timeout.as_secs());
+            println!("  RDAP:        {}", check.enable_rdap);
+            println!("  WHOIS:       {}", check.enable_whois);
+            println!();
+            println!("Snipe:");
+            println!("  TLDs:        {}", snipe.tlds.join(", "));
+            println!("  Concurrency: {}", snipe.concurrency);
+            println!("  Expiring:    {} days", snipe.expiring_days);
+        }
+        ConfigCommand::Init { local, force } => {
+            let path = if *local {
+                PathBuf::from(config::LOCAL_CONFIG_FILE)
+            } else {
+                Config::user_path().ok_or_else(|| {
+                    domain_forge::DomainForgeError::config("Could not determine home directory; use --local")
+                })?
+            };
+            if path.exists() && !force {
+                return Err(domain_forge::DomainForgeError::cli(format!(
+                    "{} already exists (use --force to overwrite)",
+                    path.display()
+                )));
+            }
+
+            let new_config = prompt_config()?;
+            new_config.save(&path)?;
+            println!("Config written to: {}", path.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// Interactively build an initial config file
+fn prompt_config() -> Result<Config> {
+    use inquire::{Password, Text};
+
+    let prompt_error = |e: inquire::InquireError| domain_forge::DomainForgeError::cli(format!("Prompt cancelled: {}", e));
+
+    let provider = Select::new("LLM provider:", domain_forge::llm::available_providers())
+        .prompt()
+        .map_err(prompt_error)?;
+    let default_model = match provider {
+        "anthropic" => "claude-4-sonnet",
+        "gemini" => "gemini-2.5-flash",
+        "ollama" => "llama3",
+        _ => "gpt-4.1-mini",
+    };
+    let model = Text::new("Model:")
+        .with_default(default_model)
+        .prompt()
+        .map_err(prompt_error)?;
+    let api_key = if provider == "ollama" {
+        String::new()
+    } else {
+        Password::new("API key (leave empty to use environment variables):")
+            .without_confirmation()
+            .prompt()
+            .map_err(prompt_error)?
+    };
+    let tlds = Text::new("Default snipe TLDs (comma-separated):")
+        .with_default("com")
+        .prompt()
+        .map_err(prompt_error)?;
+
+    Ok(Config {
+        llm: LlmSettings {
+            provider: Some(provider.to_string()),
+            model: Some(model),
+            api_key: (!api_key.is_empty()).then_some(api_key),
+            base_url: None,
+            structured_output: None,
+            max_tokens: None,
+        },
+        snipe: SnipeSettings {
+            tlds: Some(tlds.split(',').map(|s| s.trim().to_lowercase()).filter(|s| !s.is_empty()).collect()),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+/// List configured LLM providers, or health-check them with `providers test`
+async fn run_providers_command(args: &ProvidersArgs, json: bool) -> Result<()> {
+    let mut generator = DomainGenerator::new();
+    setup_llm_providers(&mut generator, None, true)?;
+
+    if let Some(ProvidersCommand::Test) = args.command {
+        let mut report: Vec<_> = generator.check_all_providers().await.into_iter().collect();
+        report.sort_by(|a, b| a.0.cmp(&b.0));
+        let healthy = report.iter().all(|(_, result)| result.is_ok());
+
+        if json {
+            let report: Vec<serde_json::Value> = report
+                .iter()
+                .map(|(provider, result)| {
+                    serde_json::json!({
+                        "provider": provider,
+                        "ok": result.is_ok(),
+                        "error": result.as_ref().err().map(|e| e.to_string()),
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            for (provider, result) in report {
+                match result {
+                    Ok(()) => println!("✅ {}", provider),
+                    Err(e) => println!("❌ {}: {}", provider, e),
+                }
+            }
+        }
+        if !healthy {
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let default = generator.default_provider();
+    let configs = generator.provider_configs();
+
+    if json {
+        let providers: Vec<serde_json::Value> = configs
+            .iter()
+            .map(|config| {
+                serde_json::json!({
+                    "provider": config.provider,
+                    "model": config.model,
+                    "api_key": config.redacted_api_key(),
+                    "base_url": config.base_url,
+                    "default": config.provider == default,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&providers)?);
+        return Ok(());
+    }
+
+    println!("{:<12} {:<32} {:<14} DEFAULT", "PROVIDER", "MODEL", "API KEY");
+    for config in configs {
+        let marker = if config.provider == default { "*" } else { "" };
+        println!("{:<12} {:<32} {:<14} {}", config.provider, config.model, config.redacted_api_key(), marker);
+    }
+
+    let tlds = DomainChecker::with_config(config::current().check_config()).available_tlds();
+    println!();
+    println!("{} TLDs supported for availability checks (see `domain-forge tlds list`)", tlds.len());
+
+    Ok(())
+}
+
+/// List the TLDs the checker supports
+fn run_tlds_command(args: &TldsArgs, quiet: bool, json: bool) -> Result<()> {
+    let tlds = match args.command {
+        Some(TldsCommand::List) | None => DomainChecker::with_config(config::current().check_config()).available_tlds(),
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&tlds)?);
+        return Ok(());
+    }
+
+    for tld in &tlds {
+        println!(".{}", tld);
+    }
+    if !quiet {
+        println!();
+        println!("{} TLDs supported", tlds.len());
+    }
+
+    Ok(())
+}
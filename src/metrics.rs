@@ -0,0 +1,146 @@
+//! Prometheus export of [`PerformanceMetrics`] (requires the `metrics` feature)
+//!
+//! The exporter reads the shared atomics at scrape time, so it never falls
+//! behind the checker or generator it is attached to.
+
+use prometheus::core::{Collector, Desc};
+use prometheus::proto::{Counter, Metric, MetricFamily, MetricType, Summary};
+use prometheus::{Encoder, TextEncoder};
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use crate::types::PerformanceMetrics;
+
+const DOMAINS_CHECKED: &str = "domain_forge_domains_checked_total";
+const API_CALLS: &str = "domain_forge_api_calls_total";
+const ERRORS: &str = "domain_forge_errors_total";
+const CHECK_DURATION: &str = "domain_forge_check_duration_ms";
+const TOKENS_USED: &str = "domain_forge_tokens_used_total";
+
+/// Name and help text of every exported metric
+const METRICS: &[(&str, &str)] = &[
+    (DOMAINS_CHECKED, "Domains checked for availability"),
+    (API_CALLS, "LLM API calls made"),
+    (ERRORS, "Errors encountered while checking or generating"),
+    (CHECK_DURATION, "Time spent checking domains in milliseconds"),
+    (TOKENS_USED, "LLM prompt and completion tokens used"),
+];
+
+/// Renders [`PerformanceMetrics`] in the Prometheus text format
+///
+/// Also implements [`Collector`], so it can be registered with an existing
+/// `prometheus::Registry` instead of serving [`render`](Self::render) directly.
+#[derive(Debug, Clone)]
+pub struct PrometheusExporter {
+    metrics: Arc<PerformanceMetrics>,
+    descs: Vec<Desc>,
+}
+
+impl PrometheusExporter {
+    pub fn new(metrics: Arc<PerformanceMetrics>) -> Self {
+        let descs = METRICS
+            .iter()
+            .map(|(name, help)| {
+                Desc::new(name.to_string(), help.to_string(), Vec::new(), HashMap::new())
+                    .expect("static metric names are valid")
+            })
+            .collect();
+
+        Self { metrics, descs }
+    }
+
+    /// Current metrics in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        if let Err(e) = TextEncoder::new().encode(&self.collect(), &mut buffer) {
+            tracing::warn!("Failed to encode Prometheus metrics: {}", e);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+fn family(name: &str, help: &str, kind: MetricType, metric: Metric) -> MetricFamily {
+    let mut family = MetricFamily::default();
+    family.set_name(name.to_string());
+    family.set_help(help.to_string());
+    family.set_field_type(kind);
+    family.set_metric(vec![metric]);
+    family
+}
+
+fn counter(value: u64) -> Metric {
+    let mut counter = Counter::default();
+    counter.set_value(value as f64);
+    let mut metric = Metric::default();
+    metric.set_counter(counter);
+    metric
+}
+
+impl Collector for PrometheusExporter {
+    fn desc(&self) -> Vec<&Desc> {
+        self.descs.iter().collect()
+    }
+
+    fn collect(&self) -> Vec<MetricFamily> {
+        let m = &self.metrics;
+        let checked = m.domains_checked.load(Ordering::Relaxed);
+        let tokens = m.total_prompt_tokens.load(Ordering::Relaxed) + m.total_completion_tokens.load(Ordering::Relaxed);
+
+        // Only the running total is tracked, so the summary carries count and sum without quantiles
+        let mut summary = Summary::default();
+        summary.set_sample_count(checked);
+        summary.set_sample_sum(m.total_check_time_ms.load(Ordering::Relaxed) as f64);
+        let mut duration = Metric::default();
+        duration.set_summary(summary);
+
+        // Same order as METRICS
+        let values = [
+            (MetricType::COUNTER, counter(checked)),
+            (MetricType::COUNTER, counter(m.api_calls_made.load(Ordering::Relaxed))),
+            (MetricType::COUNTER, counter(m.errors_encountered.load(Ordering::Relaxed))),
+            (MetricType::SUMMARY, duration),
+            (MetricType::COUNTER, counter(tokens)),
+        ];
+
+        METRICS
+            .iter()
+            .zip(values)
+            .map(|(&(name, help), (kind, metric))| family(name, help, kind, metric))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_text_format() {
+        let metrics = Arc::new(PerformanceMetrics::new());
+        metrics.increment_domains_checked();
+        metrics.increment_domains_checked();
+        metrics.add_check_time(250);
+        metrics.increment_errors();
+        metrics.add_prompt_tokens(100);
+        metrics.add_completion_tokens(20);
+
+        let output = PrometheusExporter::new(Arc::clone(&metrics)).render();
+        assert!(output.contains("# TYPE domain_forge_domains_checked_total counter"));
+        assert!(output.contains("domain_forge_domains_checked_total 2\n"));
+        assert!(output.contains("domain_forge_api_calls_total 0\n"));
+        assert!(output.contains("domain_forge_errors_total 1\n"));
+        assert!(output.contains("# TYPE domain_forge_check_duration_ms summary"));
+        assert!(output.contains("domain_forge_check_duration_ms_sum 250\n"));
+        assert!(output.contains("domain_forge_check_duration_ms_count 2\n"));
+        assert!(output.contains("domain_forge_tokens_used_total 120\n"));
+    }
+
+    #[test]
+    fn test_register_with_registry() {
+        let exporter = PrometheusExporter::new(Arc::new(PerformanceMetrics::new()));
+        let registry = prometheus::Registry::new();
+        registry.register(Box::new(exporter.clone())).unwrap();
+        assert_eq!(registry.gather().len(), METRICS.len());
+    }
+}
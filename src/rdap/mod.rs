@@ -4,5 +4,94 @@
 //! behave consistently (convention over configuration).
 
 pub mod registry;
+pub mod status;
+
+use std::collections::HashMap;
+
+use crate::error::{DomainForgeError, Result};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{ClientBuilder, Proxy};
+
+/// User agent sent with RDAP queries unless overridden: `domain-forge/<version>`
+pub fn default_user_agent() -> String {
+    format!("domain-forge/{}", crate::VERSION)
+}
+
+/// Set the user agent and add `extra` headers to every request.
+///
+/// Fails on header names or values that are not valid HTTP.
+pub(crate) fn with_headers(
+    builder: ClientBuilder,
+    user_agent: &str,
+    extra: &HashMap<String, String>,
+) -> Result<ClientBuilder> {
+    let user_agent = HeaderValue::from_str(user_agent)
+        .map_err(|_| DomainForgeError::config(format!("Invalid user agent '{}'", user_agent)))?;
+    let mut headers = HeaderMap::new();
+    for (name, value) in extra {
+        let invalid = || DomainForgeError::config(format!("Invalid header '{}'", name));
+        let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| invalid())?;
+        let header_value = HeaderValue::from_str(value).map_err(|_| invalid())?;
+        headers.insert(header_name, header_value);
+    }
+    Ok(builder.user_agent(user_agent).default_headers(headers))
+}
+
+/// Check a user agent and extra headers without building a client
+pub(crate) fn validate_headers(user_agent: &str, extra: &HashMap<String, String>) -> Result<()> {
+    with_headers(ClientBuilder::new(), user_agent, extra).map(|_| ())
+}
+
+/// Route HTTP and HTTPS traffic through an explicit proxy.
+///
+/// Without one, reqwest still honors the `HTTP_PROXY` / `HTTPS_PROXY`
+/// environment variables.
+pub(crate) fn with_proxy(
+    builder: ClientBuilder,
+    url: Option<&str>,
+    username: Option<&str>,
+    password: Option<&str>,
+) -> Result<ClientBuilder> {
+    let Some(url) = url.map(str::trim).filter(|u| !u.is_empty()) else {
+        return Ok(builder);
+    };
+
+    let invalid = |e: reqwest::Error| DomainForgeError::config(format!("Invalid proxy URL '{}': {}", url, e));
+    let mut http = Proxy::http(url).map_err(invalid)?;
+    let mut https = Proxy::https(url).map_err(invalid)?;
+    if let Some(username) = username {
+        let password = password.unwrap_or_default();
+        http = http.basic_auth(username, password);
+        https = https.basic_auth(username, password);
+    }
+    Ok(builder.proxy(http).proxy(https))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_proxy() {
+        assert!(with_proxy(ClientBuilder::new(), None, None, None).is_ok());
+        let builder = with_proxy(ClientBuilder::new(), Some("http://proxy.corp.example.com:8080"), Some("me"), Some("pw")).unwrap();
+        assert!(builder.build().is_ok());
+        assert!(with_proxy(ClientBuilder::new(), Some("not a url"), None, None).is_err());
+    }
+
+    #[test]
+    fn test_with_headers() {
+        assert_eq!(default_user_agent(), format!("domain-forge/{}", crate::VERSION));
+
+        let mut extra = HashMap::new();
+        extra.insert("Authorization".to_string(), "Bearer secret".to_string());
+        let builder = with_headers(ClientBuilder::new(), "acme-monitor/2.0 (ops@example.com)", &extra).unwrap();
+        assert!(builder.build().is_ok());
+
+        assert!(with_headers(ClientBuilder::new(), "bad\nagent", &HashMap::new()).is_err());
+        extra.insert("Bad Header".to_string(), "x".to_string());
+        assert!(with_headers(ClientBuilder::new(), "ok", &extra).is_err());
+    }
+}
 
 
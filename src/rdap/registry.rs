@@ -1,30 +1,206 @@
 //! Central RDAP server registry.
 //!
 //! We intentionally keep this a small, static mapping (convention over configuration).
+//! URLs follow the IANA RDAP bootstrap file (<https://data.iana.org/rdap/dns.json>);
+//! a few ccTLD registries that run RDAP outside the bootstrap (e.g. DENIC) are added by hand.
+//! [`RdapRegistry`] layers runtime entries (private TLDs, mock servers) on top.
+
+use std::collections::HashMap;
+
+use crate::error::{DomainForgeError, Result};
+
+const IDENTITY_DIGITAL: &str = "https://rdap.identitydigital.services/rdap/";
+const GOOGLE: &str = "https://rdap.nic.google/";
+
+/// TLD → RDAP base URL
+const RDAP_SERVERS: &[(&str, &str)] = &[
+    // Legacy and popular gTLDs
+    ("com", "https://rdap.verisign.com/com/v1/"),
+    ("net", "https://rdap.verisign.com/net/v1/"),
+    ("org", "https://rdap.org.org/"),
+    ("info", IDENTITY_DIGITAL),
+    ("biz", "https://rdap.nic.biz/"),
+    ("xyz", "https://rdap.nic.xyz/"),
+    ("tech", "https://rdap.nic.tech/"),
+    ("app", GOOGLE),
+    ("dev", GOOGLE),
+    ("page", GOOGLE),
+    ("new", GOOGLE),
+    ("shop", "https://rdap.gmoregistry.net/rdap/"),
+    ("cloud", "https://rdap.registry.cloud/rdap/"),
+    ("blog", "https://rdap.blog.fury.ca/rdap/"),
+    // CentralNic / Radix
+    ("store", "https://rdap.centralnic.com/store/"),
+    ("online", "https://rdap.centralnic.com/online/"),
+    ("site", "https://rdap.centralnic.com/site/"),
+    ("space", "https://rdap.centralnic.com/space/"),
+    ("website", "https://rdap.centralnic.com/website/"),
+    ("fun", "https://rdap.centralnic.com/fun/"),
+    ("press", "https://rdap.centralnic.com/press/"),
+    ("host", "https://rdap.centralnic.com/host/"),
+    // Identity Digital (formerly Donuts/Afilias)
+    ("ai", IDENTITY_DIGITAL),
+    ("digital", IDENTITY_DIGITAL),
+    ("media", IDENTITY_DIGITAL),
+    ("studio", IDENTITY_DIGITAL),
+    ("agency", IDENTITY_DIGITAL),
+    ("group", IDENTITY_DIGITAL),
+    ("solutions", IDENTITY_DIGITAL),
+    ("systems", IDENTITY_DIGITAL),
+    ("network", IDENTITY_DIGITAL),
+    ("services", IDENTITY_DIGITAL),
+    ("global", IDENTITY_DIGITAL),
+    ("live", IDENTITY_DIGITAL),
+    ("world", IDENTITY_DIGITAL),
+    ("life", IDENTITY_DIGITAL),
+    ("today", IDENTITY_DIGITAL),
+    ("email", IDENTITY_DIGITAL),
+    ("company", IDENTITY_DIGITAL),
+    ("software", IDENTITY_DIGITAL),
+    ("tools", IDENTITY_DIGITAL),
+    ("team", IDENTITY_DIGITAL),
+    ("center", IDENTITY_DIGITAL),
+    ("zone", IDENTITY_DIGITAL),
+    ("social", IDENTITY_DIGITAL),
+    ("academy", IDENTITY_DIGITAL),
+    ("news", IDENTITY_DIGITAL),
+    ("guru", IDENTITY_DIGITAL),
+    ("ventures", IDENTITY_DIGITAL),
+    ("marketing", IDENTITY_DIGITAL),
+    ("consulting", IDENTITY_DIGITAL),
+    ("capital", IDENTITY_DIGITAL),
+    ("fund", IDENTITY_DIGITAL),
+    ("photography", IDENTITY_DIGITAL),
+    ("works", IDENTITY_DIGITAL),
+    ("expert", IDENTITY_DIGITAL),
+    // ccTLDs
+    ("io", "https://rdap.nic.io/"),
+    ("co", "https://rdap.nic.co/"),
+    ("me", "https://rdap.nic.me/"),
+    ("us", "https://rdap.nic.us/"),
+    ("uk", "https://rdap.nominet.uk/uk/"),
+    ("de", "https://rdap.denic.de/"),
+    ("fr", "https://rdap.nic.fr/"),
+    ("ca", "https://rdap.ca.fury.ca/rdap/"),
+    ("au", "https://rdap.cctld.au/rdap/"),
+    ("in", "https://rdap.registry.in/"),
+    ("br", "https://rdap.registro.br/"),
+    ("nl", "https://rdap.sidn.nl/"),
+    ("ch", "https://rdap.nic.ch/"),
+    ("no", "https://rdap.norid.no/"),
+];
 
 /// Get the RDAP base URL for a TLD (lowercase, without leading dot).
 ///
 /// Returned URL is expected to end with `/` and include any version path if needed.
 pub fn rdap_base_url(tld: &str) -> Option<&'static str> {
-    match tld {
-        "com" => Some("https://rdap.verisign.com/com/v1/"),
-        "net" => Some("https://rdap.verisign.com/net/v1/"),
-        "org" => Some("https://rdap.org.org/"),
-        "io" => Some("https://rdap.nic.io/"),
-        "ai" => Some("https://rdap.identitydigital.services/rdap/"),
-        "tech" => Some("https://rdap.nic.tech/"),
-        "app" => Some("https://rdap.nic.google/"),
-        "dev" => Some("https://rdap.nic.google/"),
-        "xyz" => Some("https://rdap.nic.xyz/"),
-        "co" => Some("https://rdap.nic.co/"),
-        "me" => Some("https://rdap.nic.me/"),
-        _ => None,
+    RDAP_SERVERS
+        .iter()
+        .find(|(known, _)| *known == tld)
+        .map(|(_, url)| *url)
+}
+
+/// All TLDs with a known RDAP server
+pub fn supported_tlds() -> impl Iterator<Item = &'static str> {
+    RDAP_SERVERS.iter().map(|(tld, _)| *tld)
+}
+
+/// RDAP base URL from `registry` when given, otherwise from the built-in table
+pub fn lookup<'a>(registry: Option<&'a RdapRegistry>, tld: &str) -> Option<&'a str> {
+    match registry {
+        Some(registry) => registry.get(tld),
+        None => rdap_base_url(tld),
+    }
+}
+
+/// TLD → RDAP server mapping that can be extended at runtime.
+///
+/// Starts with the built-in servers; share it as an `Arc<RdapRegistry>` via
+/// `CheckConfig` or `SnipeConfig`.
+#[derive(Debug, Clone)]
+pub struct RdapRegistry {
+    servers: HashMap<String, String>,
+}
+
+impl RdapRegistry {
+    /// Registry with the built-in servers
+    pub fn new() -> Self {
+        Self {
+            servers: RDAP_SERVERS
+                .iter()
+                .map(|(tld, url)| (tld.to_string(), url.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Add a server for a TLD without one.
+    ///
+    /// The URL must be absolute `http` or `https`; a trailing `/` is added
+    /// if missing. Use [`RdapRegistry::override_tld`] to replace a server.
+    pub fn register(&mut self, tld: &str, base_url: &str) -> Result<()> {
+        let tld = normalize_tld(tld);
+        if tld.is_empty() {
+            return Err(DomainForgeError::validation("TLD cannot be empty"));
+        }
+        if self.servers.contains_key(&tld) {
+            return Err(DomainForgeError::config(format!(
+                ".{} already has an RDAP server; use override_tld to replace it",
+                tld
+            )));
+        }
+
+        let url = reqwest::Url::parse(base_url.trim())
+            .map_err(|e| DomainForgeError::config(format!("Invalid RDAP URL '{}': {}", base_url, e)))?;
+        if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+            return Err(DomainForgeError::config(format!(
+                "RDAP URL '{}' must be an http(s) URL with a host",
+                base_url
+            )));
+        }
+
+        self.servers.insert(tld, with_trailing_slash(base_url.trim()));
+        Ok(())
+    }
+
+    /// Point a TLD at another server, replacing any existing one.
+    ///
+    /// The URL is trusted as given (apart from the trailing `/`).
+    pub fn override_tld(&mut self, tld: &str, base_url: &str) {
+        self.servers.insert(normalize_tld(tld), with_trailing_slash(base_url.trim()));
+    }
+
+    /// RDAP base URL for a TLD (case-insensitive, leading dot optional)
+    pub fn get(&self, tld: &str) -> Option<&str> {
+        self.servers.get(&normalize_tld(tld)).map(String::as_str)
+    }
+
+    /// All TLDs with a server, in no particular order
+    pub fn tlds(&self) -> impl Iterator<Item = &str> {
+        self.servers.keys().map(String::as_str)
+    }
+}
+
+impl Default for RdapRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn normalize_tld(tld: &str) -> String {
+    tld.trim().trim_start_matches('.').to_lowercase()
+}
+
+fn with_trailing_slash(url: &str) -> String {
+    if url.ends_with('/') {
+        url.to_string()
+    } else {
+        format!("{}/", url)
     }
 }
 
 /// Build the RDAP domain query URL for a fully-qualified domain (e.g. `example.com`).
 pub fn rdap_domain_url(domain: &str) -> Option<String> {
-    let tld = domain.split('.').last()?;
+    let tld = domain.split('.').next_back()?;
     let base = rdap_base_url(tld)?;
     Some(format!("{base}domain/{domain}"))
 }
@@ -32,11 +208,13 @@ pub fn rdap_domain_url(domain: &str) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     #[test]
     fn test_base_url_known() {
         assert!(rdap_base_url("com").is_some());
         assert!(rdap_base_url("io").is_some());
+        assert_eq!(rdap_base_url("uk"), Some("https://rdap.nominet.uk/uk/"));
         assert!(rdap_base_url("unknown").is_none());
     }
 
@@ -45,6 +223,37 @@ mod tests {
         let url = rdap_domain_url("example.com").unwrap();
         assert!(url.contains("domain/example.com"));
     }
-}
 
+    #[test]
+    fn test_runtime_registry() {
+        let mut registry = RdapRegistry::new();
+        assert_eq!(registry.get("uk"), rdap_base_url("uk"));
+        assert!(registry.get("internal").is_none());
+
+        registry.register(".Internal", "http://127.0.0.1:8080/rdap").unwrap();
+        assert_eq!(registry.get("internal"), Some("http://127.0.0.1:8080/rdap/"));
+        assert!(registry.register("internal", "http://other/").is_err());
+        assert!(registry.register("corp", "not a url").is_err());
+        assert!(registry.register("corp", "ftp://rdap.corp/").is_err());
+        assert!(registry.register("", "https://rdap.corp/").is_err());
+
+        registry.override_tld("com", "https://rdap.mirror.example/com/");
+        assert_eq!(lookup(Some(&registry), "com"), Some("https://rdap.mirror.example/com/"));
+        assert_eq!(lookup(None, "com"), rdap_base_url("com"));
+    }
+
+    #[test]
+    fn test_registry_urls_are_valid() {
+        let mut seen = HashSet::new();
+        for &(tld, base) in RDAP_SERVERS {
+            assert!(seen.insert(tld), "duplicate entry for .{}", tld);
+            assert!(base.ends_with('/'), "{} should end with '/'", base);
 
+            let url = reqwest::Url::parse(base).unwrap_or_else(|e| panic!("{}: {}", base, e));
+            assert_eq!(url.scheme(), "https", "{}", base);
+            assert!(url.host_str().is_some(), "{}", base);
+            assert!(reqwest::Url::parse(&rdap_domain_url(&format!("forge.{}", tld)).unwrap()).is_ok());
+        }
+        assert!(seen.len() >= 60);
+    }
+}
@@ -0,0 +1,103 @@
+//! Interpretation of RDAP domain status values
+//!
+//! RDAP reports the EPP status codes of a registration (RFC 8056), spelled
+//! either as words (`pending delete`) or in EPP camel case (`pendingDelete`).
+
+use crate::types::AvailabilityStatus;
+
+/// Known status codes and what they mean for availability
+const STATUS_MAP: &[(&str, AvailabilityStatus)] = &[
+    // In the deletion pipeline: registered, but about to drop
+    ("pendingdelete", AvailabilityStatus::PendingDelete),
+    ("redemptionperiod", AvailabilityStatus::PendingDelete),
+    ("pendingrestore", AvailabilityStatus::PendingDelete),
+    // Regular registrations
+    ("active", AvailabilityStatus::Taken),
+    ("ok", AvailabilityStatus::Taken),
+    ("inactive", AvailabilityStatus::Taken),
+    ("addperiod", AvailabilityStatus::Taken),
+    ("autorenewperiod", AvailabilityStatus::Taken),
+    ("renewperiod", AvailabilityStatus::Taken),
+    ("transferperiod", AvailabilityStatus::Taken),
+    ("pendingcreate", AvailabilityStatus::Taken),
+    ("pendingrenew", AvailabilityStatus::Taken),
+    ("pendingtransfer", AvailabilityStatus::Taken),
+    ("pendingupdate", AvailabilityStatus::Taken),
+    ("clienthold", AvailabilityStatus::Taken),
+    ("serverhold", AvailabilityStatus::Taken),
+    ("clientdeleteprohibited", AvailabilityStatus::Taken),
+    ("serverdeleteprohibited", AvailabilityStatus::Taken),
+    ("clientrenewprohibited", AvailabilityStatus::Taken),
+    ("serverrenewprohibited", AvailabilityStatus::Taken),
+    ("clienttransferprohibited", AvailabilityStatus::Taken),
+    ("servertransferprohibited", AvailabilityStatus::Taken),
+    ("clientupdateprohibited", AvailabilityStatus::Taken),
+    ("serverupdateprohibited", AvailabilityStatus::Taken),
+];
+
+/// Lowercase and strip separators: `Pending Delete` → `pendingdelete`
+fn normalize(status: &str) -> String {
+    status
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Availability implied by a single status; unknown codes count as `Taken`
+fn map_status(status: &str) -> AvailabilityStatus {
+    let status = normalize(status);
+    STATUS_MAP
+        .iter()
+        .find(|(code, _)| *code == status)
+        .map_or(AvailabilityStatus::Taken, |(_, mapped)| *mapped)
+}
+
+/// Whether a status puts the domain in the deletion pipeline
+pub fn is_deletion_status(status: &str) -> bool {
+    map_status(status) == AvailabilityStatus::PendingDelete
+}
+
+/// Availability implied by the status list of an existing RDAP record.
+///
+/// Any deletion-pipeline status yields `PendingDelete`; everything else,
+/// including unknown or missing statuses, means the domain is `Taken`.
+pub fn interpret_rdap_status(statuses: &[String]) -> AvailabilityStatus {
+    if statuses.iter().any(|status| is_deletion_status(status)) {
+        AvailabilityStatus::PendingDelete
+    } else {
+        AvailabilityStatus::Taken
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statuses(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_interpret_rdap_status() {
+        assert_eq!(interpret_rdap_status(&statuses(&["active"])), AvailabilityStatus::Taken);
+        assert_eq!(
+            interpret_rdap_status(&statuses(&["client delete prohibited", "serverHold"])),
+            AvailabilityStatus::Taken
+        );
+        assert_eq!(
+            interpret_rdap_status(&statuses(&["client transfer prohibited", "pending delete"])),
+            AvailabilityStatus::PendingDelete
+        );
+        assert_eq!(interpret_rdap_status(&statuses(&["redemptionPeriod"])), AvailabilityStatus::PendingDelete);
+        assert_eq!(interpret_rdap_status(&statuses(&["something new"])), AvailabilityStatus::Taken);
+        assert_eq!(interpret_rdap_status(&[]), AvailabilityStatus::Taken);
+    }
+
+    #[test]
+    fn test_is_deletion_status() {
+        assert!(is_deletion_status("pending restore"));
+        assert!(is_deletion_status("pending_delete"));
+        assert!(!is_deletion_status("pendingTransfer"));
+    }
+}
@@ -0,0 +1,185 @@
+//! Bloom filter of domains already checked by a scan.
+//!
+//! Stored in the state file so a resumed scan whose generator no longer lines
+//! up with `current_index` (a rewound index, an extended word list) can skip
+//! domains it has already queried. A hit only means "probably checked": with
+//! the configured false positive rate a small share of new domains is skipped
+//! too, so the filter is sized for the whole scan up front.
+
+use std::f64::consts::LN_2;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Default false positive rate (`SnipeConfig::bloom_fpr`)
+pub const DEFAULT_BLOOM_FPR: f64 = 0.01;
+
+/// Scans with more domains than this get no filter; at 1% it would take
+/// ~19 MB, rewritten with every state save
+pub const MAX_BLOOM_ITEMS: u64 = 16_000_000;
+
+/// Bytes before the bit array in the serialized blob (hashes, bits, items)
+const HEADER_LEN: usize = 4 + 8 + 8;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Bloom filter over full domain names, serialized as a base64 blob
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilterData {
+    bits: Vec<u8>,
+    num_bits: u64,
+    num_hashes: u32,
+    items: u64,
+}
+
+impl BloomFilterData {
+    /// Filter for up to `expected_items` domains at false positive rate `fpr`
+    pub fn new(expected_items: u64, fpr: f64) -> Self {
+        let items = expected_items.max(1) as f64;
+        let fpr = fpr.clamp(f64::MIN_POSITIVE, 0.5);
+        let num_bits = (-items * fpr.ln() / (LN_2 * LN_2)).ceil().max(64.0) as u64;
+        let num_hashes = ((num_bits as f64 / items) * LN_2).round().clamp(1.0, 32.0) as u32;
+        Self {
+            bits: vec![0; num_bits.div_ceil(8) as usize],
+            num_bits,
+            num_hashes,
+            items: 0,
+        }
+    }
+
+    /// Filter sized for a scan of `total` domains, or `None` past [`MAX_BLOOM_ITEMS`]
+    pub fn for_scan(total: u64, fpr: f64) -> Option<Self> {
+        (total <= MAX_BLOOM_ITEMS).then(|| Self::new(total, fpr))
+    }
+
+    /// Record a domain as checked
+    pub fn insert(&mut self, domain: &str) {
+        for bit in self.bit_indexes(domain) {
+            self.bits[(bit / 8) as usize] |= 1 << (bit % 8);
+        }
+        self.items += 1;
+    }
+
+    /// Whether a domain was probably recorded (never false for recorded ones)
+    pub fn contains(&self, domain: &str) -> bool {
+        self.bit_indexes(domain)
+            .all(|bit| self.bits[(bit / 8) as usize] & (1 << (bit % 8)) != 0)
+    }
+
+    /// Number of insertions
+    pub fn len(&self) -> u64 {
+        self.items
+    }
+
+    /// Size of the bit array in bytes
+    pub fn size_bytes(&self) -> usize {
+        self.bits.len()
+    }
+
+    /// Whether nothing was recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.items == 0
+    }
+
+    /// Filter holding the domains of both, if they have the same shape
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.num_bits != other.num_bits || self.num_hashes != other.num_hashes {
+            return None;
+        }
+        Some(Self {
+            bits: self.bits.iter().zip(&other.bits).map(|(a, b)| a | b).collect(),
+            num_bits: self.num_bits,
+            num_hashes: self.num_hashes,
+            items: self.items + other.items,
+        })
+    }
+
+    /// Bit positions by double hashing two FNV-1a hashes, stable across builds
+    fn bit_indexes(&self, domain: &str) -> impl Iterator<Item = u64> {
+        let h1 = fnv1a(domain.as_bytes(), FNV_OFFSET);
+        let h2 = fnv1a(domain.as_bytes(), h1) | 1;
+        let num_bits = self.num_bits;
+        (0..u64::from(self.num_hashes)).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % num_bits)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.bits.len());
+        bytes.extend_from_slice(&self.num_hashes.to_le_bytes());
+        bytes.extend_from_slice(&self.num_bits.to_le_bytes());
+        bytes.extend_from_slice(&self.items.to_le_bytes());
+        bytes.extend_from_slice(&self.bits);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < HEADER_LEN {
+            return None;
+        }
+        let num_hashes = u32::from_le_bytes(bytes[0..4].try_into().ok()?);
+        let num_bits = u64::from_le_bytes(bytes[4..12].try_into().ok()?);
+        let items = u64::from_le_bytes(bytes[12..20].try_into().ok()?);
+        let bits = bytes[HEADER_LEN..].to_vec();
+        let valid = num_hashes > 0 && num_bits > 0 && bits.len() as u64 == num_bits.div_ceil(8);
+        valid.then_some(Self { bits, num_bits, num_hashes, items })
+    }
+}
+
+fn fnv1a(bytes: &[u8], seed: u64) -> u64 {
+    bytes.iter().fold(seed, |hash, &b| (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME))
+}
+
+impl Serialize for BloomFilterData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(self.to_bytes()))
+    }
+}
+
+impl<'de> Deserialize<'de> for BloomFilterData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let blob = String::deserialize(deserializer)?;
+        let bytes = STANDARD.decode(blob.trim()).map_err(serde::de::Error::custom)?;
+        Self::from_bytes(&bytes).ok_or_else(|| serde::de::Error::custom("malformed bloom filter"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_contains() {
+        let mut bloom = BloomFilterData::new(1000, DEFAULT_BLOOM_FPR);
+        assert!(bloom.is_empty());
+        for i in 0..1000 {
+            bloom.insert(&format!("name{}.com", i));
+        }
+        assert_eq!(bloom.len(), 1000);
+        assert!((0..1000).all(|i| bloom.contains(&format!("name{}.com", i))));
+
+        let false_positives = (0..10_000).filter(|i| bloom.contains(&format!("other{}.io", i))).count();
+        assert!(false_positives < 300, "{} false positives", false_positives);
+
+        assert!(BloomFilterData::for_scan(MAX_BLOOM_ITEMS + 1, DEFAULT_BLOOM_FPR).is_none());
+    }
+
+    #[test]
+    fn test_base64_roundtrip_and_union() {
+        let mut a = BloomFilterData::new(100, 0.05);
+        a.insert("abc.com");
+        let mut b = BloomFilterData::new(100, 0.05);
+        b.insert("xyz.io");
+
+        let json = serde_json::to_string(&a).unwrap();
+        assert!(json.starts_with('"'));
+        let loaded: BloomFilterData = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, a);
+        assert!(serde_json::from_str::<BloomFilterData>("\"AAAA\"").is_err());
+
+        let both = a.union(&b).unwrap();
+        assert!(both.contains("abc.com") && both.contains("xyz.io"));
+        assert_eq!(both.len(), 2);
+        assert!(a.union(&BloomFilterData::new(10_000, 0.05)).is_none());
+    }
+}
@@ -0,0 +1,184 @@
+//! Per-endpoint circuit breaker for RDAP checks.
+//!
+//! When a registry's RDAP server goes down, every check against it fails and
+//! the scan burns time (and error budget) on requests that cannot succeed.
+//! The breaker "opens" after a run of consecutive failures, short-circuits
+//! further checks for a cooldown, then lets a single probe through to decide
+//! whether to close again.
+
+use std::time::{Duration, Instant};
+
+/// Default number of consecutive failures before the circuit opens.
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+
+/// Default cooldown before an open circuit allows a probe.
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Circuit state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally
+    Closed,
+    /// Requests are rejected until the cooldown elapses
+    Open,
+    /// Cooldown elapsed; a single probe request is allowed through
+    HalfOpen,
+}
+
+/// Circuit breaker for a single RDAP endpoint
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    state: CircuitState,
+    consecutive_failures: u32,
+    failure_threshold: u32,
+    cooldown: Duration,
+    opened_at: Option<Instant>,
+    probe_in_flight: bool,
+}
+
+impl CircuitBreaker {
+    /// Create a closed circuit breaker
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            opened_at: None,
+            probe_in_flight: false,
+        }
+    }
+
+    /// Current state (an open circuit whose cooldown has elapsed reports `HalfOpen`)
+    pub fn state(&self) -> CircuitState {
+        match self.state {
+            CircuitState::Open if self.cooldown_elapsed() => CircuitState::HalfOpen,
+            state => state,
+        }
+    }
+
+    /// Number of consecutive failures recorded
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Decide whether a request may proceed.
+    ///
+    /// In the half-open state only one probe is allowed at a time; every other
+    /// caller is rejected until that probe reports back.
+    pub fn allow_request(&mut self) -> bool {
+        match self.state {
+            CircuitState::Closed => true,
+            CircuitState::Open => {
+                if self.cooldown_elapsed() {
+                    self.state = CircuitState::HalfOpen;
+                    self.probe_in_flight = true;
+                    true
+                } else {
+                    false
+                }
+            }
+            CircuitState::HalfOpen => {
+                if self.probe_in_flight {
+                    false
+                } else {
+                    self.probe_in_flight = true;
+                    true
+                }
+            }
+        }
+    }
+
+    /// Record a successful request (closes the circuit)
+    pub fn record_success(&mut self) {
+        self.state = CircuitState::Closed;
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+        self.probe_in_flight = false;
+    }
+
+    /// Record a failed request.
+    ///
+    /// Returns `true` if this failure transitioned the circuit to open.
+    pub fn record_failure(&mut self) -> bool {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        self.probe_in_flight = false;
+
+        let should_open = match self.state {
+            CircuitState::Closed => self.consecutive_failures >= self.failure_threshold,
+            // A failed probe re-opens the circuit for another cooldown
+            CircuitState::HalfOpen => true,
+            CircuitState::Open => false,
+        };
+
+        if should_open {
+            self.state = CircuitState::Open;
+            self.opened_at = Some(Instant::now());
+        }
+
+        should_open
+    }
+
+    fn cooldown_elapsed(&self) -> bool {
+        self.opened_at
+            .map(|t| t.elapsed() >= self.cooldown)
+            .unwrap_or(true)
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(DEFAULT_FAILURE_THRESHOLD, DEFAULT_COOLDOWN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opens_after_threshold() {
+        let mut breaker = CircuitBreaker::new(3, Duration::from_secs(30));
+        assert!(!breaker.record_failure());
+        assert!(!breaker.record_failure());
+        assert!(breaker.allow_request());
+        assert!(breaker.record_failure());
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allow_request());
+    }
+
+    #[test]
+    fn test_success_resets_failures() {
+        let mut breaker = CircuitBreaker::new(2, Duration::from_secs(30));
+        breaker.record_failure();
+        breaker.record_success();
+        assert!(!breaker.record_failure());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_allows_single_probe() {
+        let mut breaker = CircuitBreaker::new(1, Duration::ZERO);
+        assert!(breaker.record_failure());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        assert!(breaker.allow_request());
+        assert!(!breaker.allow_request());
+
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.allow_request());
+    }
+
+    #[test]
+    fn test_failed_probe_reopens() {
+        let mut breaker = CircuitBreaker::new(1, Duration::from_secs(30));
+        breaker.record_failure();
+        breaker.opened_at = Some(Instant::now() - Duration::from_secs(31));
+
+        assert!(breaker.allow_request());
+        assert!(breaker.record_failure());
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allow_request());
+    }
+}
@@ -0,0 +1,180 @@
+//! Out-of-band control of a running scan
+//!
+//! On Unix, SIGTERM asks the scan to stop after the current batch (saving
+//! state first) and SIGUSR1 asks for an immediate checkpoint. Windows has
+//! neither signal, so there the same requests are made by creating a
+//! `.stop` or `.checkpoint` file named after the state file.
+//!
+//! On every platform, a `snipe.pause` file in the state file's directory
+//! holds the scan between batches for as long as it exists, and a
+//! `snipe.stop` file stops it like SIGTERM (and is deleted once seen).
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::error::Result;
+
+/// Holds every scan writing to the directory between batches while it exists
+pub const PAUSE_FILE: &str = "snipe.pause";
+/// Stops the first scan writing to the directory to see it, after its
+/// current batch; that scan deletes the file
+pub const STOP_FILE: &str = "snipe.stop";
+
+/// Shared flags checked by [`DomainSniper::run`](super::DomainSniper::run) after each batch
+#[derive(Debug, Clone, Default)]
+pub struct ScanControl {
+    shutdown_requested: Arc<AtomicBool>,
+    checkpoint_requested: Arc<AtomicBool>,
+}
+
+impl ScanControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Save state and stop after the current batch
+    pub fn request_shutdown(&self) {
+        self.shutdown_requested.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_shutdown_requested(&self) -> bool {
+        self.shutdown_requested.load(Ordering::SeqCst)
+    }
+
+    /// Save state and report progress after the current batch, then keep going
+    pub fn request_checkpoint(&self) {
+        self.checkpoint_requested.store(true, Ordering::SeqCst);
+    }
+
+    /// Consume a pending checkpoint request
+    pub(crate) fn take_checkpoint_request(&self) -> bool {
+        self.checkpoint_requested.swap(false, Ordering::SeqCst)
+    }
+
+    /// Stop and checkpoint trigger files for a state file
+    pub fn control_files(state_file: &Path) -> (PathBuf, PathBuf) {
+        (state_file.with_extension("stop"), state_file.with_extension("checkpoint"))
+    }
+
+    /// Turn trigger files into requests, deleting them once seen
+    pub fn check_control_files(&self, state_file: &Path) {
+        let (stop, checkpoint) = Self::control_files(state_file);
+        if std::fs::remove_file(&stop).is_ok() {
+            self.request_shutdown();
+        }
+        if std::fs::remove_file(&checkpoint).is_ok() {
+            self.request_checkpoint();
+        }
+    }
+
+    /// Hold and stop files for scans writing to `dir`
+    pub fn scan_files(dir: &Path) -> (PathBuf, PathBuf) {
+        (dir.join(PAUSE_FILE), dir.join(STOP_FILE))
+    }
+
+    /// Whether the hold file in `dir` exists
+    pub fn is_paused(dir: &Path) -> bool {
+        Self::scan_files(dir).0.exists()
+    }
+
+    /// Request shutdown if the stop file in `dir` exists, deleting it
+    pub fn check_stop_file(&self, dir: &Path) {
+        if std::fs::remove_file(Self::scan_files(dir).1).is_ok() {
+            self.request_shutdown();
+        }
+    }
+
+    /// Start listening for SIGTERM and SIGUSR1 (must run inside a Tokio runtime)
+    #[cfg(unix)]
+    pub fn listen(&self, _state_file: &Path) -> Result<()> {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let install = |kind: SignalKind, name: &str| {
+            signal(kind).map_err(|e| {
+                crate::error::DomainForgeError::internal(format!("Failed to install {} handler: {}", name, e))
+            })
+        };
+        let mut terminate = install(SignalKind::terminate(), "SIGTERM")?;
+        let mut user1 = install(SignalKind::user_defined1(), "SIGUSR1")?;
+
+        let control = self.clone();
+        tokio::spawn(async move {
+            while terminate.recv().await.is_some() {
+                control.request_shutdown();
+            }
+        });
+        let control = self.clone();
+        tokio::spawn(async move {
+            while user1.recv().await.is_some() {
+                control.request_checkpoint();
+            }
+        });
+        Ok(())
+    }
+
+    /// Start polling for stop/checkpoint files (must run inside a Tokio runtime)
+    #[cfg(not(unix))]
+    pub fn listen(&self, state_file: &Path) -> Result<()> {
+        let control = self.clone();
+        let state_file = state_file.to_path_buf();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
+            while !control.is_shutdown_requested() {
+                interval.tick().await;
+                control.check_control_files(&state_file);
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_control_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join("snipe_4letter.json");
+        let (stop, checkpoint) = ScanControl::control_files(&state_file);
+        assert_eq!(stop, dir.path().join("snipe_4letter.stop"));
+
+        let control = ScanControl::new();
+        control.check_control_files(&state_file);
+        assert!(!control.is_shutdown_requested());
+        assert!(!control.take_checkpoint_request());
+
+        std::fs::write(&checkpoint, "").unwrap();
+        control.check_control_files(&state_file);
+        assert!(control.take_checkpoint_request());
+        assert!(!control.take_checkpoint_request());
+        assert!(!checkpoint.exists());
+
+        std::fs::write(&stop, "").unwrap();
+        control.clone().check_control_files(&state_file);
+        assert!(control.is_shutdown_requested());
+        assert!(!stop.exists());
+    }
+
+    #[test]
+    fn test_pause_and_stop_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let (pause, stop) = ScanControl::scan_files(dir.path());
+        assert_eq!(pause, dir.path().join("snipe.pause"));
+
+        let control = ScanControl::new();
+        assert!(!ScanControl::is_paused(dir.path()));
+        std::fs::write(&pause, "").unwrap();
+        assert!(ScanControl::is_paused(dir.path()));
+        // Pausing alone never stops the scan, and the hold file is left alone
+        control.check_stop_file(dir.path());
+        assert!(!control.is_shutdown_requested());
+        assert!(pause.exists());
+
+        std::fs::write(&stop, "").unwrap();
+        control.check_stop_file(dir.path());
+        assert!(control.is_shutdown_requested());
+        assert!(!stop.exists());
+    }
+}
@@ -0,0 +1,98 @@
+//! Compare two snipe result files to track changes between scans
+
+use std::collections::HashSet;
+
+use super::state::{ScanState, SnipedDomain};
+
+/// Changes between an older and a newer scan state
+#[derive(Debug, Clone, Default)]
+pub struct ScanDiff {
+    /// Available now, but not in the old state
+    pub newly_available: Vec<SnipedDomain>,
+    /// Available in the old state, no longer available now
+    pub newly_registered: Vec<String>,
+    /// Expiring soon now, but not in the old state
+    pub newly_expiring: Vec<SnipedDomain>,
+    /// Expiring soon in the old state, no longer expiring now
+    pub no_longer_expiring: Vec<String>,
+    /// Change in the number of checked domains
+    pub checked_delta: i64,
+}
+
+impl ScanDiff {
+    /// Whether the two states differ in any tracked list
+    pub fn is_empty(&self) -> bool {
+        self.newly_available.is_empty()
+            && self.newly_registered.is_empty()
+            && self.newly_expiring.is_empty()
+            && self.no_longer_expiring.is_empty()
+    }
+}
+
+/// Compare two scan states, keyed by `full_domain`
+pub fn diff(old: &ScanState, new: &ScanState) -> ScanDiff {
+    let keys = |domains: &[SnipedDomain]| -> HashSet<String> {
+        domains.iter().map(|d| d.full_domain.clone()).collect()
+    };
+    let old_available = keys(&old.available);
+    let new_available = keys(&new.available);
+    let old_expiring = keys(&old.expiring_soon);
+    let new_expiring = keys(&new.expiring_soon);
+
+    ScanDiff {
+        newly_available: added(&new.available, &old_available),
+        newly_registered: removed(&old.available, &new_available),
+        newly_expiring: added(&new.expiring_soon, &old_expiring),
+        no_longer_expiring: removed(&old.expiring_soon, &new_expiring),
+        checked_delta: new.checked_count as i64 - old.checked_count as i64,
+    }
+}
+
+fn added(domains: &[SnipedDomain], before: &HashSet<String>) -> Vec<SnipedDomain> {
+    domains
+        .iter()
+        .filter(|d| !before.contains(&d.full_domain))
+        .cloned()
+        .collect()
+}
+
+fn removed(domains: &[SnipedDomain], after: &HashSet<String>) -> Vec<String> {
+    let mut names: Vec<String> = domains
+        .iter()
+        .filter(|d| !after.contains(&d.full_domain))
+        .map(|d| d.full_domain.clone())
+        .collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sniped(name: &str) -> SnipedDomain {
+        SnipedDomain::new(name, "com")
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut old = ScanState::new(4, vec!["com".to_string()], 100);
+        old.checked_count = 40;
+        old.available = vec![sniped("abcd"), sniped("efgh")];
+        old.expiring_soon = vec![sniped("ijkl"), sniped("mnop")];
+
+        let mut new = ScanState::new(4, vec!["com".to_string()], 100);
+        new.checked_count = 100;
+        new.available = vec![sniped("abcd"), sniped("ijkl")];
+        new.expiring_soon = vec![sniped("mnop"), sniped("qrst")];
+
+        let diff = diff(&old, &new);
+        assert_eq!(diff.newly_available.len(), 1);
+        assert_eq!(diff.newly_available[0].full_domain, "ijkl.com");
+        assert_eq!(diff.newly_registered, vec!["efgh.com"]);
+        assert_eq!(diff.newly_expiring[0].full_domain, "qrst.com");
+        assert_eq!(diff.no_longer_expiring, vec!["ijkl.com"]);
+        assert_eq!(diff.checked_delta, 60);
+        assert!(!diff.is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+//! Export snipe scan results to CSV
+
+use std::io::Write;
+use std::path::Path;
+
+use crate::domain::export::CsvExporter;
+use crate::error::Result;
+
+use super::state::ScanState;
+
+/// CSV writer for snipe scan results (available, expiring and expired domains).
+///
+/// Rows carry the scan's tags in an extra `tags` column, separated by `;`.
+#[derive(Debug, Clone)]
+pub struct SnipeCsvExporter {
+    inner: CsvExporter,
+}
+
+impl SnipeCsvExporter {
+    /// Create an exporter without a byte order mark
+    pub fn new() -> Self {
+        Self::from_csv(CsvExporter::new())
+    }
+
+    /// Scan exporter with the options of `csv` plus the `tags` column
+    pub fn from_csv(csv: CsvExporter) -> Self {
+        Self {
+            inner: csv.with_extra_columns(&["tags"]),
+        }
+    }
+
+    /// Prefix output with a UTF-8 BOM (for Excel on Windows)
+    pub fn with_bom(mut self, bom: bool) -> Self {
+        self.inner = self.inner.with_bom(bom);
+        self
+    }
+
+    /// Write all found domains in the scan state
+    pub fn write<W: Write>(&self, writer: W, state: &ScanState) -> Result<()> {
+        self.inner.write_rows(writer, Self::rows(state))
+    }
+
+    /// Save all found domains in the scan state to a CSV file
+    pub fn save(&self, path: &Path, state: &ScanState) -> Result<()> {
+        self.inner.save_rows(path, Self::rows(state))
+    }
+
+    fn rows(state: &ScanState) -> impl Iterator<Item = Vec<String>> + '_ {
+        let tags = state.tags.join(";");
+        state
+            .available
+            .iter()
+            .chain(&state.pending_delete)
+            .chain(&state.expiring_soon)
+            .chain(&state.expired)
+            .map(move |d| {
+                let mut row = d.to_csv_row();
+                row.push(tags.clone());
+                row
+            })
+    }
+}
+
+impl Default for SnipeCsvExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snipe::state::SnipedDomain;
+    use chrono::{Duration, Utc};
+
+    fn sniped(name: &str, expires_in_days: Option<i64>) -> SnipedDomain {
+        SnipedDomain {
+            expiration_date: expires_in_days.map(|d| Utc::now() + Duration::days(d)),
+            days_until_expiry: expires_in_days,
+            ..SnipedDomain::new(name, "com")
+        }
+    }
+
+    #[test]
+    fn test_snipe_csv_rows() {
+        let mut state = ScanState::new(4, vec!["com".to_string()], 10);
+        state.add_tags(&["q3".to_string(), "brand".to_string()]);
+        state.available.push(sniped("abcd", None));
+        state.expiring_soon.push(sniped("efgh", Some(3)));
+        state.expired.push(sniped("ijkl", Some(-2)));
+        state.pending_delete.push(SnipedDomain {
+            rdap_status: vec!["redemption period".to_string()],
+            ..sniped("mnop", Some(-40))
+        });
+
+        let mut out = Vec::new();
+        SnipeCsvExporter::new().write(&mut out, &state).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = text.lines().collect();
+
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].ends_with(",found_at,tags"));
+        assert!(lines[1].starts_with("abcd.com,com,available,"));
+        assert!(lines[1].ends_with(",q3;brand"));
+        assert!(lines[2].starts_with("mnop.com,com,pending_delete,"));
+        assert!(lines[3].starts_with("efgh.com,com,expiring_soon,"));
+        assert!(lines[4].starts_with("ijkl.com,com,expired,"));
+    }
+}
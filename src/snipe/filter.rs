@@ -18,7 +18,56 @@ const VALUABLE_SUFFIXES: &[&str] = &[
     "er", "ed", "en", "ey", "ie", "ty", "by", "ry", "ny", "xy",
 ];
 
+/// Consonant pairs that spell a single English sound
+const PHONEME_DIGRAPHS: &[&str] = &["ng", "sh", "th", "ch", "ck"];
+
+/// Consonant pairs common at the start or end of English syllables
+const COMMON_CLUSTERS: &[&str] = &[
+    "bl", "br", "cl", "cr", "dr", "fl", "fr", "gl", "gr", "pl", "pr", "sk", "sl", "sm", "sn",
+    "sp", "st", "sw", "tr", "tw", "wh", "ph", "nd", "nt", "nk", "mp", "ld", "lk", "lt", "rd",
+    "rk", "rn", "rt", "ft", "ct", "pt", "ll", "ss", "ff",
+];
+
+/// Vowel pairs common in English spelling
+const COMMON_VOWEL_PAIRS: &[&str] = &["ai", "ea", "ee", "ie", "io", "oa", "oo", "ou"];
+
+/// Score of one adjacent letter pair
+fn pair_score(pair: &str, first_vowel: bool, second_vowel: bool) -> f32 {
+    match (first_vowel, second_vowel) {
+        (false, false) if PHONEME_DIGRAPHS.contains(&pair) => 1.0,
+        (false, false) if COMMON_CLUSTERS.contains(&pair) => 0.75,
+        (false, false) => 0.0,
+        (true, true) if COMMON_VOWEL_PAIRS.contains(&pair) => 0.75,
+        (true, true) => 0.4,
+        _ => 0.75,
+    }
+}
+
+/// How natural a name sounds in English, from 0.0 to 1.0.
+///
+/// The average score of its adjacent letter pairs: phoneme digraphs (`ng`,
+/// `sh`, `th`, `ch`, `ck`) score 1.0, consonant-vowel transitions and common
+/// clusters such as `st` or `bl` 0.75, and any other consonant pair 0.0, so
+/// names like `bfqz` score 0.0. `y` counts as a vowel.
+pub fn pronounceable_score(name: &str) -> f32 {
+    let letters: Vec<char> = name.trim().to_lowercase().chars().collect();
+    if letters.len() < 2 {
+        return 0.0;
+    }
+
+    let is_vowel = |c: char| VOWELS.contains(&c) || c == 'y';
+    let total: f32 = letters
+        .windows(2)
+        .map(|pair| {
+            let text: String = pair.iter().collect();
+            pair_score(&text, is_vowel(pair[0]), is_vowel(pair[1]))
+        })
+        .sum();
+    total / (letters.len() - 1) as f32
+}
+
 /// Pronounceable pattern types
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy)]
 pub enum Pattern {
     /// Consonant-Vowel-Consonant-Vowel (e.g., "boca", "dune", "kite")
@@ -44,6 +93,8 @@ pub struct PronounceableGenerator {
     current_index: u64,
     pattern_sizes: Vec<u64>,
     total: u64,
+    /// Names scoring below this [`pronounceable_score`] are skipped
+    min_score: Option<f32>,
 }
 
 impl PronounceableGenerator {
@@ -67,7 +118,27 @@ impl PronounceableGenerator {
             current_index: 0,
             pattern_sizes,
             total,
+            min_score: None,
+        }
+    }
+
+    /// Skip names whose [`pronounceable_score`] is below `threshold`
+    pub fn set_min_score(&mut self, threshold: f32) {
+        self.min_score = Some(threshold);
+    }
+
+    fn passes(&self, name: &str) -> bool {
+        self.min_score.is_none_or(|min| pronounceable_score(name) >= min)
+    }
+
+    /// Names that pass the score threshold, out of [`total`](Self::total)
+    pub fn matching_total(&self) -> u64 {
+        if self.min_score.is_none() {
+            return self.total;
         }
+        let mut all = Self::new();
+        all.min_score = self.min_score;
+        all.count() as u64
     }
 
     fn pattern_size(pattern: Pattern) -> u64 {
@@ -236,7 +307,7 @@ impl PronounceableGenerator {
 
             if let Some(domain) = self.generate_for_pattern(pattern, self.current_index) {
                 // Deduplicate (prefix/suffix patterns may overlap)
-                if seen.insert(domain.clone()) {
+                if self.passes(&domain) && seen.insert(domain.clone()) {
                     batch.push(domain);
                 }
             }
@@ -273,7 +344,10 @@ impl Iterator for PronounceableGenerator {
 
             let result = self.generate_for_pattern(*pattern, self.current_index);
             self.current_index += 1;
-            return result;
+            match result {
+                Some(name) if !self.passes(&name) => continue,
+                result => return result,
+            }
         }
     }
 }
@@ -324,4 +398,24 @@ mod tests {
         assert!(all.contains(&"goaa".to_string()) || all.contains(&"myaa".to_string()));
         assert!(all.contains(&"aaly".to_string()) || all.contains(&"aaio".to_string()));
     }
+
+    #[test]
+    fn test_pronounceable_score() {
+        assert_eq!(pronounceable_score("bfqz"), 0.0);
+        assert_eq!(pronounceable_score("xvkw"), 0.0);
+        assert!(pronounceable_score("sing") > pronounceable_score("boca"));
+        assert!(pronounceable_score("rock") > pronounceable_score("rozk"));
+        assert!(pronounceable_score("stop") > pronounceable_score("sbop"));
+        assert!(pronounceable_score("boat") > pronounceable_score("buit"));
+    }
+
+    #[test]
+    fn test_min_score() {
+        let mut gen = PronounceableGenerator::new();
+        gen.set_min_score(0.7);
+        let batch = gen.next_batch(500);
+        assert_eq!(batch.len(), 500);
+        assert!(batch.iter().all(|name| pronounceable_score(name) >= 0.7));
+        assert!(gen.matching_total() < gen.total());
+    }
 }
@@ -1,6 +1,61 @@
 //! Domain name generator for sniping
 
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
 use super::Charset;
+use crate::error::{DomainForgeError, Result};
+
+/// `(start, end)` of part `index` (from 0) when `0..total` is split into `count` parts
+fn chunk_bounds(total: u64, index: u64, count: u64) -> (u64, u64) {
+    let bound = |i: u64| (u128::from(total) * u128::from(i) / u128::from(count)) as u64;
+    (bound(index), bound(index + 1))
+}
+
+/// One of `count` near-equal parts of a scan, numbered from 1 (`--chunk 2/4`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ScanChunk {
+    pub index: usize,
+    pub count: usize,
+}
+
+impl ScanChunk {
+    pub fn new(index: usize, count: usize) -> Result<Self> {
+        if count == 0 || !(1..=count).contains(&index) {
+            return Err(DomainForgeError::validation(format!(
+                "Invalid chunk {}/{}: expected i/n with 1 <= i <= n",
+                index, count
+            )));
+        }
+        Ok(Self { index, count })
+    }
+
+    /// `(start, end)` indices of this chunk out of `total` combinations
+    pub fn range(&self, total: u64) -> (u64, u64) {
+        chunk_bounds(total, self.index as u64 - 1, self.count as u64)
+    }
+}
+
+impl fmt::Display for ScanChunk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
+
+impl FromStr for ScanChunk {
+    type Err = DomainForgeError;
+
+    /// Parse `i/n`, e.g. `1/4`
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || DomainForgeError::validation(format!("Invalid chunk '{}': expected i/n, e.g. 1/4", s));
+        let (index, count) = s.trim().split_once('/').ok_or_else(invalid)?;
+        let index = index.trim().parse().map_err(|_| invalid())?;
+        let count = count.trim().parse().map_err(|_| invalid())?;
+        Self::new(index, count)
+    }
+}
 
 /// Generator for domain name combinations
 pub struct DomainGenerator {
@@ -8,6 +63,10 @@ pub struct DomainGenerator {
     length: usize,
     current_index: u64,
     total: u64,
+    /// First index of the range this generator covers
+    start: u64,
+    /// Index the generator stops at (exclusive)
+    end: u64,
 }
 
 impl DomainGenerator {
@@ -19,22 +78,46 @@ impl DomainGenerator {
             length,
             current_index: 0,
             total,
+            start: 0,
+            end: total,
         }
     }
 
+    /// Create a generator covering only indices `start..end`, e.g. one of
+    /// the ranges from [`chunks`](Self::chunks)
+    pub fn new_chunk(length: usize, charset: Charset, start: u64, end: u64) -> Self {
+        let mut gen = Self::new(length, charset);
+        gen.end = end.min(gen.total);
+        gen.start = start.min(gen.end);
+        gen.current_index = gen.start;
+        gen
+    }
+
+    /// Split all combinations into `n` near-equal `(start, end)` ranges
+    /// that together cover every index once
+    pub fn chunks(&self, n: usize) -> Vec<(u64, u64)> {
+        let n = n.max(1) as u64;
+        (0..n).map(|i| chunk_bounds(self.total, i, n)).collect()
+    }
+
     /// Get total number of combinations
     pub fn total(&self) -> u64 {
         self.total
     }
 
+    /// Combinations in this generator's range (`total` unless it is a chunk)
+    pub fn range_len(&self) -> u64 {
+        self.end - self.start
+    }
+
     /// Get current progress index
     pub fn current_index(&self) -> u64 {
         self.current_index
     }
 
-    /// Set current index (for resume)
+    /// Set current index (for resume), kept within the generator's range
     pub fn set_index(&mut self, index: u64) {
-        self.current_index = index.min(self.total);
+        self.current_index = index.clamp(self.start, self.end);
     }
 
     /// Generate domain at specific index
@@ -61,6 +144,9 @@ impl DomainGenerator {
         let mut batch = Vec::with_capacity(count);
 
         for _ in 0..count {
+            if self.is_exhausted() {
+                break;
+            }
             if let Some(domain) = self.domain_at(self.current_index) {
                 batch.push(domain);
                 self.current_index += 1;
@@ -74,21 +160,21 @@ impl DomainGenerator {
 
     /// Check if generator is exhausted
     pub fn is_exhausted(&self) -> bool {
-        self.current_index >= self.total
+        self.current_index >= self.end
     }
 
     /// Get progress percentage
     pub fn progress_percent(&self) -> f64 {
-        if self.total == 0 {
+        if self.range_len() == 0 {
             100.0
         } else {
-            (self.current_index as f64 / self.total as f64) * 100.0
+            ((self.current_index - self.start) as f64 / self.range_len() as f64) * 100.0
         }
     }
 
     /// Remaining count
     pub fn remaining(&self) -> u64 {
-        self.total.saturating_sub(self.current_index)
+        self.end.saturating_sub(self.current_index)
     }
 }
 
@@ -96,6 +182,9 @@ impl Iterator for DomainGenerator {
     type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.is_exhausted() {
+            return None;
+        }
         let domain = self.domain_at(self.current_index)?;
         self.current_index += 1;
         Some(domain)
@@ -136,6 +225,37 @@ mod tests {
         assert_eq!(gen.current_index(), 3);
     }
 
+    #[test]
+    fn test_chunks() {
+        let gen = DomainGenerator::new(2, Charset::Letters);
+        let chunks = gen.chunks(3);
+        assert_eq!(chunks, vec![(0, 225), (225, 450), (450, 676)]);
+
+        let mut all = Vec::new();
+        for (start, end) in chunks {
+            all.extend(DomainGenerator::new_chunk(2, Charset::Letters, start, end));
+        }
+        assert_eq!(all, DomainGenerator::new(2, Charset::Letters).collect::<Vec<_>>());
+
+        let mut chunk = DomainGenerator::new_chunk(2, Charset::Letters, 450, 676);
+        assert_eq!(chunk.range_len(), 226);
+        chunk.set_index(0);
+        assert_eq!(chunk.current_index(), 450);
+        assert_eq!(chunk.next_batch(500).len(), 226);
+        assert!(chunk.is_exhausted());
+    }
+
+    #[test]
+    fn test_scan_chunk() {
+        let chunk: ScanChunk = "2/4".parse().unwrap();
+        assert_eq!(chunk, ScanChunk { index: 2, count: 4 });
+        assert_eq!(chunk.to_string(), "2/4");
+        assert_eq!(chunk.range(676), (169, 338));
+        assert!("0/4".parse::<ScanChunk>().is_err());
+        assert!("5/4".parse::<ScanChunk>().is_err());
+        assert!("1-4".parse::<ScanChunk>().is_err());
+    }
+
     #[test]
     fn test_resume() {
         let mut gen = DomainGenerator::new(4, Charset::Letters);
@@ -3,40 +3,82 @@
 //! Phase 1: 4-letter domain scanning (any combination)
 //! Phase 2: 5-letter meaningful word scanning
 
+use crate::error::{DomainForgeError, Result};
+
+mod bloom;
+mod circuit_breaker;
+pub mod control;
+pub mod diff;
+pub mod export;
 mod filter;
 mod generator;
+pub mod notify;
+pub mod pattern;
+pub mod rate_limiter;
 mod readable;
 mod scanner;
 mod six;
 mod state;
-mod words;
+pub mod watchlist;
+pub(crate) mod words;
 
-pub use filter::PronounceableGenerator;
-pub use generator::DomainGenerator;
+pub use bloom::{BloomFilterData, DEFAULT_BLOOM_FPR};
+pub use circuit_breaker::{CircuitBreaker, CircuitState};
+pub use control::ScanControl;
+pub use diff::ScanDiff;
+pub use export::SnipeCsvExporter;
+pub use filter::{pronounceable_score, PronounceableGenerator};
+pub use generator::{DomainGenerator, ScanChunk};
+pub use pattern::PatternGenerator;
+pub use notify::{SmtpConfig, SmtpNotifier, WebhookConfig, WebhookMethod, WebhookNotifier};
+pub use rate_limiter::AdaptiveRateLimiter;
 pub use readable::ReadableGenerator;
-pub use scanner::{recheck_expiring_soon, DomainSniper, RecheckReport, SnipeConfig, SnipeResult, SnipeStatus, ScanMode};
+pub use scanner::{recheck_expiring_soon, DomainSniper, DryRunReport, RecheckReport, SnipeConfig, SnipeConfigBuilder, SnipeResult, SnipeStatus, ScanMode};
 pub use six::SixLetterGenerator;
-pub use state::ScanState;
+pub use state::{ScanState, ScanSummary, TldSummary};
+pub use state::SnipedDomain;
 pub use state::FailedDomain;
+pub use state::SCHEMA_VERSION as STATE_SCHEMA_VERSION;
+pub use watchlist::{StatusChange, WatchedDomain, Watchlist};
 pub use words::WordGenerator;
 
 /// Character set for domain generation
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum Charset {
     /// Only lowercase letters (a-z)
+    #[default]
     Letters,
     /// Letters and digits (a-z, 0-9)
     Alphanumeric,
+    /// User-chosen letters and digits, in scan order (see [`Charset::custom`])
+    Custom(Vec<char>),
 }
 
-impl Default for Charset {
-    fn default() -> Self {
-        Self::Letters
+impl Charset {
+    /// Parse a user character set such as `aeiou` or `0123456789abcdef`.
+    ///
+    /// Input is lowercased and deduplicated; only a-z and 0-9 are allowed,
+    /// since hyphens cannot start or end a label.
+    pub fn custom(chars: &str) -> Result<Self> {
+        let mut set = Vec::new();
+        for c in chars.chars().map(|c| c.to_ascii_lowercase()) {
+            if !c.is_ascii_lowercase() && !c.is_ascii_digit() {
+                return Err(DomainForgeError::validation(format!(
+                    "Invalid character '{}' in charset (only a-z and 0-9 are allowed)",
+                    c
+                )));
+            }
+            if !set.contains(&c) {
+                set.push(c);
+            }
+        }
+        if set.is_empty() {
+            return Err(DomainForgeError::validation("Charset must contain at least one character"));
+        }
+        Ok(Charset::Custom(set))
     }
-}
 
-impl Charset {
-    pub fn chars(&self) -> &'static [char] {
+    pub fn chars(&self) -> &[char] {
         match self {
             Charset::Letters => &[
                 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
@@ -47,6 +89,7 @@ impl Charset {
                 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
                 '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
             ],
+            Charset::Custom(chars) => chars,
         }
     }
 
@@ -54,3 +97,24 @@ impl Charset {
         (self.chars().len() as u64).pow(length as u32)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_charset() {
+        assert_eq!(Charset::Custom(vec!['a', 'b']).total_combinations(3), 8);
+        assert_eq!(Charset::Letters.total_combinations(2), 676);
+
+        let hex = Charset::custom("0123456789ABCDEFabc").unwrap();
+        assert_eq!(hex.chars().len(), 16);
+        assert_eq!(hex.chars()[10], 'a');
+        assert!(Charset::custom("ab-").is_err());
+        assert!(Charset::custom("").is_err());
+
+        let gen = DomainGenerator::new(2, Charset::custom("xy").unwrap());
+        assert_eq!(gen.total(), 4);
+        assert_eq!(gen.domain_at(1).as_deref(), Some("xy"));
+    }
+}
@@ -0,0 +1,345 @@
+//! Notifications for snipe scans
+//!
+//! When SMTP is configured, every batch that turns up available or
+//! expiring-soon domains triggers one email. Webhooks fire once per newly
+//! available domain. Sending happens on spawned tasks so a slow endpoint
+//! never stalls the scan; failures are logged.
+
+use std::collections::HashMap;
+
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{DomainForgeError, Result};
+
+use super::state::SnipedDomain;
+
+/// Default SMTP submission port (STARTTLS)
+pub const DEFAULT_SMTP_PORT: u16 = 587;
+
+/// Port for implicit TLS (SMTPS)
+const SMTPS_PORT: u16 = 465;
+
+/// SMTP server and addresses for scan notifications
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub to_address: String,
+    pub from_address: String,
+}
+
+impl SmtpConfig {
+    /// Read settings from `DF_SMTP_HOST`, `DF_SMTP_PORT`, `DF_SMTP_USER`,
+    /// `DF_SMTP_PASSWORD`, `DF_SMTP_TO` and `DF_SMTP_FROM`.
+    ///
+    /// Returns `None` unless both host and recipient are set. The sender
+    /// defaults to the username, then to the recipient.
+    pub fn from_env() -> Option<Self> {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let var = |key: &str| lookup(key).map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+
+        let host = var("DF_SMTP_HOST")?;
+        let to_address = var("DF_SMTP_TO")?;
+        let port = var("DF_SMTP_PORT")
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(DEFAULT_SMTP_PORT);
+        let username = var("DF_SMTP_USER");
+        let from_address = var("DF_SMTP_FROM")
+            .or_else(|| username.clone())
+            .unwrap_or_else(|| to_address.clone());
+
+        Some(Self {
+            host,
+            port,
+            username,
+            password: var("DF_SMTP_PASSWORD"),
+            to_address,
+            from_address,
+        })
+    }
+}
+
+/// Sends "domains found" emails over SMTP
+#[derive(Clone)]
+pub struct SmtpNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    to: Mailbox,
+}
+
+impl SmtpNotifier {
+    /// Validate the addresses and set up the SMTP transport
+    pub fn new(config: &SmtpConfig) -> Result<Self> {
+        let parse = |address: &str| {
+            address
+                .parse::<Mailbox>()
+                .map_err(|e| DomainForgeError::config(format!("Invalid email address '{}': {}", address, e)))
+        };
+        let from = parse(&config.from_address)?;
+        let to = parse(&config.to_address)?;
+
+        let builder = if config.port == SMTPS_PORT {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+        }
+        .map_err(|e| DomainForgeError::config(format!("Invalid SMTP host '{}': {}", config.host, e)))?
+        .port(config.port);
+
+        let builder = match (&config.username, &config.password) {
+            (Some(user), Some(password)) => builder.credentials(Credentials::new(user.clone(), password.clone())),
+            _ => builder,
+        };
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+            to,
+        })
+    }
+
+    /// Email the given domains in the background; errors are only logged
+    pub fn notify(&self, domains: &[SnipedDomain]) {
+        if domains.is_empty() {
+            return;
+        }
+
+        let message = match self.build_message(domains) {
+            Ok(message) => message,
+            Err(e) => {
+                tracing::warn!("Failed to build notification email: {}", e);
+                return;
+            }
+        };
+
+        let transport = self.transport.clone();
+        let count = domains.len();
+        tokio::spawn(async move {
+            match transport.send(message).await {
+                Ok(_) => tracing::debug!(count, "Sent snipe notification email"),
+                Err(e) => tracing::warn!("Failed to send notification email: {}", e),
+            }
+        });
+    }
+
+    fn build_message(&self, domains: &[SnipedDomain]) -> Result<Message> {
+        Message::builder()
+            .from(self.from.clone())
+            .to(self.to.clone())
+            .subject(subject(domains.len()))
+            .header(ContentType::TEXT_PLAIN)
+            .body(body(domains))
+            .map_err(|e| DomainForgeError::internal(format!("Failed to build email: {}", e)))
+    }
+}
+
+/// Email subject for `count` found domains
+fn subject(count: usize) -> String {
+    format!("Domain Forge: {} available domains found", count)
+}
+
+/// One line per domain, noting expiry for expiring-soon finds
+fn body(domains: &[SnipedDomain]) -> String {
+    let mut body = String::from("The snipe scan found the following domains:\n\n");
+    for domain in domains {
+        match domain.days_until_expiry {
+            Some(days) => body.push_str(&format!("  {} (expires in {} days)\n", domain.full_domain, days)),
+            None => body.push_str(&format!("  {}\n", domain.full_domain)),
+        }
+    }
+    body
+}
+
+/// HTTP method used for webhook calls
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WebhookMethod {
+    #[default]
+    Post,
+    Put,
+}
+
+/// Webhook endpoint for scan notifications (Slack, Discord, ntfy, ...)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    pub url: String,
+    #[serde(default)]
+    pub method: WebhookMethod,
+    /// Extra request headers, e.g. `Authorization`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+    /// Request body replacing the default JSON payload; `{{domain}}` (full
+    /// domain), `{{name}}`, `{{tld}}` and `{{found_at}}` are substituted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_template: Option<String>,
+}
+
+impl WebhookConfig {
+    /// POST the default payload to `url`
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            method: WebhookMethod::default(),
+            headers: HashMap::new(),
+            payload_template: None,
+        }
+    }
+}
+
+/// Calls a webhook for every newly available domain
+#[derive(Clone)]
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    config: WebhookConfig,
+}
+
+impl WebhookNotifier {
+    /// Send webhook calls through an existing client
+    pub fn new(client: reqwest::Client, config: WebhookConfig) -> Self {
+        Self { client, config }
+    }
+
+    /// Call the webhook in the background; errors are only logged
+    pub fn notify(&self, domain: &SnipedDomain) {
+        let mut request = match self.config.method {
+            WebhookMethod::Post => self.client.post(&self.config.url),
+            WebhookMethod::Put => self.client.put(&self.config.url),
+        };
+        if !self.config.headers.keys().any(|name| name.eq_ignore_ascii_case("content-type")) {
+            request = request.header(reqwest::header::CONTENT_TYPE, "application/json");
+        }
+        for (name, value) in &self.config.headers {
+            request = request.header(name, value);
+        }
+        let request = request.body(self.payload(domain));
+
+        let full_domain = domain.full_domain.clone();
+        tokio::spawn(async move {
+            match request.send().await.and_then(|r| r.error_for_status()) {
+                Ok(_) => tracing::debug!(domain = %full_domain, "Sent webhook notification"),
+                Err(e) => tracing::warn!(domain = %full_domain, "Webhook notification failed: {}", e),
+            }
+        });
+    }
+
+    fn payload(&self, domain: &SnipedDomain) -> String {
+        match &self.config.payload_template {
+            Some(template) => render_template(template, domain),
+            None => serde_json::json!({
+                "event": "domain_available",
+                "domain": domain.full_domain,
+                "tld": domain.tld,
+                "found_at": domain.found_at.to_rfc3339(),
+            })
+            .to_string(),
+        }
+    }
+}
+
+/// Substitute `{{placeholder}}`s (whitespace inside the braces is allowed)
+fn render_template(template: &str, domain: &SnipedDomain) -> String {
+    let found_at = domain.found_at.to_rfc3339();
+    let values = [
+        ("domain", domain.full_domain.as_str()),
+        ("full_domain", domain.full_domain.as_str()),
+        ("name", domain.domain.as_str()),
+        ("tld", domain.tld.as_str()),
+        ("found_at", found_at.as_str()),
+    ];
+
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+        let key = rest[start + 2..start + len].trim();
+        match values.iter().find(|(name, _)| *name == key) {
+            Some((_, value)) => out.push_str(value),
+            None => out.push_str(&rest[start..start + len + 2]),
+        }
+        rest = &rest[start + len + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sniped(name: &str, days_until_expiry: Option<i64>) -> SnipedDomain {
+        SnipedDomain { days_until_expiry, ..SnipedDomain::new(name, "com") }
+    }
+
+    #[test]
+    fn test_config_from_lookup() {
+        let vars: HashMap<&str, &str> = [
+            ("DF_SMTP_HOST", "smtp.example.com"),
+            ("DF_SMTP_USER", "bot@example.com"),
+            ("DF_SMTP_PASSWORD", "secret"),
+            ("DF_SMTP_TO", "me@example.com"),
+        ]
+        .into_iter()
+        .collect();
+
+        let config = SmtpConfig::from_lookup(|key| vars.get(key).map(|v| v.to_string())).unwrap();
+        assert_eq!(config.port, DEFAULT_SMTP_PORT);
+        assert_eq!(config.from_address, "bot@example.com");
+        assert_eq!(config.password.as_deref(), Some("secret"));
+
+        assert!(SmtpConfig::from_lookup(|key| (key == "DF_SMTP_HOST").then(|| "smtp.example.com".to_string())).is_none());
+    }
+
+    #[test]
+    fn test_message_lists_domains() {
+        let domains = vec![sniped("abcd", None), sniped("efgh", Some(3))];
+        assert_eq!(subject(domains.len()), "Domain Forge: 2 available domains found");
+
+        let text = body(&domains);
+        assert!(text.contains("  abcd.com\n"));
+        assert!(text.contains("  efgh.com (expires in 3 days)\n"));
+
+        let config = SmtpConfig {
+            host: "smtp.example.com".to_string(),
+            port: DEFAULT_SMTP_PORT,
+            username: None,
+            password: None,
+            to_address: "me@example.com".to_string(),
+            from_address: "bot@example.com".to_string(),
+        };
+        let notifier = SmtpNotifier::new(&config).unwrap();
+        assert!(notifier.build_message(&domains).is_ok());
+
+        let bad = SmtpConfig { to_address: "not an address".to_string(), ..config };
+        assert!(SmtpNotifier::new(&bad).is_err());
+    }
+
+    #[test]
+    fn test_webhook_payload() {
+        let domain = sniped("abcd", None);
+        let notifier = WebhookNotifier::new(reqwest::Client::new(), WebhookConfig::new("https://hooks.example.com/x"));
+        let payload: serde_json::Value = serde_json::from_str(&notifier.payload(&domain)).unwrap();
+        assert_eq!(payload["event"], "domain_available");
+        assert_eq!(payload["domain"], "abcd.com");
+        assert_eq!(payload["tld"], "com");
+        assert!(payload["found_at"].is_string());
+
+        let config = WebhookConfig {
+            payload_template: Some(r#"{"text": "{{ domain }} is free ({{tld}}) {{unknown}} {{"}"#.to_string()),
+            ..WebhookConfig::new("https://hooks.example.com/x")
+        };
+        let notifier = WebhookNotifier::new(reqwest::Client::new(), config);
+        assert_eq!(notifier.payload(&domain), r#"{"text": "abcd.com is free (com) {{unknown}} {{"}"#);
+    }
+}
@@ -0,0 +1,168 @@
+//! Pattern-based domain generator (`?oo?`, `ai??`)
+
+use super::Charset;
+use crate::error::{DomainForgeError, Result};
+
+/// Wildcard matching any character of the charset
+pub const WILDCARD: char = '?';
+
+/// Generator for names matching a pattern of literals and `?` wildcards
+#[derive(Debug, Clone, Default)]
+pub struct PatternGenerator {
+    /// One slot per character: `None` for a wildcard, `Some(c)` for a literal
+    slots: Vec<Option<char>>,
+    charset: Charset,
+    current_index: u64,
+    total: u64,
+}
+
+impl PatternGenerator {
+    /// Parse `pattern`, filling wildcards from `charset`.
+    ///
+    /// Literals may be a-z, 0-9 or `-` (not at either end); a pattern without
+    /// wildcards yields exactly one name.
+    pub fn new(pattern: &str, charset: Charset) -> Result<Self> {
+        let pattern = pattern.trim().to_lowercase();
+        let len = pattern.chars().count();
+        if len == 0 || len > 63 {
+            return Err(DomainForgeError::validation("Pattern must be 1-63 characters long"));
+        }
+        if pattern.starts_with('-') || pattern.ends_with('-') {
+            return Err(DomainForgeError::validation("Pattern cannot start or end with a hyphen"));
+        }
+
+        let slots = pattern
+            .chars()
+            .map(|c| match c {
+                WILDCARD => Ok(None),
+                'a'..='z' | '0'..='9' | '-' => Ok(Some(c)),
+                other => Err(DomainForgeError::validation(format!(
+                    "Invalid character '{}' in pattern (use a-z, 0-9, '-' or '{}')",
+                    other, WILDCARD
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let wildcards = slots.iter().filter(|slot| slot.is_none()).count();
+        let total = (charset.chars().len() as u64)
+            .checked_pow(wildcards as u32)
+            .ok_or_else(|| DomainForgeError::validation("Pattern has too many wildcards"))?;
+
+        Ok(Self {
+            slots,
+            charset,
+            current_index: 0,
+            total,
+        })
+    }
+
+    /// Length of generated names
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Get total number of names
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Get current progress index
+    pub fn current_index(&self) -> u64 {
+        self.current_index
+    }
+
+    /// Set current index (for resume)
+    pub fn set_index(&mut self, index: u64) {
+        self.current_index = index.min(self.total);
+    }
+
+    /// Check if generator is exhausted
+    pub fn is_exhausted(&self) -> bool {
+        self.current_index >= self.total
+    }
+
+    /// Generate name at specific index (last wildcard varies fastest)
+    pub fn domain_at(&self, index: u64) -> Option<String> {
+        if index >= self.total {
+            return None;
+        }
+
+        let chars = self.charset.chars();
+        let base = chars.len() as u64;
+        let mut result = vec![' '; self.slots.len()];
+        let mut n = index;
+
+        for (i, slot) in self.slots.iter().enumerate().rev() {
+            result[i] = match slot {
+                Some(c) => *c,
+                None => {
+                    let c = chars[(n % base) as usize];
+                    n /= base;
+                    c
+                }
+            };
+        }
+
+        Some(result.into_iter().collect())
+    }
+
+    /// Generate next batch of names
+    pub fn next_batch(&mut self, count: usize) -> Vec<String> {
+        let mut batch = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            match self.domain_at(self.current_index) {
+                Some(domain) => {
+                    batch.push(domain);
+                    self.current_index += 1;
+                }
+                None => break,
+            }
+        }
+
+        batch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_generation() {
+        let mut gen = PatternGenerator::new("?oo?", Charset::Letters).unwrap();
+        assert_eq!(gen.total(), 676);
+        assert_eq!(gen.len(), 4);
+        assert_eq!(gen.next_batch(3), vec!["aooa", "aoob", "aooc"]);
+        assert_eq!(gen.domain_at(675).as_deref(), Some("zooz"));
+
+        gen.set_index(675);
+        assert_eq!(gen.next_batch(10), vec!["zooz"]);
+        assert!(gen.is_exhausted());
+
+        let gen = PatternGenerator::new("AI??", Charset::custom("01").unwrap()).unwrap();
+        assert_eq!(gen.total(), 4);
+        assert_eq!(gen.domain_at(2).as_deref(), Some("ai10"));
+    }
+
+    #[test]
+    fn test_pattern_without_wildcards() {
+        let mut gen = PatternGenerator::new("cool", Charset::Letters).unwrap();
+        assert_eq!(gen.total(), 1);
+        assert_eq!(gen.next_batch(10), vec!["cool"]);
+        assert!(gen.is_exhausted());
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        assert!(PatternGenerator::new("", Charset::Letters).is_err());
+        assert!(PatternGenerator::new("-ab?", Charset::Letters).is_err());
+        assert!(PatternGenerator::new("a*b?", Charset::Letters).is_err());
+        assert!(PatternGenerator::new(&"?".repeat(20), Charset::Letters).is_err());
+        assert!(PatternGenerator::new("a-?", Charset::Letters).is_ok());
+    }
+}
@@ -0,0 +1,144 @@
+//! Per-RDAP-server delay between scan batches.
+//!
+//! Registries differ wildly in how much traffic they accept: Verisign serves
+//! `.com` at a rate that would get a scan banned from a small ccTLD server.
+//! Each server gets its own limiter, which backs off when the server answers
+//! `429 Too Many Requests` and speeds up again after a run of successes.
+
+use std::time::{Duration, Instant};
+
+/// Consecutive successes before the delay is halved
+pub const SUCCESSES_TO_SPEED_UP: u32 = 50;
+
+/// Delay after the first 429 when the configured delay is zero
+const MIN_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Upper bound on the delay, however often the server rate-limits
+const MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Adaptive delay for one RDAP server
+#[derive(Debug, Clone)]
+pub struct AdaptiveRateLimiter {
+    delay: Duration,
+    min_delay: Duration,
+    consecutive_successes: u32,
+    requests: u64,
+    rate_limited: u64,
+    /// End of the pause started after the last batch
+    ready_at: Option<Instant>,
+}
+
+impl AdaptiveRateLimiter {
+    /// Start at `base` (`SnipeConfig::rate_limit_ms`); speeding up never goes
+    /// below a quarter of it
+    pub fn new(base: Duration) -> Self {
+        Self {
+            delay: base,
+            min_delay: base / 4,
+            consecutive_successes: 0,
+            requests: 0,
+            rate_limited: 0,
+            ready_at: None,
+        }
+    }
+
+    /// Current delay between batches
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Share of responses that were 429s (0.0 before any response)
+    pub fn rate_limited_ratio(&self) -> f64 {
+        if self.requests == 0 {
+            0.0
+        } else {
+            self.rate_limited as f64 / self.requests as f64
+        }
+    }
+
+    /// Record an HTTP response: 429 doubles the delay, every
+    /// [`SUCCESSES_TO_SPEED_UP`] answers in a row (200 or 404) halve it.
+    /// Other statuses are left to the circuit breaker.
+    pub fn record_response(&mut self, status_code: u16) {
+        self.requests += 1;
+        match status_code {
+            429 => {
+                self.rate_limited += 1;
+                self.consecutive_successes = 0;
+                self.delay = (self.delay * 2).clamp(MIN_BACKOFF, MAX_DELAY);
+            }
+            200 | 404 => {
+                self.consecutive_successes += 1;
+                if self.consecutive_successes >= SUCCESSES_TO_SPEED_UP {
+                    self.consecutive_successes = 0;
+                    self.delay = (self.delay / 2).max(self.min_delay);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Begin the pause before this server's next request
+    pub fn start_pause(&mut self) {
+        self.ready_at = Some(Instant::now() + self.delay);
+    }
+
+    /// Time left in the current pause
+    pub fn wait_time(&self) -> Duration {
+        self.ready_at
+            .map(|ready_at| ready_at.saturating_duration_since(Instant::now()))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backs_off_on_429() {
+        let mut limiter = AdaptiveRateLimiter::new(Duration::from_millis(500));
+        limiter.record_response(429);
+        assert_eq!(limiter.delay(), Duration::from_secs(1));
+        limiter.record_response(429);
+        assert_eq!(limiter.delay(), Duration::from_secs(2));
+        assert_eq!(limiter.rate_limited_ratio(), 1.0);
+
+        for _ in 0..10 {
+            limiter.record_response(429);
+        }
+        assert_eq!(limiter.delay(), MAX_DELAY);
+
+        // A zero delay still backs off
+        let mut limiter = AdaptiveRateLimiter::new(Duration::ZERO);
+        limiter.record_response(429);
+        assert_eq!(limiter.delay(), MIN_BACKOFF);
+    }
+
+    #[test]
+    fn test_speeds_up_after_successes() {
+        let mut limiter = AdaptiveRateLimiter::new(Duration::from_millis(400));
+        for _ in 0..SUCCESSES_TO_SPEED_UP - 1 {
+            limiter.record_response(404);
+        }
+        assert_eq!(limiter.delay(), Duration::from_millis(400));
+        limiter.record_response(200);
+        assert_eq!(limiter.delay(), Duration::from_millis(200));
+
+        // Errors other than 429 neither count nor reset the run
+        limiter.record_response(503);
+        for _ in 0..SUCCESSES_TO_SPEED_UP * 4 {
+            limiter.record_response(200);
+        }
+        assert_eq!(limiter.delay(), Duration::from_millis(100));
+        assert_eq!(limiter.rate_limited_ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_pause() {
+        let mut limiter = AdaptiveRateLimiter::new(Duration::from_secs(10));
+        assert_eq!(limiter.wait_time(), Duration::ZERO);
+        limiter.start_pause();
+        assert!(limiter.wait_time() > Duration::from_secs(9));
+    }
+}
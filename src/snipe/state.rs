@@ -2,13 +2,45 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
 use std::path::Path;
 
+use crate::domain::phonetic::pronounceability;
+use crate::domain::scoring::tld_popularity;
 use crate::error::{DomainForgeError, Result};
+use crate::rdap::status::is_deletion_status;
+use super::bloom::BloomFilterData;
+
+use super::generator::ScanChunk;
+use super::pattern::WILDCARD;
+use super::scanner::ScanMode;
+use super::words::COMMON_WORDS;
+
+/// Version of the state file format written by this release.
+///
+/// Bump it (and add a `migrate_from_vN`) only when old files can no longer
+/// be read as-is; new fields should use `#[serde(default)]` instead.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Weight of the name length in [`SnipedDomain::value_score`]
+pub const VALUE_LENGTH_WEIGHT: f32 = 0.3;
+/// Weight of pronounceability in [`SnipedDomain::value_score`]
+pub const VALUE_PRONOUNCEABILITY_WEIGHT: f32 = 0.2;
+/// Weight of a common English word match in [`SnipedDomain::value_score`]
+pub const VALUE_WORD_WEIGHT: f32 = 0.2;
+/// Weight of the TLD popularity in [`SnipedDomain::value_score`]
+pub const VALUE_TLD_WEIGHT: f32 = 0.2;
+/// Weight of the no-hyphen bonus in [`SnipedDomain::value_score`]
+pub const VALUE_HYPHEN_WEIGHT: f32 = 0.1;
 
 /// Persistent scan state
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScanState {
+    /// State file format version (0 for files written before versioning)
+    #[serde(default)]
+    pub schema_version: u32,
     /// Recheck/update timestamps history (append-only).
     /// This is used by `snipe recheck` to record each update time.
     #[serde(default)]
@@ -17,12 +49,21 @@ pub struct ScanState {
     pub scan_id: String,
     /// Domain length being scanned
     pub length: usize,
+    /// Scan mode (absent in state files from older versions)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<ScanMode>,
+    /// User labels for organizing result files
+    #[serde(default)]
+    pub tags: Vec<String>,
     /// TLDs to scan
     pub tlds: Vec<String>,
     /// Current index in generation sequence
     pub current_index: u64,
     /// Total combinations to scan
     pub total_combinations: u64,
+    /// Chunks (`--chunk i/n`) this state covers; empty for the whole range
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chunks: Vec<ScanChunk>,
     /// Available domains found
     pub available: Vec<SnipedDomain>,
     /// Domains that appear expired (expiration_date <= now) but are not yet available (RDAP still returns 200).
@@ -30,6 +71,11 @@ pub struct ScanState {
     /// These are often high-value to monitor because they may transition to available later.
     #[serde(default)]
     pub expired: Vec<SnipedDomain>,
+    /// Registered domains in the RDAP deletion pipeline (`pendingDelete`, `redemptionPeriod`).
+    ///
+    /// These drop soonest and are the first targets for drop-catching.
+    #[serde(default)]
+    pub pending_delete: Vec<SnipedDomain>,
     /// Domains expiring soon
     pub expiring_soon: Vec<SnipedDomain>,
     /// Failed domain checks with error details
@@ -39,6 +85,14 @@ pub struct ScanState {
     pub checked_count: u64,
     /// Number of errors encountered
     pub error_count: u64,
+    /// Domains (one per name and TLD) left unchecked because the name matched
+    /// a skip pattern; they still count towards `total_combinations`
+    #[serde(default)]
+    pub skipped_count: u64,
+    /// Domains checked so far, for skipping them when the generator and
+    /// `current_index` no longer line up; `None` for very large scans
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checked_bloom: Option<BloomFilterData>,
     /// Scan start time
     pub started_at: DateTime<Utc>,
     /// Last update time
@@ -61,6 +115,150 @@ pub struct SnipedDomain {
     #[serde(default)]
     pub rdap_status: Vec<String>,
     pub found_at: DateTime<Utc>,
+    /// Cached [`SnipedDomain::value_score`]
+    #[serde(default)]
+    pub score: f32,
+    /// Other scanned TLDs where the same name was found available in the
+    /// same batch
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sibling_tlds: Vec<String>,
+}
+
+impl SnipedDomain {
+    /// `name.tld` found now, with no registration details or score yet
+    pub fn new(name: impl Into<String>, tld: impl Into<String>) -> Self {
+        let (domain, tld) = (name.into(), tld.into());
+        Self {
+            full_domain: format!("{}.{}", domain, tld),
+            domain,
+            tld,
+            expiration_date: None,
+            days_until_expiry: None,
+            registrar: None,
+            rdap_status: Vec::new(),
+            found_at: Utc::now(),
+            score: 0.0,
+            sibling_tlds: Vec::new(),
+        }
+    }
+
+    /// Heuristic resale value of the domain, from 0.0 to 1.0.
+    ///
+    /// Four letters score best on length, losing a quarter per letter either
+    /// side. The other signals are pronounceability, an exact
+    /// [`COMMON_WORDS`] match, TLD popularity (`.com` > `.io` > `.ai` >
+    /// `.xyz`) and the absence of hyphens, each worth its `VALUE_*` weight.
+    pub fn value_score(&self) -> f32 {
+        let name = self.domain.to_lowercase();
+        let len = name.chars().count();
+        let length = (1.0 - len.abs_diff(4) as f32 * 0.25).max(0.0);
+        let word = if COMMON_WORDS.contains(&name.as_str()) { 1.0 } else { 0.0 };
+        let hyphen = if name.contains('-') { 0.0 } else { 1.0 };
+
+        length * VALUE_LENGTH_WEIGHT
+            + pronounceability(&name) * VALUE_PRONOUNCEABILITY_WEIGHT
+            + word * VALUE_WORD_WEIGHT
+            + tld_popularity(&self.tld) * VALUE_TLD_WEIGHT
+            + hyphen * VALUE_HYPHEN_WEIGHT
+    }
+
+    /// Whole days until expiry as of now, like
+    /// [`DomainResult::days_until_expiry`](crate::types::DomainResult::days_until_expiry);
+    /// the stored `days_until_expiry` is only a fallback as it dates from the check
+    #[inline]
+    pub fn expires_in_days(&self) -> Option<i64> {
+        self.expiration_date
+            .map(|e| (e - Utc::now()).num_days())
+            .or(self.days_until_expiry)
+    }
+
+    /// Fill in the cached [`SnipedDomain::score`]
+    #[must_use]
+    pub fn scored(mut self) -> Self {
+        self.score = self.value_score();
+        self
+    }
+
+    /// Row for CSV export (see [`crate::domain::export::CSV_HEADER`])
+    pub fn to_csv_row(&self) -> Vec<String> {
+        let status = match self.expiration_date {
+            _ if self.rdap_status.iter().any(|s| is_deletion_status(s)) => "pending_delete",
+            None => "available",
+            Some(exp) if exp <= Utc::now() => "expired",
+            Some(_) => "expiring_soon",
+        };
+
+        vec![
+            self.full_domain.clone(),
+            self.tld.clone(),
+            status.to_string(),
+            self.registrar.clone().unwrap_or_default(),
+            self.expiration_date.map(|d| d.to_rfc3339()).unwrap_or_default(),
+            self.days_until_expiry.map(|d| d.to_string()).unwrap_or_default(),
+            self.found_at.to_rfc3339(),
+        ]
+    }
+}
+
+/// Per-TLD counts in a [`ScanSummary`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TldSummary {
+    pub checked: u64,
+    pub available: usize,
+    pub expiring: usize,
+}
+
+/// Totals of a scan, printed at the end of `snipe`
+#[derive(Debug, Clone)]
+pub struct ScanSummary {
+    pub total_checked: u64,
+    pub total_available: usize,
+    pub total_expiring: usize,
+    pub total_pending_delete: usize,
+    pub total_expired: usize,
+    pub total_errors: u64,
+    pub total_skipped: u64,
+    pub completion_percent: f64,
+    pub elapsed: chrono::Duration,
+    pub domains_per_second: f64,
+    pub per_tld: HashMap<String, TldSummary>,
+}
+
+impl fmt::Display for ScanSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.elapsed.num_seconds().max(0);
+        writeln!(f, "Summary:")?;
+        writeln!(f, "  Checked:     {} ({:.1}%)", self.total_checked, self.completion_percent)?;
+        writeln!(f, "  Available:   {}", self.total_available)?;
+        writeln!(f, "  Pending del: {}", self.total_pending_delete)?;
+        writeln!(f, "  Expiring:    {}", self.total_expiring)?;
+        writeln!(f, "  Expired:     {}", self.total_expired)?;
+        writeln!(f, "  Errors:      {}", self.total_errors)?;
+        if self.total_skipped > 0 {
+            writeln!(f, "  Skipped:     {}", self.total_skipped)?;
+        }
+        write!(
+            f,
+            "  Elapsed:     {}h {:02}m {:02}s ({:.1} domains/s)",
+            secs / 3600,
+            secs % 3600 / 60,
+            secs % 60,
+            self.domains_per_second
+        )?;
+        if self.per_tld.len() > 1 {
+            writeln!(f)?;
+            write!(f, "  Per TLD:")?;
+            let sorted: BTreeMap<_, _> = self.per_tld.iter().collect();
+            for (tld, counts) in sorted {
+                write!(
+                    f,
+                    "\n    .{:<10} {} checked, {} available, {} expiring",
+                    tld, counts.checked, counts.available, counts.expiring
+                )?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// A failed domain check
@@ -78,32 +276,93 @@ impl ScanState {
     pub fn new(length: usize, tlds: Vec<String>, total_combinations: u64) -> Self {
         let now = Utc::now();
         Self {
+            schema_version: SCHEMA_VERSION,
             update_times: Vec::new(),
             scan_id: format!("scan_{}_{}", length, now.format("%Y%m%d_%H%M%S")),
             length,
+            mode: None,
+            tags: Vec::new(),
             tlds,
             current_index: 0,
             total_combinations,
+            chunks: Vec::new(),
             available: Vec::new(),
             expired: Vec::new(),
+            pending_delete: Vec::new(),
             expiring_soon: Vec::new(),
             errors: Vec::new(),
             checked_count: 0,
             error_count: 0,
+            skipped_count: 0,
+            checked_bloom: None,
             started_at: now,
             updated_at: now,
             completed: false,
         }
     }
 
-    /// Load state from file
+    /// Load state from file, upgrading files written by older releases
     pub fn load(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path).map_err(|e| {
             DomainForgeError::io(e.to_string(), Some(path.to_string_lossy().to_string()))
         })?;
 
-        serde_json::from_str(&content).map_err(|e| {
-            DomainForgeError::parse(e.to_string(), Some(content))
+        let raw: serde_json::Value = serde_json::from_str(&content).map_err(|e| {
+            DomainForgeError::parse(e.to_string(), Some(content.clone()))
+        })?;
+        let version = raw.get("schema_version").and_then(|v| v.as_u64()).unwrap_or(0);
+
+        let mut state = match version {
+            0 => {
+                tracing::info!(
+                    path = %path.display(),
+                    from = 0,
+                    to = SCHEMA_VERSION,
+                    "Migrating scan state file"
+                );
+                Self::migrate_from_v0(raw)
+            }
+            v if v == u64::from(SCHEMA_VERSION) => serde_json::from_value(raw)
+                .map_err(|e| DomainForgeError::parse(e.to_string(), Some(content))),
+            v => Err(DomainForgeError::parse(
+                format!(
+                    "State file {} has schema version {}, but this release only reads up to {}. Upgrade domain-forge to resume it.",
+                    path.display(),
+                    v,
+                    SCHEMA_VERSION
+                ),
+                None,
+            )),
+        }?;
+        // Files saved before scoring (or by an older heuristic) get fresh scores
+        state.rescore();
+        state.sort_available_by_score();
+        Ok(state)
+    }
+
+    /// Upgrade a state file written before schema versioning.
+    ///
+    /// Such files may lack the counters and lists added over time; missing
+    /// ones start empty, and `updated_at` falls back to `started_at`.
+    pub fn migrate_from_v0(mut raw: serde_json::Value) -> Result<ScanState> {
+        let object = raw.as_object_mut().ok_or_else(|| {
+            DomainForgeError::parse("Scan state must be a JSON object", None)
+        })?;
+
+        for list in ["available", "expiring_soon"] {
+            object.entry(list).or_insert_with(|| serde_json::json!([]));
+        }
+        for counter in ["current_index", "checked_count", "error_count"] {
+            object.entry(counter).or_insert_with(|| serde_json::json!(0));
+        }
+        object.entry("completed").or_insert(serde_json::Value::Bool(false));
+        if let Some(started_at) = object.get("started_at").cloned() {
+            object.entry("updated_at").or_insert(started_at);
+        }
+        object.insert("schema_version".to_string(), serde_json::json!(SCHEMA_VERSION));
+
+        serde_json::from_value(raw).map_err(|e| {
+            DomainForgeError::parse(format!("Cannot migrate scan state: {}", e), None)
         })
     }
 
@@ -125,11 +384,134 @@ impl ScanState {
         })
     }
 
+    /// Keep only the available domains matching `predicate`
+    pub fn filter_available<F>(&mut self, predicate: F)
+    where
+        F: Fn(&SnipedDomain) -> bool,
+    {
+        self.available.retain(|d| predicate(d));
+    }
+
+    /// Keep only the expiring-soon domains matching `predicate`
+    pub fn filter_expiring<F>(&mut self, predicate: F)
+    where
+        F: Fn(&SnipedDomain) -> bool,
+    {
+        self.expiring_soon.retain(|d| predicate(d));
+    }
+
+    /// Keep only expiring domains whose registrar contains `registrar`
+    /// (case-insensitive). Available domains have no registrar and are kept.
+    pub fn filter_by_registrar(&mut self, registrar: &str) {
+        let wanted = registrar.trim().to_lowercase();
+        self.filter_expiring(|d| {
+            d.registrar
+                .as_deref()
+                .is_some_and(|r| r.to_lowercase().contains(&wanted))
+        });
+    }
+
+    /// Keep only expiring domains at least `days` away from expiry
+    pub fn filter_by_min_days_until_expiry(&mut self, days: i64) {
+        self.filter_expiring(|d| d.days_until_expiry.is_some_and(|left| left >= days));
+    }
+
+    /// Full domains already found available, expiring or expired
+    pub fn known_domains(&self) -> impl Iterator<Item = &str> {
+        self.available
+            .iter()
+            .chain(&self.expiring_soon)
+            .chain(&self.expired)
+            .map(|domain| domain.full_domain.as_str())
+    }
+
+    /// Add tags not already present (trimmed, empty ones skipped)
+    pub fn add_tags(&mut self, tags: &[String]) {
+        for tag in tags {
+            let tag = tag.trim();
+            if !tag.is_empty() && !self.tags.iter().any(|t| t == tag) {
+                self.tags.push(tag.to_string());
+            }
+        }
+    }
+
+    /// Whether the scan carries `tag` (case-insensitive)
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag.trim()))
+    }
+
+    /// Recompute the cached score of every found domain
+    pub fn rescore(&mut self) {
+        for list in [&mut self.available, &mut self.expired, &mut self.pending_delete, &mut self.expiring_soon] {
+            for domain in list.iter_mut() {
+                domain.score = domain.value_score();
+            }
+        }
+    }
+
+    /// Order available domains by score, best first (ties keep found order)
+    pub fn sort_available_by_score(&mut self) {
+        self.available.sort_by(|a, b| b.score.total_cmp(&a.score));
+    }
+
     /// Get default state file path
     pub fn default_path(length: usize) -> std::path::PathBuf {
         std::path::PathBuf::from(format!("output/snipe_{}letter.json", length))
     }
 
+    /// Default state file for one chunk of a split scan
+    pub fn chunk_path(length: usize, chunk: ScanChunk) -> std::path::PathBuf {
+        std::path::PathBuf::from(format!(
+            "output/snipe_{}letter_chunk{}of{}.json",
+            length, chunk.index, chunk.count
+        ))
+    }
+
+    /// Default state file for a scan of `mode`, so scans of different modes
+    /// never share one. A state file of the same mode saved under the older
+    /// [`Self::default_path`] name is still used when there is no newer one.
+    pub fn default_path_for(length: usize, mode: &ScanMode) -> std::path::PathBuf {
+        let path = std::path::PathBuf::from(format!("output/{}.json", Self::path_stem(length, mode)));
+        Self::or_legacy(path, Self::default_path(length), mode)
+    }
+
+    /// [`Self::chunk_path`] for a scan of `mode`
+    pub fn chunk_path_for(length: usize, mode: &ScanMode, chunk: ScanChunk) -> std::path::PathBuf {
+        let path = std::path::PathBuf::from(format!(
+            "output/{}_chunk{}of{}.json",
+            Self::path_stem(length, mode),
+            chunk.index,
+            chunk.count
+        ));
+        Self::or_legacy(path, Self::chunk_path(length, chunk), mode)
+    }
+
+    /// File name stem for a scan; full and 3-letter scans keep the original
+    /// `snipe_{length}letter` name
+    fn path_stem(length: usize, mode: &ScanMode) -> String {
+        match mode {
+            ScanMode::Full | ScanMode::ThreeLetter => format!("snipe_{}letter", length),
+            // `?` is not allowed in Windows file names, and `_` never appears in a pattern
+            ScanMode::Pattern(pattern) => format!(
+                "snipe_{}letter_pattern_{}",
+                length,
+                pattern.trim().to_lowercase().replace(WILDCARD, "_")
+            ),
+            mode => format!("snipe_{}letter_{}", length, mode),
+        }
+    }
+
+    /// `legacy` when `path` does not exist yet and `legacy` holds a scan of `mode`
+    fn or_legacy(path: std::path::PathBuf, legacy: std::path::PathBuf, mode: &ScanMode) -> std::path::PathBuf {
+        if path == legacy || path.exists() {
+            return path;
+        }
+        match Self::load(&legacy) {
+            Ok(state) if state.mode.as_ref() == Some(mode) => legacy,
+            _ => path,
+        }
+    }
+
     /// Add an available domain
     pub fn add_available(&mut self, domain: SnipedDomain) {
         self.available.push(domain);
@@ -142,6 +524,12 @@ impl ScanState {
         self.updated_at = Utc::now();
     }
 
+    /// Add a domain that is pending deletion
+    pub fn add_pending_delete(&mut self, domain: SnipedDomain) {
+        self.pending_delete.push(domain);
+        self.updated_at = Utc::now();
+    }
+
     /// Add a failed domain check
     pub fn add_error(&mut self, failed: FailedDomain) {
         self.errors.push(failed);
@@ -163,6 +551,123 @@ impl ScanState {
         self.updated_at = Utc::now();
     }
 
+    /// Combine the results of another scan of the same length and mode.
+    ///
+    /// Found domains are deduplicated by `full_domain` across all result lists,
+    /// keeping the most recent sighting. Counters are summed, TLDs united and
+    /// `scan_id` is kept from `self`. States without a recorded mode (older
+    /// files) are only checked for matching length. Checked-domain filters
+    /// are united, which fails when both hold domains but differ in size.
+    /// Chunked scans must cover the same chunks or disjoint ones.
+    pub fn merge(&self, other: &ScanState) -> Result<ScanState> {
+        if self.length != other.length {
+            return Err(DomainForgeError::validation(format!(
+                "Cannot merge a {}-letter scan with a {}-letter scan",
+                self.length, other.length
+            )));
+        }
+        if let (Some(ours), Some(theirs)) = (&self.mode, &other.mode) {
+            if ours != theirs {
+                return Err(DomainForgeError::validation(format!(
+                    "Cannot merge a {:?} scan with a {:?} scan",
+                    ours, theirs
+                )));
+            }
+        }
+
+        let mut merged = self.clone();
+        merged.mode = self.mode.clone().or_else(|| other.mode.clone());
+
+        let same_tlds = self.tlds.len() == other.tlds.len() && other.tlds.iter().all(|t| self.tlds.contains(t));
+        for tld in &other.tlds {
+            if !merged.tlds.contains(tld) {
+                merged.tlds.push(tld.clone());
+            }
+        }
+        merged.chunks = merge_chunks(&self.chunks, &other.chunks)?;
+        let separate_chunks = !self.chunks.is_empty()
+            && !other.chunks.is_empty()
+            && self.chunks.iter().all(|chunk| !other.chunks.contains(chunk));
+
+        // Parallel scans over different TLDs or chunks cover separate combinations
+        merged.total_combinations = if same_tlds && !separate_chunks {
+            self.total_combinations.max(other.total_combinations)
+        } else {
+            self.total_combinations + other.total_combinations
+        };
+        merged.current_index = self.current_index.max(other.current_index);
+
+        merged.merge_found(other);
+
+        let retried: HashSet<&str> = other.errors.iter().map(|f| f.full_domain.as_str()).collect();
+        merged.errors.retain(|failed| !retried.contains(failed.full_domain.as_str()));
+        merged.errors.extend(other.errors.iter().cloned());
+
+        merged.checked_count = self.checked_count + other.checked_count;
+        merged.error_count = self.error_count + other.error_count;
+        merged.skipped_count = self.skipped_count + other.skipped_count;
+        merged.checked_bloom = match (&self.checked_bloom, &other.checked_bloom) {
+            (Some(ours), Some(theirs)) if ours.is_empty() => Some(theirs.clone()),
+            (Some(ours), Some(theirs)) if theirs.is_empty() => Some(ours.clone()),
+            (Some(ours), Some(theirs)) => Some(ours.union(theirs).ok_or_else(|| {
+                DomainForgeError::validation(
+                    "Cannot merge scans whose checked-domain filters have different sizes",
+                )
+            })?),
+            (ours, theirs) => ours.clone().or_else(|| theirs.clone()),
+        };
+        merged.started_at = self.started_at.min(other.started_at);
+        merged.updated_at = self.updated_at.max(other.updated_at);
+        merged.update_times.extend(other.update_times.iter().copied());
+        merged.update_times.sort();
+        merged.update_times.dedup();
+        merged.completed = self.completed && other.completed;
+        merged.add_tags(&other.tags);
+
+        Ok(merged)
+    }
+
+    /// Whether this state covers only part of a split scan
+    pub fn is_partial(&self) -> bool {
+        !self.chunks.is_empty()
+    }
+
+    /// Rebuild the found-domain lists from `self` and `other`, one entry per domain
+    fn merge_found(&mut self, other: &ScanState) {
+        let mut order = Vec::new();
+        let mut latest: HashMap<String, (usize, SnipedDomain)> = HashMap::new();
+        for state in [&*self, other] {
+            let lists = [&state.available, &state.expired, &state.pending_delete, &state.expiring_soon];
+            for (list_index, list) in lists.into_iter().enumerate() {
+                for domain in list {
+                    match latest.entry(domain.full_domain.clone()) {
+                        Entry::Vacant(entry) => {
+                            order.push(domain.full_domain.clone());
+                            entry.insert((list_index, domain.clone()));
+                        }
+                        Entry::Occupied(mut entry) => {
+                            if domain.found_at > entry.get().1.found_at {
+                                entry.insert((list_index, domain.clone()));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut lists: [Vec<SnipedDomain>; 4] = Default::default();
+        for full_domain in order {
+            if let Some((list_index, domain)) = latest.remove(&full_domain) {
+                lists[list_index].push(domain);
+            }
+        }
+        let [available, expired, pending_delete, expiring_soon] = lists;
+        self.available = available;
+        self.expired = expired;
+        self.pending_delete = pending_delete;
+        self.expiring_soon = expiring_soon;
+    }
+
     /// Get progress percentage
     pub fn progress_percent(&self) -> f64 {
         if self.total_combinations == 0 {
@@ -172,6 +677,63 @@ impl ScanState {
         }
     }
 
+    /// Available domains grouped by TLD
+    pub fn available_by_tld(&self) -> HashMap<String, Vec<&SnipedDomain>> {
+        group_by(&self.available, |domain| &domain.tld)
+    }
+
+    /// Expiring-soon domains grouped by TLD
+    pub fn expiring_by_tld(&self) -> HashMap<String, Vec<&SnipedDomain>> {
+        group_by(&self.expiring_soon, |domain| &domain.tld)
+    }
+
+    /// Available domains grouped by name, so `cool.com` and `cool.io` sit
+    /// together
+    pub fn available_grouped_by_name(&self) -> HashMap<String, Vec<&SnipedDomain>> {
+        group_by(&self.available, |domain| &domain.domain)
+    }
+
+    /// Expiring-soon domains grouped by name
+    pub fn expiring_grouped_by_name(&self) -> HashMap<String, Vec<&SnipedDomain>> {
+        group_by(&self.expiring_soon, |domain| &domain.domain)
+    }
+
+    /// Totals for reporting.
+    ///
+    /// Every name is checked against every TLD, so each TLD is credited an
+    /// even share of the checked count. Elapsed time runs to the last update.
+    pub fn summary(&self) -> ScanSummary {
+        let elapsed = self.updated_at - self.started_at;
+        let seconds = elapsed.num_milliseconds() as f64 / 1000.0;
+        let checked_per_tld = self.checked_count / self.tlds.len().max(1) as u64;
+
+        let mut per_tld: HashMap<String, TldSummary> = self
+            .tlds
+            .iter()
+            .map(|tld| (tld.clone(), TldSummary { checked: checked_per_tld, ..Default::default() }))
+            .collect();
+        for domain in &self.available {
+            per_tld.entry(domain.tld.clone()).or_default().available += 1;
+        }
+        for domain in &self.expiring_soon {
+            per_tld.entry(domain.tld.clone()).or_default().expiring += 1;
+        }
+
+        ScanSummary {
+            total_checked: self.checked_count,
+            total_available: self.available.len(),
+            total_expiring: self.expiring_soon.len(),
+            total_pending_delete: self.pending_delete.len(),
+            total_expired: self.expired.len(),
+            total_errors: self.error_count,
+            total_skipped: self.skipped_count,
+            completion_percent: self.progress_percent(),
+            elapsed,
+            domains_per_second: if seconds > 0.0 { self.checked_count as f64 / seconds } else { 0.0 },
+            per_tld,
+        }
+    }
+
     /// Get elapsed time
     pub fn elapsed(&self) -> chrono::Duration {
         Utc::now() - self.started_at
@@ -192,6 +754,48 @@ impl ScanState {
     }
 }
 
+/// Group domains by `key`, keeping list order within each group
+fn group_by(domains: &[SnipedDomain], key: impl Fn(&SnipedDomain) -> &String) -> HashMap<String, Vec<&SnipedDomain>> {
+    let mut groups: HashMap<String, Vec<&SnipedDomain>> = HashMap::new();
+    for domain in domains {
+        groups.entry(key(domain).clone()).or_default().push(domain);
+    }
+    groups
+}
+
+/// Chunks covered by two merged states; empty once every chunk is covered.
+/// Fails for chunk sets that only partly overlap.
+fn merge_chunks(ours: &[ScanChunk], theirs: &[ScanChunk]) -> Result<Vec<ScanChunk>> {
+    if ours.is_empty() || theirs.is_empty() {
+        return Ok(Vec::new());
+    }
+    if let Some(chunk) = theirs.iter().find(|chunk| chunk.count != ours[0].count) {
+        return Err(DomainForgeError::validation(format!(
+            "Cannot merge chunk {} with a scan split into {} chunks",
+            chunk, ours[0].count
+        )));
+    }
+
+    // Partly shared chunks would be counted twice in `total_combinations`
+    let shared = theirs.iter().filter(|chunk| ours.contains(chunk)).count();
+    if shared > 0 && (shared != ours.len() || shared != theirs.len()) {
+        let list = |chunks: &[ScanChunk]| chunks.iter().map(ScanChunk::to_string).collect::<Vec<_>>().join(", ");
+        return Err(DomainForgeError::validation(format!(
+            "Cannot merge scans over partly overlapping chunks ({} and {})",
+            list(ours),
+            list(theirs)
+        )));
+    }
+
+    let mut chunks: Vec<ScanChunk> = ours.iter().chain(theirs).copied().collect();
+    chunks.sort();
+    chunks.dedup();
+    if chunks.len() == chunks[0].count {
+        chunks.clear();
+    }
+    Ok(chunks)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,4 +814,318 @@ mod tests {
         state.update_progress(500, 500, 0);
         assert_eq!(state.progress_percent(), 50.0);
     }
+
+    #[test]
+    fn test_load_v0_state_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("old.json");
+        std::fs::write(&path, r#"{
+            "scan_id": "scan_4_20240101_000000",
+            "length": 4,
+            "tlds": ["com"],
+            "current_index": 1200,
+            "total_combinations": 456976,
+            "available": [],
+            "checked_count": 1200,
+            "started_at": "2024-01-01T00:00:00Z"
+        }"#).unwrap();
+
+        let state = ScanState::load(&path).unwrap();
+        assert_eq!(state.schema_version, SCHEMA_VERSION);
+        assert_eq!(state.current_index, 1200);
+        assert!(state.expiring_soon.is_empty());
+        assert_eq!(state.error_count, 0);
+        assert_eq!(state.updated_at, state.started_at);
+        assert!(!state.completed);
+
+        // Saved again in the current format
+        state.save(&path).unwrap();
+        let saved: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved["schema_version"], SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_result_filters() {
+        let expiring = |name: &str, registrar: Option<&str>, days: Option<i64>| SnipedDomain {
+            registrar: registrar.map(str::to_string),
+            days_until_expiry: days,
+            ..sniped(name, "com", 0)
+        };
+        let mut state = ScanState::new(5, vec!["com".to_string()], 100);
+        state.available.push(sniped("alpha", "com", 0));
+        state.available.push(sniped("bravoo", "com", 0));
+        state.expiring_soon.push(expiring("carol", Some("GoDaddy.com, LLC"), Some(5)));
+        state.expiring_soon.push(expiring("delta", Some("NameCheap, Inc."), Some(2)));
+        state.expiring_soon.push(expiring("echos", None, Some(6)));
+        state.expiring_soon.push(expiring("fanta", Some("GoDaddy.com, LLC"), Some(1)));
+
+        state.filter_available(|d| d.domain.len() == 5);
+        assert_eq!(state.available.len(), 1);
+
+        state.filter_by_registrar("godaddy");
+        let names: Vec<_> = state.expiring_soon.iter().map(|d| d.domain.as_str()).collect();
+        assert_eq!(names, ["carol", "fanta"]);
+        assert_eq!(state.available.len(), 1);
+
+        state.filter_by_min_days_until_expiry(3);
+        let names: Vec<_> = state.expiring_soon.iter().map(|d| d.domain.as_str()).collect();
+        assert_eq!(names, ["carol"]);
+    }
+
+    #[test]
+    fn test_value_score() {
+        let total = VALUE_LENGTH_WEIGHT
+            + VALUE_PRONOUNCEABILITY_WEIGHT
+            + VALUE_WORD_WEIGHT
+            + VALUE_TLD_WEIGHT
+            + VALUE_HYPHEN_WEIGHT;
+        assert!((total - 1.0).abs() < 1e-6);
+
+        let score = |name: &str, tld: &str| sniped(name, tld, 0).value_score();
+        assert!(score("book", "com") > score("zqxv", "com"));
+        assert!(score("book", "com") > score("books", "com"));
+        assert!(score("book", "com") > score("book", "io"));
+        assert!(score("book", "ai") > score("book", "xyz"));
+        assert!(score("ab-cd", "com") < score("abcde", "com"));
+
+        let mut state = ScanState::new(4, vec!["com".to_string()], 100);
+        state.available.push(sniped("zqxv", "xyz", 0).scored());
+        state.available.push(sniped("book", "com", 0).scored());
+        state.sort_available_by_score();
+        assert_eq!(state.available[0].full_domain, "book.com");
+        assert!(state.available[0].score > state.available[1].score);
+    }
+
+    #[test]
+    fn test_group_by_tld_and_summary() {
+        let mut state = ScanState::new(4, vec!["com".to_string(), "io".to_string()], 100);
+        state.available.push(sniped("abcd", "com", 0));
+        state.available.push(sniped("abce", "com", 0));
+        state.available.push(sniped("abcd", "io", 0));
+        state.expiring_soon.push(sniped("wxyz", "io", 0));
+        state.update_progress(50, 100, 2);
+
+        let available = state.available_by_tld();
+        assert_eq!(available["com"].len(), 2);
+        assert_eq!(available["io"][0].full_domain, "abcd.io");
+        assert!(!state.expiring_by_tld().contains_key("com"));
+
+        let by_name = state.available_grouped_by_name();
+        assert_eq!(by_name.len(), 2);
+        let tlds: Vec<&str> = by_name["abcd"].iter().map(|d| d.tld.as_str()).collect();
+        assert_eq!(tlds, vec!["com", "io"]);
+        assert_eq!(state.expiring_grouped_by_name()["wxyz"].len(), 1);
+
+        let summary = state.summary();
+        assert_eq!(summary.total_checked, 100);
+        assert_eq!(summary.total_available, 3);
+        assert_eq!(summary.total_errors, 2);
+        assert_eq!(summary.completion_percent, 50.0);
+        assert_eq!(summary.per_tld["com"], TldSummary { checked: 50, available: 2, expiring: 0 });
+        assert_eq!(summary.per_tld["io"], TldSummary { checked: 50, available: 1, expiring: 1 });
+
+        let text = summary.to_string();
+        assert!(text.starts_with("Summary:\n  Checked:     100 (50.0%)"));
+        assert!(text.contains(".com        50 checked, 2 available, 0 expiring"));
+    }
+
+    #[test]
+    fn test_tags() {
+        let mut state = ScanState::new(4, vec!["com".to_string()], 100);
+        state.add_tags(&["q3".to_string(), " brand ".to_string(), String::new()]);
+        state.add_tags(&["q3".to_string()]);
+        assert_eq!(state.tags, vec!["q3", "brand"]);
+        assert!(state.has_tag("BRAND"));
+        assert!(!state.has_tag("client-x"));
+
+        let mut other = state.clone();
+        other.tags = vec!["client-x".to_string(), "q3".to_string()];
+        assert_eq!(state.merge(&other).unwrap().tags, vec!["q3", "brand", "client-x"]);
+    }
+
+    #[test]
+    fn test_load_newer_schema_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("future.json");
+        let mut state = ScanState::new(4, vec!["com".to_string()], 100);
+        state.schema_version = SCHEMA_VERSION + 1;
+        state.save(&path).unwrap();
+
+        let err = ScanState::load(&path).unwrap_err();
+        assert!(err.to_string().contains("Upgrade domain-forge"));
+    }
+
+    fn sniped(name: &str, tld: &str, found_secs_ago: i64) -> SnipedDomain {
+        SnipedDomain {
+            found_at: Utc::now() - chrono::Duration::seconds(found_secs_ago),
+            ..SnipedDomain::new(name, tld)
+        }
+    }
+
+    #[test]
+    fn test_expires_in_days() {
+        let mut domain = sniped("abcd", "com", 10);
+        assert_eq!(domain.expires_in_days(), None);
+
+        // Results saved weeks ago still count down from today
+        domain.days_until_expiry = Some(30);
+        assert_eq!(domain.expires_in_days(), Some(30));
+        domain.expiration_date = Some(Utc::now() + chrono::Duration::days(5) + chrono::Duration::hours(1));
+        assert_eq!(domain.expires_in_days(), Some(5));
+    }
+
+    #[test]
+    fn test_merge_parallel_tld_scans() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut com = ScanState::new(4, vec!["com".to_string()], 100);
+        com.mode = Some(ScanMode::Full);
+        com.available.push(sniped("abcd", "com", 10));
+        com.expiring_soon.push(sniped("efgh", "com", 10));
+        com.update_progress(50, 50, 2);
+
+        let mut io = ScanState::new(4, vec!["io".to_string()], 100);
+        io.available.push(sniped("abcd", "io", 5));
+        io.expired.push(sniped("wxyz", "io", 5));
+        io.update_progress(80, 80, 1);
+
+        let merged = com.merge(&io).unwrap();
+        let path = dir.path().join("merged.json");
+        merged.save(&path).unwrap();
+        let loaded = ScanState::load(&path).unwrap();
+
+        assert_eq!(loaded.scan_id, com.scan_id);
+        assert_eq!(loaded.mode, Some(ScanMode::Full));
+        assert_eq!(loaded.tlds, vec!["com", "io"]);
+        assert_eq!(loaded.total_combinations, 200);
+        assert_eq!(loaded.checked_count, 130);
+        assert_eq!(loaded.error_count, 3);
+        assert_eq!(loaded.updated_at, com.updated_at.max(io.updated_at));
+        let available: Vec<_> = loaded.available.iter().map(|d| d.full_domain.as_str()).collect();
+        assert_eq!(available, vec!["abcd.com", "abcd.io"]);
+        assert_eq!(loaded.expiring_soon.len(), 1);
+        assert_eq!(loaded.expired.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_deduplicates_by_latest_sighting() {
+        let mut first = ScanState::new(5, vec!["com".to_string()], 100);
+        first.available.push(sniped("alpha", "com", 60));
+        first.expiring_soon.push(sniped("bravo", "com", 60));
+
+        let mut second = first.clone();
+        second.scan_id = "other".to_string();
+        second.expiring_soon.clear();
+        second.available.push(sniped("bravo", "com", 1));
+
+        let merged = first.merge(&second).unwrap();
+        let available: Vec<_> = merged.available.iter().map(|d| d.full_domain.as_str()).collect();
+        assert_eq!(available, vec!["alpha.com", "bravo.com"]);
+        assert!(merged.expiring_soon.is_empty());
+        assert_eq!(merged.total_combinations, 100);
+        assert_eq!(merged.scan_id, first.scan_id);
+    }
+
+    #[test]
+    fn test_default_paths_differ_by_mode() {
+        assert_eq!(ScanState::default_path(4), std::path::PathBuf::from("output/snipe_4letter.json"));
+        assert_eq!(ScanState::default_path_for(4, &ScanMode::Full), ScanState::default_path(4));
+        assert_eq!(ScanState::default_path_for(3, &ScanMode::ThreeLetter), ScanState::default_path(3));
+        let chunk = ScanChunk::new(1, 2).unwrap();
+        assert_eq!(
+            ScanState::chunk_path_for(3, &ScanMode::ThreeLetter, chunk),
+            std::path::PathBuf::from("output/snipe_3letter_chunk1of2.json")
+        );
+        assert_eq!(
+            ScanState::default_path_for(4, &ScanMode::Pattern("?OO?".to_string())),
+            std::path::PathBuf::from("output/snipe_4letter_pattern__oo_.json")
+        );
+        assert_ne!(ScanState::default_path_for(5, &ScanMode::Words), ScanState::default_path_for(5, &ScanMode::Readable));
+    }
+
+    #[test]
+    fn test_legacy_state_path_fallback() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snipe_5letter_words.json");
+        let legacy = dir.path().join("snipe_5letter.json");
+        let mut state = ScanState::new(5, vec!["com".to_string()], 100);
+        state.mode = Some(ScanMode::Words);
+        state.save(&legacy).unwrap();
+
+        // Only a scan of the same mode picks up the older file
+        assert_eq!(ScanState::or_legacy(path.clone(), legacy.clone(), &ScanMode::Words), legacy);
+        assert_eq!(ScanState::or_legacy(path.clone(), legacy.clone(), &ScanMode::Readable), path);
+
+        // Once the newer file exists it wins
+        state.save(&path).unwrap();
+        assert_eq!(ScanState::or_legacy(path.clone(), legacy, &ScanMode::Words), path);
+    }
+
+    #[test]
+    fn test_merge_checked_filters() {
+        let with_filter = |total, domain: Option<&str>| {
+            let mut state = ScanState::new(2, vec!["com".to_string()], total);
+            let mut bloom = BloomFilterData::new(total, 0.01);
+            if let Some(domain) = domain {
+                bloom.insert(domain);
+            }
+            state.checked_bloom = Some(bloom);
+            state
+        };
+
+        let merged = with_filter(676, Some("aa.com")).merge(&with_filter(676, Some("ab.com"))).unwrap();
+        let bloom = merged.checked_bloom.unwrap();
+        assert!(bloom.contains("aa.com") && bloom.contains("ab.com"));
+
+        // An empty filter adds nothing, whatever its size
+        let merged = with_filter(676, Some("aa.com")).merge(&with_filter(1352, None)).unwrap();
+        assert!(merged.checked_bloom.unwrap().contains("aa.com"));
+
+        // Filters of different sizes cannot be united, so the merge fails
+        // rather than dropping them
+        assert!(with_filter(676, Some("aa.com")).merge(&with_filter(1352, Some("ab.com"))).is_err());
+    }
+
+    #[test]
+    fn test_merge_chunks() {
+        let chunk = |index| {
+            let mut state = ScanState::new(4, vec!["com".to_string()], 100);
+            state.chunks = vec![ScanChunk::new(index, 3).unwrap()];
+            state
+        };
+        let first_two = chunk(1).merge(&chunk(2)).unwrap();
+        assert_eq!(first_two.chunks.len(), 2);
+        assert!(first_two.is_partial());
+        assert_eq!(first_two.total_combinations, 200);
+
+        let all = first_two.merge(&chunk(3)).unwrap();
+        assert!(!all.is_partial());
+
+        // {1,2} and {2,3} share chunk 2, so no total would be right
+        let last_two = chunk(2).merge(&chunk(3)).unwrap();
+        assert!(first_two.merge(&last_two).is_err());
+        // The same chunk twice (an interrupted run) still merges
+        assert_eq!(chunk(2).merge(&chunk(2)).unwrap().total_combinations, 100);
+        assert_eq!(all.total_combinations, 300);
+
+        // The same chunk scanned twice still covers its range once
+        assert_eq!(chunk(1).merge(&chunk(1)).unwrap().total_combinations, 100);
+
+        let mut other_split = chunk(1);
+        other_split.chunks = vec![ScanChunk::new(1, 4).unwrap()];
+        assert!(matches!(chunk(2).merge(&other_split), Err(DomainForgeError::Validation { .. })));
+    }
+
+    #[test]
+    fn test_merge_rejects_incompatible_scans() {
+        let four = ScanState::new(4, vec!["com".to_string()], 100);
+        let five = ScanState::new(5, vec!["com".to_string()], 100);
+        assert!(matches!(four.merge(&five), Err(DomainForgeError::Validation { .. })));
+
+        let mut full = four.clone();
+        full.mode = Some(ScanMode::Full);
+        let mut pronounceable = four.clone();
+        pronounceable.mode = Some(ScanMode::Pronounceable);
+        assert!(full.merge(&pronounceable).is_err());
+        assert!(full.merge(&four).is_ok());
+    }
 }
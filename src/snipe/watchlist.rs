@@ -0,0 +1,196 @@
+//! Persistent list of domains to recheck without a full snipe scan
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::error::{DomainForgeError, Result};
+use crate::types::{AvailabilityStatus, DomainResult};
+
+/// A domain on the watchlist
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchedDomain {
+    pub full_domain: String,
+    pub added_at: DateTime<Utc>,
+    pub last_checked_at: Option<DateTime<Utc>>,
+    pub last_status: Option<AvailabilityStatus>,
+    #[serde(default)]
+    pub check_count: u32,
+}
+
+/// Outcome of rechecking one watched domain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusChange {
+    pub full_domain: String,
+    /// `None` on the first check
+    pub previous: Option<AvailabilityStatus>,
+    pub current: AvailabilityStatus,
+}
+
+impl StatusChange {
+    pub fn is_changed(&self) -> bool {
+        self.previous != Some(self.current)
+    }
+
+    /// The domain just became registrable
+    pub fn became_available(&self) -> bool {
+        self.current == AvailabilityStatus::Available && self.previous != Some(AvailabilityStatus::Available)
+    }
+}
+
+/// Watched domains, saved as a JSON array
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Watchlist {
+    domains: Vec<WatchedDomain>,
+}
+
+impl Watchlist {
+    /// Default location: `output/watchlist.json`
+    pub fn default_path() -> PathBuf {
+        PathBuf::from("output/watchlist.json")
+    }
+
+    /// Load the watchlist; a missing file is an empty list
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            DomainForgeError::io(e.to_string(), Some(path.to_string_lossy().to_string()))
+        })?;
+
+        serde_json::from_str(&content).map_err(|e| DomainForgeError::parse(e.to_string(), Some(content)))
+    }
+
+    /// Save the watchlist, creating the parent directory if needed
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                DomainForgeError::io(e.to_string(), Some(parent.to_string_lossy().to_string()))
+            })?;
+        }
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| DomainForgeError::internal(format!("Failed to serialize watchlist: {}", e)))?;
+
+        std::fs::write(path, content).map_err(|e| {
+            DomainForgeError::io(e.to_string(), Some(path.to_string_lossy().to_string()))
+        })
+    }
+
+    /// Start watching a domain; returns `false` if it was already watched
+    pub fn add(&mut self, full_domain: &str) -> bool {
+        let full_domain = full_domain.trim().to_lowercase();
+        if self.get(&full_domain).is_some() {
+            return false;
+        }
+        self.domains.push(WatchedDomain {
+            full_domain,
+            added_at: Utc::now(),
+            last_checked_at: None,
+            last_status: None,
+            check_count: 0,
+        });
+        true
+    }
+
+    /// Stop watching a domain; returns `false` if it was not watched
+    pub fn remove(&mut self, full_domain: &str) -> bool {
+        let full_domain = full_domain.trim().to_lowercase();
+        let before = self.domains.len();
+        self.domains.retain(|d| d.full_domain != full_domain);
+        self.domains.len() != before
+    }
+
+    pub fn get(&self, full_domain: &str) -> Option<&WatchedDomain> {
+        self.domains.iter().find(|d| d.full_domain == full_domain)
+    }
+
+    pub fn entries(&self) -> &[WatchedDomain] {
+        &self.domains
+    }
+
+    /// Names of all watched domains, for passing to the checker
+    pub fn domain_names(&self) -> Vec<String> {
+        self.domains.iter().map(|d| d.full_domain.clone()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.domains.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty()
+    }
+
+    /// Store a check result; returns `None` if the domain is not watched
+    pub fn record(&mut self, result: &DomainResult) -> Option<StatusChange> {
+        let full_domain = result.domain.to_lowercase();
+        let entry = self.domains.iter_mut().find(|d| d.full_domain == full_domain)?;
+
+        let previous = entry.last_status.replace(result.status);
+        entry.last_checked_at = Some(result.checked_at);
+        entry.check_count += 1;
+
+        Some(StatusChange {
+            full_domain,
+            previous,
+            current: result.status,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CheckMethod;
+
+    fn result(domain: &str, status: AvailabilityStatus) -> DomainResult {
+        DomainResult::new(domain, status, CheckMethod::Rdap)
+    }
+
+    #[test]
+    fn test_add_remove_and_persist() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("output").join("watchlist.json");
+
+        let mut watchlist = Watchlist::load(&path).unwrap();
+        assert!(watchlist.is_empty());
+        assert!(watchlist.add("Forge.IO"));
+        assert!(!watchlist.add("forge.io"));
+        assert!(watchlist.add("abcd.com"));
+        assert!(watchlist.remove("abcd.com"));
+        assert!(!watchlist.remove("abcd.com"));
+        watchlist.save(&path).unwrap();
+
+        let loaded = Watchlist::load(&path).unwrap();
+        assert_eq!(loaded.domain_names(), vec!["forge.io"]);
+        let raw: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(raw.is_array());
+    }
+
+    #[test]
+    fn test_record_status_changes() {
+        let mut watchlist = Watchlist::default();
+        watchlist.add("forge.io");
+
+        let first = watchlist.record(&result("forge.io", AvailabilityStatus::PendingDelete)).unwrap();
+        assert_eq!(first.previous, None);
+        assert!(!first.became_available());
+
+        let second = watchlist.record(&result("forge.io", AvailabilityStatus::Available)).unwrap();
+        assert_eq!(second.previous, Some(AvailabilityStatus::PendingDelete));
+        assert!(second.is_changed());
+        assert!(second.became_available());
+
+        let third = watchlist.record(&result("forge.io", AvailabilityStatus::Available)).unwrap();
+        assert!(!third.is_changed());
+        assert!(!third.became_available());
+
+        let entry = watchlist.get("forge.io").unwrap();
+        assert_eq!(entry.check_count, 3);
+        assert!(entry.last_checked_at.is_some());
+        assert!(watchlist.record(&result("other.com", AvailabilityStatus::Taken)).is_none());
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! Focuses on valuable, pronounceable, memorable words
 
+use std::collections::HashSet;
+
+use crate::error::{DomainForgeError, Result};
+
 /// Vowels used in pronounceable patterns.
 ///
 /// Keep this to the most common vowels to reduce "weird" combos and keep
@@ -148,6 +152,7 @@ pub const ROOTS_3: &[&str] = &[
 ];
 
 /// Generator for 5-letter meaningful words
+#[derive(Debug, Clone)]
 pub struct WordGenerator {
     words: Vec<String>,
     current_index: usize,
@@ -215,7 +220,7 @@ impl WordGenerator {
     /// Create with custom word list
     pub fn with_words(words: Vec<String>) -> Self {
         let mut words: Vec<String> = words.into_iter()
-            .filter(|w| w.len() == 5 && w.chars().all(|c| c.is_ascii_lowercase()))
+            .filter(|w| is_valid_word(w))
             .collect();
         words.sort();
         words.dedup();
@@ -227,14 +232,68 @@ impl WordGenerator {
     }
 
     /// Load words from file (one word per line)
-    pub fn from_file(path: &std::path::Path) -> std::io::Result<Self> {
-        let content = std::fs::read_to_string(path)?;
-        let words: Vec<String> = content
+    pub fn from_file(path: &std::path::Path) -> Result<Self> {
+        Ok(Self::with_words(Self::read_words(path)?))
+    }
+
+    /// Read a word list file: one word per line, blank lines and `#` comments ignored.
+    /// Words that are not exactly 5 ASCII letters are skipped.
+    pub fn read_words(path: &std::path::Path) -> Result<Vec<String>> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| DomainForgeError::io(e.to_string(), Some(path.display().to_string())))?;
+        let mut words: Vec<String> = content
             .lines()
             .map(|s| s.trim().to_lowercase())
+            .filter(|w| !w.starts_with('#'))
             .filter(|w| w.len() == 5 && w.chars().all(|c| c.is_ascii_lowercase()))
             .collect();
-        Ok(Self::with_words(words))
+        words.sort();
+        words.dedup();
+        Ok(words)
+    }
+
+    /// Add words to scan; anything that is not 5 ASCII letters (after
+    /// lowercasing) or is already in the list is ignored.
+    ///
+    /// Words already handed out stay where they are and the current index is
+    /// unchanged: new words are merged into the not-yet-scanned part of the
+    /// list, which is re-sorted. Before iteration starts this keeps the whole
+    /// list sorted; mid-iteration, a new word that sorts before the current
+    /// position is still scanned, just after the words already scanned. A
+    /// resumed scan must therefore extend the list the same way before
+    /// calling [`set_index`](Self::set_index).
+    pub fn extend(&mut self, words: impl IntoIterator<Item = String>) -> &mut Self {
+        let known: HashSet<String> = self.words.iter().cloned().collect();
+        let mut added: Vec<String> = words
+            .into_iter()
+            .map(|w| w.trim().to_lowercase())
+            .filter(|w| is_valid_word(w) && !known.contains(w))
+            .collect();
+        if added.is_empty() {
+            return self;
+        }
+
+        added.extend(self.words.drain(self.current_index..));
+        added.sort();
+        added.dedup();
+        self.words.extend(added);
+        self
+    }
+
+    /// Drop words from the list (case-insensitive).
+    ///
+    /// The next word handed out is the same as before unless it was removed;
+    /// the current index moves back by the number of removed words that had
+    /// already been scanned.
+    pub fn remove(&mut self, words: &[String]) -> &mut Self {
+        let unwanted: HashSet<String> = words.iter().map(|w| w.trim().to_lowercase()).collect();
+        let scanned_removed = self.words[..self.current_index]
+            .iter()
+            .filter(|w| unwanted.contains(*w))
+            .count();
+        self.words.retain(|w| !unwanted.contains(w));
+        self.current_index -= scanned_removed;
+        self
     }
 
     /// Total number of words
@@ -295,6 +354,11 @@ impl Iterator for WordGenerator {
     }
 }
 
+/// Exactly 5 ASCII lowercase letters
+fn is_valid_word(word: &str) -> bool {
+    word.len() == 5 && word.chars().all(|c| c.is_ascii_lowercase())
+}
+
 fn generate_pronounceable_5_letter() -> Vec<String> {
     let mut out: Vec<String> = Vec::new();
 
@@ -363,4 +427,37 @@ mod tests {
             assert_eq!(word.len(), 5);
         }
     }
+
+    #[test]
+    fn test_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("words.txt");
+        std::fs::write(&path, "# my list\nForge\n\n  cloud \nfour\nsix666\nforge\n").unwrap();
+
+        let gen = WordGenerator::from_file(&path).unwrap();
+        assert_eq!(gen.collect::<Vec<_>>(), vec!["cloud", "forge"]);
+
+        let missing = dir.path().join("missing.txt");
+        let err = WordGenerator::from_file(&missing).err().unwrap();
+        assert!(matches!(err, DomainForgeError::Io { path: Some(p), .. } if p == missing.display().to_string()));
+    }
+
+    #[test]
+    fn test_extend_and_remove() {
+        let words = |list: &[&str]| list.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+        let mut gen = WordGenerator::with_words(words(&["cloud", "forge", "spark"]));
+        gen.extend(words(&["Zebra", "anvil", "forge", "toolong", "abc"]));
+        assert_eq!(gen.clone().collect::<Vec<_>>(), words(&["anvil", "cloud", "forge", "spark", "zebra"]));
+
+        // Mid-iteration: scanned words keep their place, new ones join the rest
+        assert_eq!(gen.next_batch(2), words(&["anvil", "cloud"]));
+        gen.extend(words(&["alpha", "ember"]));
+        assert_eq!(gen.current_index(), 2);
+        assert_eq!(gen.clone().collect::<Vec<_>>(), words(&["alpha", "ember", "forge", "spark", "zebra"]));
+
+        gen.remove(&words(&["cloud", "FORGE"]));
+        assert_eq!(gen.current_index(), 1);
+        assert_eq!(gen.total(), 5);
+        assert_eq!(gen.collect::<Vec<_>>(), words(&["alpha", "ember", "spark", "zebra"]));
+    }
 }
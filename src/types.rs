@@ -2,8 +2,12 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
+use crate::rdap::registry::RdapRegistry;
+
 /// LLM provider type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -35,6 +39,10 @@ pub enum GenerationStyle {
     Descriptive,
     Short,
     Tech,
+    /// Abbreviations and initialisms derived from the description
+    Acronym,
+    /// Two words blended into one (breakfast + lunch = brunch)
+    Portmanteau,
 }
 
 impl std::fmt::Display for GenerationStyle {
@@ -46,6 +54,8 @@ impl std::fmt::Display for GenerationStyle {
             GenerationStyle::Descriptive => write!(f, "descriptive"),
             GenerationStyle::Short => write!(f, "short"),
             GenerationStyle::Tech => write!(f, "tech"),
+            GenerationStyle::Acronym => write!(f, "acronym"),
+            GenerationStyle::Portmanteau => write!(f, "portmanteau"),
         }
     }
 }
@@ -56,6 +66,12 @@ impl std::fmt::Display for GenerationStyle {
 pub enum AvailabilityStatus {
     Available,
     Taken,
+    /// Registered but past expiry and pending deletion (may drop soon)
+    Expired,
+    /// Registered, but in the RDAP deletion pipeline (`pendingDelete`, `redemptionPeriod`)
+    PendingDelete,
+    /// Reserved by IANA/RFC and never registrable
+    Reserved,
     Unknown,
     Error,
 }
@@ -65,6 +81,9 @@ impl std::fmt::Display for AvailabilityStatus {
         match self {
             AvailabilityStatus::Available => write!(f, "available"),
             AvailabilityStatus::Taken => write!(f, "taken"),
+            AvailabilityStatus::Expired => write!(f, "expired"),
+            AvailabilityStatus::PendingDelete => write!(f, "pending delete"),
+            AvailabilityStatus::Reserved => write!(f, "reserved"),
             AvailabilityStatus::Unknown => write!(f, "unknown"),
             AvailabilityStatus::Error => write!(f, "error"),
         }
@@ -77,6 +96,8 @@ impl std::fmt::Display for AvailabilityStatus {
 pub enum CheckMethod {
     Rdap,
     Whois,
+    /// RDAP result with gaps filled in from WHOIS
+    Combined,
     Unknown,
 }
 
@@ -85,6 +106,7 @@ impl std::fmt::Display for CheckMethod {
         match self {
             CheckMethod::Rdap => write!(f, "rdap"),
             CheckMethod::Whois => write!(f, "whois"),
+            CheckMethod::Combined => write!(f, "combined"),
             CheckMethod::Unknown => write!(f, "unknown"),
         }
     }
@@ -106,6 +128,9 @@ pub struct DomainSuggestion {
     pub full_domain: Option<String>,
     /// Generation timestamp
     pub generated_at: DateTime<Utc>,
+    /// How closely the name sounds like `GenerationConfig::target_phonetics` (0.0-1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phonetic_score: Option<f32>,
 }
 
 impl DomainSuggestion {
@@ -121,6 +146,7 @@ impl DomainSuggestion {
             tld,
             full_domain: None,
             generated_at: Utc::now(),
+            phonetic_score: None,
         }
     }
     
@@ -137,6 +163,16 @@ impl DomainSuggestion {
     pub fn get_full_domain(&self) -> String {
         format!("{}.{}", self.name, self.tld)
     }
+
+    /// Composite quality score (0.0-1.0), see [`crate::domain::scoring`]
+    pub fn score(&self) -> f32 {
+        self.score_breakdown().total
+    }
+
+    /// Individual components behind [`score`](Self::score)
+    pub fn score_breakdown(&self) -> crate::domain::ScoreBreakdown {
+        crate::domain::scoring::score_breakdown(self)
+    }
 }
 
 /// Domain availability check result
@@ -152,6 +188,116 @@ pub struct DomainResult {
     pub expiration_date: Option<DateTime<Utc>>,
     pub nameservers: Vec<String>,
     pub error_message: Option<String>,
+    /// Registrant name from RDAP; usually absent because of GDPR redaction
+    #[serde(default)]
+    pub registrant_name: Option<String>,
+    /// Administrative contact (name or email) from RDAP
+    #[serde(default)]
+    pub admin_contact: Option<String>,
+    /// Technical contact (name or email) from RDAP
+    #[serde(default)]
+    pub tech_contact: Option<String>,
+    /// Where to report abuse, from the registrar's RDAP abuse contact
+    #[serde(default)]
+    pub abuse_email: Option<String>,
+}
+
+impl DomainResult {
+    /// Result checked now, with no registration details yet
+    pub fn new(domain: impl Into<String>, status: AvailabilityStatus, method: CheckMethod) -> Self {
+        Self {
+            domain: domain.into(),
+            status,
+            method,
+            checked_at: Utc::now(),
+            check_duration: None,
+            registrar: None,
+            creation_date: None,
+            expiration_date: None,
+            nameservers: Vec::new(),
+            error_message: None,
+            registrant_name: None,
+            admin_contact: None,
+            tech_contact: None,
+            abuse_email: None,
+        }
+    }
+
+    /// Result for a domain that could not be checked at all
+    pub fn error(domain: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            error_message: Some(message.into()),
+            ..Self::new(domain, AvailabilityStatus::Error, CheckMethod::Unknown)
+        }
+    }
+
+    /// Whole days until the registration expires (negative once expired)
+    #[inline]
+    pub fn days_until_expiry(&self) -> Option<i64> {
+        self.expiration_date.map(|e| (e - Utc::now()).num_days())
+    }
+
+    /// Whole days since the domain was registered
+    #[inline]
+    pub fn registration_age_days(&self) -> Option<i64> {
+        self.creation_date.map(|c| (Utc::now() - c).num_days())
+    }
+
+    /// Whether the registration expires in the next `days` days (false once
+    /// expired or without an expiration date)
+    #[inline]
+    pub fn is_expiring_within(&self, days: u32) -> bool {
+        self.days_until_expiry()
+            .is_some_and(|left| (0..=i64::from(days)).contains(&left))
+    }
+
+    /// Merge RDAP and WHOIS results for the same domain.
+    ///
+    /// RDAP is more reliable, so its status and dates win; WHOIS fills in
+    /// the registrar and nameservers (or dates) when RDAP has none.
+    pub fn combine(rdap_result: &DomainResult, whois_result: &DomainResult) -> DomainResult {
+        let check_duration = match (rdap_result.check_duration, whois_result.check_duration) {
+            (Some(rdap), Some(whois)) => Some(rdap + whois),
+            (rdap, whois) => rdap.or(whois),
+        };
+        DomainResult {
+            domain: rdap_result.domain.clone(),
+            status: rdap_result.status,
+            method: CheckMethod::Combined,
+            checked_at: rdap_result.checked_at.max(whois_result.checked_at),
+            check_duration,
+            registrar: rdap_result.registrar.clone().or_else(|| whois_result.registrar.clone()),
+            creation_date: rdap_result.creation_date.or(whois_result.creation_date),
+            expiration_date: rdap_result.expiration_date.or(whois_result.expiration_date),
+            nameservers: if rdap_result.nameservers.is_empty() {
+                whois_result.nameservers.clone()
+            } else {
+                rdap_result.nameservers.clone()
+            },
+            error_message: None,
+            // WHOIS parsing does not extract contacts
+            registrant_name: rdap_result.registrant_name.clone(),
+            admin_contact: rdap_result.admin_contact.clone(),
+            tech_contact: rdap_result.tech_contact.clone(),
+            abuse_email: rdap_result.abuse_email.clone(),
+        }
+    }
+
+    /// Row for CSV export (see [`crate::domain::export::CSV_HEADER`])
+    pub fn to_csv_row(&self) -> Vec<String> {
+        let tld = self.domain.rsplit_once('.').map(|(_, tld)| tld).unwrap_or_default();
+        let days_until_expiry = self.expiration_date.map(|exp| (exp - Utc::now()).num_days());
+
+        vec![
+            self.domain.clone(),
+            tld.to_string(),
+            self.status.to_string(),
+            self.registrar.clone().unwrap_or_default(),
+            self.expiration_date.map(|d| d.to_rfc3339()).unwrap_or_default(),
+            days_until_expiry.map(|d| d.to_string()).unwrap_or_default(),
+            self.checked_at.to_rfc3339(),
+        ]
+    }
 }
 
 /// Combined domain generation and check result
@@ -171,6 +317,59 @@ pub struct GenerationConfig {
     pub temperature: f32,
     pub description: String,
     pub avoid_names: Vec<String>, // Domain names to avoid (without TLD)
+    /// Replaces the built-in system message verbatim
+    pub system_prompt_template: Option<String>,
+    /// Extra instructions appended to the user prompt
+    pub user_prompt_suffix: Option<String>,
+    /// How often to re-prompt when the response is not valid JSON
+    #[serde(default = "default_parse_retry_attempts")]
+    pub parse_retry_attempts: u8,
+    /// Minimum characters in the name part (before the TLD)
+    #[serde(default)]
+    pub min_name_length: Option<usize>,
+    /// Maximum characters in the name part (before the TLD)
+    #[serde(default)]
+    pub max_name_length: Option<usize>,
+    /// Keyword(s) suggestions are scored against for sound-alike similarity
+    #[serde(default)]
+    pub target_phonetics: Option<String>,
+    /// Drop suggestions containing blocked words
+    #[serde(default)]
+    pub content_filter: Option<crate::domain::ContentFilter>,
+    /// Accept responses with code fences, comments or trailing commas
+    /// (disable to check that a prompt yields strict JSON)
+    #[serde(default = "default_lenient_parsing")]
+    pub lenient_parsing: bool,
+    /// Description → good domain pairs shown to the LLM as few-shot examples
+    #[serde(default = "default_examples")]
+    pub examples: Vec<(String, String)>,
+    /// Names the LLM is shown as what not to suggest
+    #[serde(default)]
+    pub negative_examples: Vec<String>,
+    /// Have the LLM score and re-rank suggestions in a second call
+    /// (doubles API calls and cost)
+    #[serde(default)]
+    pub use_llm_scoring: bool,
+    /// Sampling seed for approximately repeatable output. Sent by the
+    /// OpenAI-compatible providers together with this config's
+    /// `temperature`; a no-op for Anthropic, Gemini and Ollama.
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+fn default_parse_retry_attempts() -> u8 {
+    2
+}
+
+fn default_lenient_parsing() -> bool {
+    true
+}
+
+fn default_examples() -> Vec<(String, String)> {
+    crate::llm::providers::DEFAULT_EXAMPLES
+        .iter()
+        .map(|(description, domain)| (description.to_string(), domain.to_string()))
+        .collect()
 }
 
 impl Default for GenerationConfig {
@@ -183,6 +382,220 @@ impl Default for GenerationConfig {
             temperature: 0.7,
             description: "".to_string(),
             avoid_names: Vec::new(),
+            system_prompt_template: None,
+            user_prompt_suffix: None,
+            parse_retry_attempts: default_parse_retry_attempts(),
+            min_name_length: None,
+            max_name_length: None,
+            target_phonetics: None,
+            content_filter: None,
+            lenient_parsing: default_lenient_parsing(),
+            examples: default_examples(),
+            negative_examples: Vec::new(),
+            use_llm_scoring: false,
+            seed: None,
+        }
+    }
+}
+
+impl GenerationConfig {
+    /// Start building a generation config from defaults
+    pub fn builder() -> GenerationConfigBuilder {
+        GenerationConfigBuilder::default()
+    }
+}
+
+/// Fluent builder for [`GenerationConfig`]
+#[derive(Debug, Clone, Default)]
+pub struct GenerationConfigBuilder {
+    config: GenerationConfig,
+    /// `None` until [`tlds`](Self::tlds) is called
+    tlds: Option<Vec<String>>,
+}
+
+impl GenerationConfigBuilder {
+    #[must_use]
+    pub fn provider(mut self, provider: LlmProvider) -> Self {
+        self.config.provider = provider;
+        self
+    }
+
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.config.description = description.into();
+        self
+    }
+
+    #[must_use]
+    pub fn count(mut self, count: usize) -> Self {
+        self.config.count = count;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: GenerationStyle) -> Self {
+        self.config.style = style;
+        self
+    }
+
+    #[must_use]
+    pub fn tlds<I, S>(mut self, tlds: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tlds = Some(tlds.into_iter().map(Into::into).collect());
+        self
+    }
+
+    #[must_use]
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.config.temperature = temperature;
+        self
+    }
+
+    #[must_use]
+    pub fn avoid_names<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.config.avoid_names = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    #[must_use]
+    pub fn system_prompt_template(mut self, template: impl Into<String>) -> Self {
+        self.config.system_prompt_template = Some(template.into());
+        self
+    }
+
+    #[must_use]
+    pub fn user_prompt_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.config.user_prompt_suffix = Some(suffix.into());
+        self
+    }
+
+    #[must_use]
+    pub fn parse_retry_attempts(mut self, attempts: u8) -> Self {
+        self.config.parse_retry_attempts = attempts;
+        self
+    }
+
+    #[must_use]
+    pub fn content_filter(mut self, filter: crate::domain::ContentFilter) -> Self {
+        self.config.content_filter = Some(filter);
+        self
+    }
+
+    #[must_use]
+    pub fn target_phonetics(mut self, target: impl Into<String>) -> Self {
+        self.config.target_phonetics = Some(target.into());
+        self
+    }
+
+    #[must_use]
+    pub fn min_name_length(mut self, length: usize) -> Self {
+        self.config.min_name_length = Some(length);
+        self
+    }
+
+    #[must_use]
+    pub fn max_name_length(mut self, length: usize) -> Self {
+        self.config.max_name_length = Some(length);
+        self
+    }
+
+    /// Replace the few-shot examples (an empty list sends none)
+    #[must_use]
+    pub fn examples<I, D, N>(mut self, examples: I) -> Self
+    where
+        I: IntoIterator<Item = (D, N)>,
+        D: Into<String>,
+        N: Into<String>,
+    {
+        self.config.examples = examples
+            .into_iter()
+            .map(|(description, domain)| (description.into(), domain.into()))
+            .collect();
+        self
+    }
+
+    #[must_use]
+    pub fn negative_examples<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.config.negative_examples = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    #[must_use]
+    pub fn use_llm_scoring(mut self, enabled: bool) -> Self {
+        self.config.use_llm_scoring = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    /// Seed the request and sample at temperature 0.0, for output that is
+    /// as repeatable as the provider allows
+    #[must_use]
+    pub fn deterministic(self, seed: u64) -> Self {
+        self.seed(seed).temperature(0.0)
+    }
+
+    #[must_use]
+    pub fn lenient_parsing(mut self, lenient: bool) -> Self {
+        self.config.lenient_parsing = lenient;
+        self
+    }
+
+    /// Validate and return the config; `description` is required.
+    ///
+    /// Without TLDs (or with just `auto`) they are recommended from the description.
+    pub fn build(mut self) -> crate::error::Result<GenerationConfig> {
+        if self.config.description.trim().is_empty() {
+            return Err(crate::error::DomainForgeError::config("Generation description is required"));
+        }
+        if self.config.count == 0 {
+            return Err(crate::error::DomainForgeError::config("Generation count must be at least 1"));
+        }
+        self.config.tlds = match self.tlds {
+            Some(tlds) if !crate::llm::tld_recommender::is_auto(&tlds) => tlds,
+            _ => crate::llm::tld_recommender::recommend_tlds(&self.config.description),
+        };
+        if let (Some(min), Some(max)) = (self.config.min_name_length, self.config.max_name_length) {
+            if min > max {
+                return Err(crate::error::DomainForgeError::config(format!(
+                    "Minimum name length ({}) exceeds maximum ({})",
+                    min, max
+                )));
+            }
+        }
+        Ok(self.config)
+    }
+}
+
+/// Rate limits for batched WHOIS lookups
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WhoisConfig {
+    /// Pause between consecutive queries on one connection to a server (ms)
+    pub per_server_rate_limit_ms: u64,
+    /// Queries in flight at once against a single WHOIS server
+    pub max_connections_per_server: usize,
+}
+
+impl Default for WhoisConfig {
+    fn default() -> Self {
+        Self {
+            per_server_rate_limit_ms: 500,
+            max_connections_per_server: 2,
         }
     }
 }
@@ -199,6 +612,36 @@ pub struct CheckConfig {
     pub rate_limit: u32,
     /// Connection pool size for HTTP clients
     pub connection_pool_size: usize,
+    /// Pool sizes for specific TLDs, overriding `connection_pool_size` for
+    /// their RDAP servers (e.g. a small pool for a strict registry)
+    pub per_tld_pool_size: HashMap<String, usize>,
+    /// Error rate over recent checks above which concurrency is halved
+    pub adaptive_error_threshold: f64,
+    /// Error rate below which concurrency recovers towards `concurrent_checks`
+    pub adaptive_recovery_threshold: f64,
+    /// How long check results are reused before re-querying (`None` disables caching)
+    pub cache_ttl: Option<Duration>,
+    /// Maximum number of cached check results
+    pub cache_max_entries: usize,
+    /// Names treated as reserved in addition to the IANA/RFC list
+    /// (`.tld`, `name.tld`, or a bare label)
+    pub extra_reserved_names: Vec<String>,
+    /// HTTP(S) proxy URL, e.g. `http://proxy.corp.example.com:8080`
+    /// (falls back to `HTTPS_PROXY` / `HTTP_PROXY` when unset)
+    pub proxy: Option<String>,
+    pub proxy_username: Option<String>,
+    pub proxy_password: Option<String>,
+    /// User agent for RDAP queries; `None` sends
+    /// [`default_user_agent`](crate::rdap::default_user_agent), `domain-forge/<version>`
+    pub user_agent: Option<String>,
+    /// Headers added to every RDAP query, e.g. an `Authorization` token for a
+    /// private RDAP server
+    pub extra_headers: HashMap<String, String>,
+    /// RDAP servers to use instead of the built-in table
+    #[serde(skip)]
+    pub rdap_registry: Option<Arc<RdapRegistry>>,
+    /// Rate limits for WHOIS fallback in batch checks
+    pub whois: WhoisConfig,
 }
 
 impl Default for CheckConfig {
@@ -212,10 +655,192 @@ impl Default for CheckConfig {
             retry_attempts: 3,
             rate_limit: 60,
             connection_pool_size: 10,
+            per_tld_pool_size: HashMap::new(),
+            adaptive_error_threshold: 0.2,
+            adaptive_recovery_threshold: 0.05,
+            cache_ttl: None,
+            cache_max_entries: 10_000,
+            extra_reserved_names: Vec::new(),
+            proxy: None,
+            proxy_username: None,
+            proxy_password: None,
+            user_agent: None,
+            extra_headers: HashMap::new(),
+            rdap_registry: None,
+            whois: WhoisConfig::default(),
         }
     }
 }
 
+impl CheckConfig {
+    /// Start building a check config from defaults
+    pub fn builder() -> CheckConfigBuilder {
+        CheckConfigBuilder::default()
+    }
+
+    /// User agent sent with RDAP queries
+    pub fn rdap_user_agent(&self) -> String {
+        self.user_agent.clone().unwrap_or_else(crate::rdap::default_user_agent)
+    }
+}
+
+/// Fluent builder for [`CheckConfig`]
+#[derive(Debug, Clone, Default)]
+pub struct CheckConfigBuilder {
+    config: CheckConfig,
+}
+
+impl CheckConfigBuilder {
+    #[must_use]
+    pub fn concurrent_checks(mut self, concurrent_checks: usize) -> Self {
+        self.config.concurrent_checks = concurrent_checks;
+        self
+    }
+
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub fn enable_rdap(mut self, enable: bool) -> Self {
+        self.config.enable_rdap = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn enable_whois(mut self, enable: bool) -> Self {
+        self.config.enable_whois = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn detailed_info(mut self, detailed: bool) -> Self {
+        self.config.detailed_info = detailed;
+        self
+    }
+
+    #[must_use]
+    pub fn retry_attempts(mut self, attempts: usize) -> Self {
+        self.config.retry_attempts = attempts;
+        self
+    }
+
+    #[must_use]
+    pub fn rate_limit(mut self, rate_limit: u32) -> Self {
+        self.config.rate_limit = rate_limit;
+        self
+    }
+
+    #[must_use]
+    pub fn connection_pool_size(mut self, size: usize) -> Self {
+        self.config.connection_pool_size = size;
+        self
+    }
+
+    #[must_use]
+    pub fn per_tld_pool_size(mut self, tld: impl Into<String>, size: usize) -> Self {
+        self.config.per_tld_pool_size.insert(tld.into(), size);
+        self
+    }
+
+    #[must_use]
+    pub fn adaptive_thresholds(mut self, error: f64, recovery: f64) -> Self {
+        self.config.adaptive_error_threshold = error;
+        self.config.adaptive_recovery_threshold = recovery;
+        self
+    }
+
+    #[must_use]
+    pub fn cache_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.config.cache_ttl = ttl;
+        self
+    }
+
+    #[must_use]
+    pub fn cache_max_entries(mut self, max_entries: usize) -> Self {
+        self.config.cache_max_entries = max_entries;
+        self
+    }
+
+    #[must_use]
+    pub fn extra_reserved_names<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.config.extra_reserved_names = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    #[must_use]
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.config.proxy = Some(url.into());
+        self
+    }
+
+    #[must_use]
+    pub fn proxy_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.config.proxy_username = Some(username.into());
+        self.config.proxy_password = Some(password.into());
+        self
+    }
+
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.config.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Add a header sent with every RDAP query
+    #[must_use]
+    pub fn extra_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.extra_headers.insert(name.into(), value.into());
+        self
+    }
+
+    #[must_use]
+    pub fn rdap_registry(mut self, registry: Arc<RdapRegistry>) -> Self {
+        self.config.rdap_registry = Some(registry);
+        self
+    }
+
+    #[must_use]
+    pub fn whois(mut self, whois: WhoisConfig) -> Self {
+        self.config.whois = whois;
+        self
+    }
+
+    /// Validate and return the config
+    pub fn build(self) -> crate::error::Result<CheckConfig> {
+        let config = self.config;
+        if config.concurrent_checks == 0 {
+            return Err(crate::error::DomainForgeError::config("concurrent_checks must be at least 1"));
+        }
+        if !config.enable_rdap && !config.enable_whois {
+            return Err(crate::error::DomainForgeError::config("At least one of RDAP or WHOIS must be enabled"));
+        }
+        if config.adaptive_recovery_threshold > config.adaptive_error_threshold {
+            return Err(crate::error::DomainForgeError::config(
+                "adaptive recovery threshold must not exceed the error threshold",
+            ));
+        }
+        if config.whois.max_connections_per_server == 0 {
+            return Err(crate::error::DomainForgeError::config(
+                "max_connections_per_server must be at least 1",
+            ));
+        }
+        if let Some(url) = &config.proxy {
+            reqwest::Proxy::all(url.as_str()).map_err(|e| {
+                crate::error::DomainForgeError::config(format!("Invalid proxy URL '{}': {}", url, e))
+            })?;
+        }
+        crate::rdap::validate_headers(&config.rdap_user_agent(), &config.extra_headers)?;
+        Ok(config)
+    }
+}
+
 /// LLM configuration
 #[derive(Debug, Clone)]
 pub struct LlmConfig {
@@ -224,6 +849,12 @@ pub struct LlmConfig {
     pub api_key: String,
     pub base_url: Option<String>,
     pub temperature: f32,
+    /// Use JSON-schema structured output where the provider and model
+    /// support it (OpenAI's gpt-4o and newer, Anthropic tool use)
+    pub use_structured_output: bool,
+    /// Response token limit for generation, replacing the provider default
+    /// and its scaling for large counts
+    pub max_tokens: Option<u32>,
 }
 
 impl Default for LlmConfig {
@@ -234,6 +865,24 @@ impl Default for LlmConfig {
             api_key: String::new(),
             base_url: None,
             temperature: 0.7,
+            use_structured_output: true,
+            max_tokens: None,
+        }
+    }
+}
+
+impl LlmConfig {
+    /// API key safe to print: only its first and last three characters
+    pub fn redacted_api_key(&self) -> String {
+        let chars: Vec<char> = self.api_key.chars().collect();
+        match chars.len() {
+            0 => "(none)".to_string(),
+            len if len <= 8 => "*".repeat(len),
+            len => format!(
+                "{}...{}",
+                chars[..3].iter().collect::<String>(),
+                chars[len - 3..].iter().collect::<String>()
+            ),
         }
     }
 }
@@ -246,6 +895,12 @@ pub struct PerformanceMetrics {
     pub api_calls_made: std::sync::atomic::AtomicU64,
     pub errors_encountered: std::sync::atomic::AtomicU64,
     pub total_check_time_ms: std::sync::atomic::AtomicU64,
+    /// Effective concurrency limit of the domain checker
+    pub current_concurrency: std::sync::atomic::AtomicUsize,
+    pub total_prompt_tokens: std::sync::atomic::AtomicU64,
+    pub total_completion_tokens: std::sync::atomic::AtomicU64,
+    /// Estimated LLM spend in microdollars (USD * 1e6)
+    pub total_llm_cost_estimate_usd: std::sync::atomic::AtomicU64,
 }
 
 impl PerformanceMetrics {
@@ -272,6 +927,22 @@ impl PerformanceMetrics {
     pub fn add_check_time(&self, milliseconds: u64) {
         self.total_check_time_ms.fetch_add(milliseconds, std::sync::atomic::Ordering::Relaxed);
     }
+
+    pub fn set_current_concurrency(&self, limit: usize) {
+        self.current_concurrency.store(limit, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    pub fn add_prompt_tokens(&self, tokens: u64) {
+        self.total_prompt_tokens.fetch_add(tokens, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    pub fn add_completion_tokens(&self, tokens: u64) {
+        self.total_completion_tokens.fetch_add(tokens, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    pub fn add_llm_cost_microdollars(&self, microdollars: u64) {
+        self.total_llm_cost_estimate_usd.fetch_add(microdollars, std::sync::atomic::Ordering::Relaxed);
+    }
     
     pub fn get_stats(&self) -> MetricsSnapshot {
         MetricsSnapshot {
@@ -280,6 +951,11 @@ impl PerformanceMetrics {
             api_calls_made: self.api_calls_made.load(std::sync::atomic::Ordering::Relaxed),
             errors_encountered: self.errors_encountered.load(std::sync::atomic::Ordering::Relaxed),
             total_check_time_ms: self.total_check_time_ms.load(std::sync::atomic::Ordering::Relaxed),
+            current_concurrency: self.current_concurrency.load(std::sync::atomic::Ordering::Relaxed),
+            total_tokens_used: self.total_prompt_tokens.load(std::sync::atomic::Ordering::Relaxed)
+                + self.total_completion_tokens.load(std::sync::atomic::Ordering::Relaxed),
+            estimated_cost_usd: self.total_llm_cost_estimate_usd.load(std::sync::atomic::Ordering::Relaxed) as f64
+                / 1_000_000.0,
         }
     }
 }
@@ -291,6 +967,9 @@ pub struct MetricsSnapshot {
     pub api_calls_made: u64,
     pub errors_encountered: u64,
     pub total_check_time_ms: u64,
+    pub current_concurrency: usize,
+    pub total_tokens_used: u64,
+    pub estimated_cost_usd: f64,
 }
 
 impl MetricsSnapshot {
@@ -301,28 +980,150 @@ impl MetricsSnapshot {
             self.total_check_time_ms as f64 / self.domains_checked as f64
         }
     }
+
+    /// Errors per domain checked (0.0 before any check)
+    pub fn error_rate(&self) -> f64 {
+        if self.domains_checked == 0 {
+            0.0
+        } else {
+            self.errors_encountered as f64 / self.domains_checked as f64
+        }
+    }
+
+    /// Domains checked per second over `elapsed` (0.0 for a zero duration)
+    pub fn domains_per_second(&self, elapsed: Duration) -> f64 {
+        let secs = elapsed.as_secs_f64();
+        if secs == 0.0 {
+            0.0
+        } else {
+            self.domains_checked as f64 / secs
+        }
+    }
+
+    /// Sum of two snapshots, e.g. a generator's and its checker's
+    pub fn combined(&self, other: &Self) -> Self {
+        Self {
+            domains_generated: self.domains_generated + other.domains_generated,
+            domains_checked: self.domains_checked + other.domains_checked,
+            api_calls_made: self.api_calls_made + other.api_calls_made,
+            errors_encountered: self.errors_encountered + other.errors_encountered,
+            total_check_time_ms: self.total_check_time_ms + other.total_check_time_ms,
+            current_concurrency: self.current_concurrency.max(other.current_concurrency),
+            total_tokens_used: self.total_tokens_used + other.total_tokens_used,
+            estimated_cost_usd: self.estimated_cost_usd + other.estimated_cost_usd,
+        }
+    }
+
+    /// Detailed multi-line table for a run that took `elapsed`
+    pub fn table(&self, elapsed: Duration) -> String {
+        let rows = [
+            ("Domains generated", group_thousands(self.domains_generated)),
+            ("Domains checked", group_thousands(self.domains_checked)),
+            ("Errors", format!("{} ({:.2}%)", group_thousands(self.errors_encountered), self.error_rate() * 100.0)),
+            ("Avg check time", format!("{:.0}ms", self.avg_check_time_ms())),
+            ("Throughput", format!("{:.1} domains/s", self.domains_per_second(elapsed))),
+            ("Concurrency", self.current_concurrency.to_string()),
+            ("API calls", group_thousands(self.api_calls_made)),
+            ("Tokens used", group_thousands(self.total_tokens_used)),
+            ("Est. API cost", format!("${:.4}", self.estimated_cost_usd)),
+            ("Elapsed", format!("{:.1}s", elapsed.as_secs_f64())),
+        ];
+        let mut table = String::from("Metrics\n");
+        for (label, value) in rows {
+            table.push_str(&format!("  {:<19}{}\n", format!("{}:", label), value));
+        }
+        table
+    }
+}
+
+impl std::fmt::Display for MetricsSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Domains checked: {} | Errors: {} ({:.2}%) | Avg check time: {:.0}ms | API calls: {} | Tokens used: {}",
+            group_thousands(self.domains_checked),
+            group_thousands(self.errors_encountered),
+            self.error_rate() * 100.0,
+            self.avg_check_time_ms(),
+            group_thousands(self.api_calls_made),
+            group_thousands(self.total_tokens_used),
+        )
+    }
+}
+
+/// `12450` as `12,450`
+fn group_thousands(n: u64) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
 }
 
 /// Multi-round domain generation session
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainSession {
+    /// What the domains were generated for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     pub available_domains: Vec<DomainSuggestion>,
     pub taken_domains: std::collections::HashSet<String>,
     pub error_domains: Vec<(String, String)>, // domain, error_message
     pub round_count: u32,
+    /// Serialized as fractional seconds
+    #[serde(with = "duration_secs")]
     pub total_time: Duration,
     pub total_generated: u32,
+    /// Per-round breakdown, oldest first
+    #[serde(default)]
+    pub rounds: Vec<RoundResult>,
+    /// Estimated LLM spend across all runs of this session
+    #[serde(default)]
+    pub api_cost_usd: f64,
+}
+
+/// Domains found in a single generation round
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoundResult {
+    pub round_number: u32,
+    pub domains_checked: usize,
+    pub available: Vec<String>,
+    pub taken: Vec<String>,
+    pub errors: Vec<String>,
+    /// Serialized as fractional seconds
+    #[serde(with = "duration_secs")]
+    pub duration: Duration,
+}
+
+/// Summary of a whole session, see [`DomainSession::statistics`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionStatistics {
+    pub total_rounds: u32,
+    pub total_checked: u32,
+    pub total_available: usize,
+    pub total_taken: usize,
+    /// Round number with the most available domains (earliest on ties)
+    pub best_round: Option<u32>,
+    pub avg_available_per_round: f64,
+    pub total_api_cost_estimate_usd: f64,
 }
 
 impl DomainSession {
     pub fn new() -> Self {
         Self {
+            description: None,
             available_domains: Vec::new(),
             taken_domains: std::collections::HashSet::new(),
             error_domains: Vec::new(),
             round_count: 0,
             total_time: Duration::from_secs(0),
             total_generated: 0,
+            rounds: Vec::new(),
+            api_cost_usd: 0.0,
         }
     }
     
@@ -330,23 +1131,74 @@ impl DomainSession {
         self.round_count += 1;
         self.total_time += round_time;
         self.total_generated += domains.len() as u32;
+
+        let mut round = RoundResult {
+            round_number: self.round_count,
+            domains_checked: 0,
+            available: Vec::new(),
+            taken: Vec::new(),
+            errors: Vec::new(),
+            duration: round_time,
+        };
         
         for (domain, result) in domains.iter().zip(results.iter()) {
+            let full_domain = domain.get_full_domain();
+            round.domains_checked += 1;
             match result.status {
                 AvailabilityStatus::Available => {
                     self.available_domains.push(domain.clone());
+                    round.available.push(full_domain);
                 }
-                AvailabilityStatus::Taken => {
-                    self.taken_domains.insert(domain.get_full_domain());
+                AvailabilityStatus::Taken
+                | AvailabilityStatus::Expired
+                | AvailabilityStatus::PendingDelete
+                | AvailabilityStatus::Reserved => {
+                    self.taken_domains.insert(full_domain.clone());
+                    round.taken.push(full_domain);
                 }
                 AvailabilityStatus::Unknown | AvailabilityStatus::Error => {
                     let error_msg = result.error_message.as_deref().unwrap_or("Unknown error");
-                    self.error_domains.push((domain.get_full_domain(), error_msg.to_string()));
+                    self.error_domains.push((full_domain.clone(), error_msg.to_string()));
+                    round.errors.push(full_domain);
                 }
             }
         }
+
+        self.rounds.push(round);
+    }
+
+    /// Totals across all rounds
+    pub fn statistics(&self) -> SessionStatistics {
+        let best_round = self
+            .rounds
+            .iter()
+            .rev()
+            .max_by_key(|round| round.available.len())
+            .map(|round| round.round_number);
+        let avg_available_per_round = if self.round_count == 0 {
+            0.0
+        } else {
+            self.available_domains.len() as f64 / self.round_count as f64
+        };
+
+        SessionStatistics {
+            total_rounds: self.round_count,
+            total_checked: self.total_domains_checked(),
+            total_available: self.available_domains.len(),
+            total_taken: self.taken_domains.len(),
+            best_round,
+            avg_available_per_round,
+            total_api_cost_estimate_usd: self.api_cost_usd,
+        }
     }
     
+    /// Whether `full_domain` was already found taken or available in this session
+    pub fn is_checked(&self, full_domain: &str) -> bool {
+        let full_domain = full_domain.to_lowercase();
+        self.taken_domains.contains(&full_domain)
+            || self.available_domains.iter().any(|d| d.get_full_domain().eq_ignore_ascii_case(&full_domain))
+    }
+
     pub fn get_taken_domain_names(&self) -> Vec<String> {
         self.taken_domains.iter().map(|d| {
             // Extract just the domain name without TLD for AI prompt
@@ -358,13 +1210,179 @@ impl DomainSession {
         }).collect()
     }
     
+    /// Available domains, best [`score`](DomainSuggestion::score) first
+    pub fn ranked_available(&self) -> Vec<&DomainSuggestion> {
+        let mut ranked: Vec<&DomainSuggestion> = self.available_domains.iter().collect();
+        crate::domain::scoring::sort_by_score(&mut ranked);
+        ranked
+    }
+
+    /// Available domains under `tld` (case-insensitive, leading dot optional)
+    pub fn filter_available_by_tld(&self, tld: &str) -> Vec<&DomainSuggestion> {
+        let tld = tld.trim().trim_start_matches('.');
+        self.available_domains
+            .iter()
+            .filter(|domain| domain.tld.eq_ignore_ascii_case(tld))
+            .collect()
+    }
+
+    /// Available domains with a confidence of at least `threshold`
+    pub fn filter_by_min_confidence(&self, threshold: f32) -> Vec<&DomainSuggestion> {
+        self.available_domains
+            .iter()
+            .filter(|domain| domain.confidence >= threshold)
+            .collect()
+    }
+
+    /// Sort available domains in place, highest confidence first
+    pub fn sort_available_by_confidence(&mut self) {
+        self.available_domains
+            .sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    }
+
+    /// Remove and return the `n` best-scoring available domains, best first
+    pub fn take_top_n(&mut self, n: usize) -> Vec<DomainSuggestion> {
+        let mut ranked = std::mem::take(&mut self.available_domains);
+        ranked.sort_by(|a, b| b.score().total_cmp(&a.score()));
+        self.available_domains = ranked.split_off(n.min(ranked.len()));
+        ranked
+    }
+
     pub fn total_domains_checked(&self) -> u32 {
         self.available_domains.len() as u32 + self.taken_domains.len() as u32 + self.error_domains.len() as u32
     }
+
+    /// Markdown report of the session, see [`crate::domain::report`]
+    pub fn export_markdown(&self) -> String {
+        crate::domain::report::session_markdown(self)
+    }
+
+    /// Standalone HTML page rendering [`export_markdown`](Self::export_markdown)
+    pub fn export_html(&self) -> String {
+        crate::domain::report::session_html(self)
+    }
+
+    /// Load a saved session from file
+    pub fn load(path: &std::path::Path) -> crate::error::Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            crate::error::DomainForgeError::io(e.to_string(), Some(path.to_string_lossy().to_string()))
+        })?;
+
+        serde_json::from_str(&content).map_err(|e| {
+            crate::error::DomainForgeError::parse(e.to_string(), Some(content))
+        })
+    }
+
+    /// Save session to file
+    pub fn save(&self, path: &std::path::Path) -> crate::error::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                crate::error::DomainForgeError::io(e.to_string(), Some(parent.to_string_lossy().to_string()))
+            })?;
+        }
+
+        let content = serde_json::to_string_pretty(self).map_err(|e| {
+            crate::error::DomainForgeError::internal(format!("Failed to serialize session: {}", e))
+        })?;
+
+        std::fs::write(path, content).map_err(|e| {
+            crate::error::DomainForgeError::io(e.to_string(), Some(path.to_string_lossy().to_string()))
+        })
+    }
+}
+
+/// Serde helper storing a `Duration` as fractional seconds
+mod duration_secs {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let secs = f64::deserialize(deserializer)?;
+        Duration::try_from_secs_f64(secs).map_err(serde::de::Error::custom)
+    }
 }
 
 impl Default for DomainSession {
     fn default() -> Self {
         Self::new()
     }
+}
+
+/// Machine-readable summary of a generation session (`--json` output)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonOutput {
+    pub description: String,
+    pub generated_at: DateTime<Utc>,
+    pub rounds: u32,
+    pub available: Vec<DomainSuggestion>,
+    pub taken: Vec<String>,
+    pub errors: Vec<DomainError>,
+    /// Per-round breakdown
+    #[serde(default)]
+    pub round_results: Vec<RoundResult>,
+    /// Quality score of each available domain, best first
+    #[serde(default)]
+    pub scores: Vec<DomainScore>,
+}
+
+/// Why an available domain scored as it did
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainScore {
+    pub domain: String,
+    pub breakdown: crate::domain::ScoreBreakdown,
+    /// Shannon entropy of the name, in bits per character
+    pub entropy_bits: f32,
+    /// Structural complexity from 0 to 10
+    pub complexity: u8,
+    /// Reasons the name lost complexity points or looks repetitive
+    pub notes: Vec<String>,
+}
+
+impl DomainScore {
+    pub fn new(suggestion: &DomainSuggestion) -> Self {
+        Self {
+            domain: suggestion.get_full_domain(),
+            breakdown: suggestion.score_breakdown(),
+            entropy_bits: crate::domain::utils::name_entropy(&suggestion.name),
+            complexity: crate::domain::utils::name_complexity(&suggestion.name),
+            notes: crate::domain::utils::complexity_notes(&suggestion.name),
+        }
+    }
+}
+
+/// A domain whose availability could not be determined
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainError {
+    pub domain: String,
+    pub message: String,
+}
+
+impl JsonOutput {
+    /// Build the JSON summary from a session
+    pub fn from_session(session: &DomainSession, description: &str) -> Self {
+        let mut taken: Vec<String> = session.taken_domains.iter().cloned().collect();
+        taken.sort();
+
+        Self {
+            description: description.to_string(),
+            generated_at: Utc::now(),
+            rounds: session.round_count,
+            available: session.available_domains.clone(),
+            taken,
+            errors: session
+                .error_domains
+                .iter()
+                .map(|(domain, message)| DomainError {
+                    domain: domain.clone(),
+                    message: message.clone(),
+                })
+                .collect(),
+            round_results: session.rounds.clone(),
+            scores: session.ranked_available().into_iter().map(DomainScore::new).collect(),
+        }
+    }
 }
\ No newline at end of file
@@ -11,20 +11,20 @@ use std::time::Duration;
 
 #[tokio::test]
 async fn test_domain_checker_creation() {
-    let _checker = DomainChecker::new();
     // Should create successfully with default config
-    assert!(true);
+    let _checker = DomainChecker::new();
 }
 
 #[tokio::test]
 async fn test_domain_checker_with_config() {
-    let mut config = CheckConfig::default();
-    config.timeout = Duration::from_secs(5);
-    config.concurrent_checks = 2;
+    let config = CheckConfig {
+        timeout: Duration::from_secs(5),
+        concurrent_checks: 2,
+        ..Default::default()
+    };
 
-    let _checker = DomainChecker::with_config(config);
     // Should create successfully with custom config
-    assert!(true);
+    let _checker = DomainChecker::with_config(config);
 }
 
 #[tokio::test]
@@ -53,7 +53,7 @@ async fn test_batch_domain_checking() {
         "example.com".to_string(),
     ];
 
-    match checker.check_domains(&domains).await {
+    match checker.check_domains(&domains, None).await {
         Ok(results) => {
             assert_eq!(results.len(), 2);
             // All results should have the correct domain names
@@ -70,9 +70,8 @@ async fn test_batch_domain_checking() {
 
 #[tokio::test]
 async fn test_llm_generator_creation() {
-    let _generator = DomainGenerator::new();
     // Should create successfully
-    assert!(true);
+    let _generator = DomainGenerator::new();
 }
 
 #[tokio::test]
@@ -83,6 +82,8 @@ async fn test_llm_config_creation() {
         api_key: "test-key".to_string(),
         base_url: None,
         temperature: 0.7,
+        use_structured_output: true,
+        max_tokens: None,
     };
 
     assert_eq!(config.provider, "openai");
@@ -100,6 +101,18 @@ async fn test_generation_config_creation() {
         temperature: 0.7,
         description: "Test app".to_string(),
         avoid_names: Vec::new(),
+        system_prompt_template: None,
+        user_prompt_suffix: None,
+        parse_retry_attempts: 2,
+        min_name_length: None,
+        max_name_length: Some(8),
+        target_phonetics: None,
+        content_filter: None,
+        lenient_parsing: true,
+        examples: Vec::new(),
+        negative_examples: Vec::new(),
+        use_llm_scoring: false,
+        seed: None,
     };
 
     assert_eq!(config.count, 5);
@@ -119,6 +132,26 @@ fn test_generation_style_enum() {
     assert_eq!(format!("{:?}", GenerationStyle::Creative), "Creative");
     assert_eq!(format!("{:?}", GenerationStyle::Professional), "Professional");
     assert_eq!(format!("{:?}", GenerationStyle::Brandable), "Brandable");
+    assert_eq!(format!("{:?}", GenerationStyle::Acronym), "Acronym");
+}
+
+#[test]
+fn test_generation_style_serde_round_trip() {
+    let styles = [
+        GenerationStyle::Creative,
+        GenerationStyle::Professional,
+        GenerationStyle::Brandable,
+        GenerationStyle::Descriptive,
+        GenerationStyle::Short,
+        GenerationStyle::Tech,
+        GenerationStyle::Acronym,
+        GenerationStyle::Portmanteau,
+    ];
+    for style in styles {
+        let json = serde_json::to_string(&style).unwrap();
+        assert_eq!(json, format!("\"{}\"", style));
+        assert_eq!(serde_json::from_str::<GenerationStyle>(&json).unwrap(), style);
+    }
 }
 
 #[test]
@@ -147,4 +180,412 @@ fn test_library_initialization() {
     // Test that the library can be initialized without panicking
     let result = domain_forge::init();
     assert!(result.is_ok());
-}
\ No newline at end of file
+}
+
+/// RDAP result for `domain`, checked now
+fn result(domain: &str, status: AvailabilityStatus) -> domain_forge::types::DomainResult {
+    domain_forge::types::DomainResult::new(domain, status, domain_forge::types::CheckMethod::Rdap)
+}
+
+#[test]
+fn test_json_output_round_trip() {
+    use domain_forge::types::{DomainResult, DomainSession, DomainSuggestion, JsonOutput};
+
+    let domains = vec![
+        DomainSuggestion::new("forgely", "com", 0.9, Some("short and brandable")),
+        DomainSuggestion::new("google", "com", 0.5, None::<String>),
+        DomainSuggestion::new("flaky", "io", 0.4, None::<String>),
+    ];
+    let results = vec![
+        result("forgely.com", AvailabilityStatus::Available),
+        result("google.com", AvailabilityStatus::Taken),
+        DomainResult { error_message: Some("timeout".to_string()), ..result("flaky.io", AvailabilityStatus::Unknown) },
+    ];
+
+    let mut session = DomainSession::new();
+    session.add_round_results(&domains, &results, Duration::from_secs(1));
+    let output = JsonOutput::from_session(&session, "forge tools");
+
+    let json = serde_json::to_string_pretty(&output).unwrap();
+    let parsed: JsonOutput = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string_pretty(&parsed).unwrap(), json);
+
+    assert_eq!(parsed.rounds, 1);
+    assert_eq!(parsed.available[0].get_full_domain(), "forgely.com");
+    assert_eq!(parsed.taken, vec!["google.com"]);
+    assert_eq!(parsed.errors[0].message, "timeout");
+    assert_eq!(parsed.round_results.len(), 1);
+    assert_eq!(parsed.round_results[0].available, vec!["forgely.com"]);
+    assert_eq!(parsed.round_results[0].errors, vec!["flaky.io"]);
+    assert_eq!(parsed.scores.len(), 1);
+    assert_eq!(parsed.scores[0].domain, "forgely.com");
+    assert_eq!(parsed.scores[0].breakdown.total, domains[0].score());
+    assert!(parsed.scores[0].notes.iter().any(|note| note.contains("'forge'")));
+}
+
+#[test]
+fn test_combine_rdap_and_whois() {
+    use chrono::{TimeZone, Utc};
+    use domain_forge::types::{CheckMethod, DomainResult};
+
+    let expires = Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap();
+    let rdap = DomainResult {
+        check_duration: Some(Duration::from_millis(300)),
+        expiration_date: Some(expires),
+        ..DomainResult::new("forge.com", AvailabilityStatus::Taken, CheckMethod::Rdap)
+    };
+    let whois = DomainResult {
+        status: AvailabilityStatus::Available,
+        method: CheckMethod::Whois,
+        check_duration: Some(Duration::from_millis(200)),
+        registrar: Some("Example Registrar, Inc.".to_string()),
+        expiration_date: Some(Utc.with_ymd_and_hms(2029, 1, 1, 0, 0, 0).unwrap()),
+        nameservers: vec!["ns1.example.net".to_string()],
+        ..rdap.clone()
+    };
+
+    let combined = DomainResult::combine(&rdap, &whois);
+    assert_eq!(combined.method, CheckMethod::Combined);
+    assert_eq!(combined.status, AvailabilityStatus::Taken);
+    assert_eq!(combined.expiration_date, Some(expires));
+    assert_eq!(combined.registrar.as_deref(), Some("Example Registrar, Inc."));
+    assert_eq!(combined.nameservers, vec!["ns1.example.net"]);
+    assert_eq!(combined.check_duration, Some(Duration::from_millis(500)));
+}
+
+#[test]
+fn test_session_save_and_resume() {
+    use domain_forge::types::{DomainSession, DomainSuggestion};
+
+    let domains = vec![
+        DomainSuggestion::new("forgely", "com", 0.9, None::<String>),
+        DomainSuggestion::new("google", "com", 0.5, None::<String>),
+    ];
+    let results = vec![
+        result("forgely.com", AvailabilityStatus::Available),
+        result("google.com", AvailabilityStatus::Taken),
+    ];
+
+    let mut session = DomainSession::new();
+    session.add_round_results(&domains, &results, Duration::from_millis(1500));
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("output").join("session.json");
+    session.save(&path).unwrap();
+
+    let json = std::fs::read_to_string(&path).unwrap();
+    assert!(json.contains("\"total_time\": 1.5"));
+
+    let restored = DomainSession::load(&path).unwrap();
+    assert_eq!(restored.round_count, 1);
+    assert_eq!(restored.total_time, Duration::from_millis(1500));
+    assert_eq!(restored.taken_domains, session.taken_domains);
+    assert_eq!(restored.available_domains[0].get_full_domain(), "forgely.com");
+    assert_eq!(restored.get_taken_domain_names(), vec!["google"]);
+    assert!(restored.is_checked("Google.com"));
+    assert!(restored.is_checked("forgely.com"));
+    assert!(!restored.is_checked("google.io"));
+}
+
+#[test]
+fn test_session_statistics() {
+    use domain_forge::types::{DomainSession, DomainSuggestion};
+
+
+    let mut session = DomainSession::new();
+    assert_eq!(session.statistics().best_round, None);
+
+    session.add_round_results(
+        &[DomainSuggestion::new("google", "com", 0.5, None::<String>)],
+        &[result("google.com", AvailabilityStatus::Taken)],
+        Duration::from_secs(1),
+    );
+    session.add_round_results(
+        &[
+            DomainSuggestion::new("forgely", "com", 0.9, None::<String>),
+            DomainSuggestion::new("anvilo", "io", 0.8, None::<String>),
+            DomainSuggestion::new("flaky", "io", 0.4, None::<String>),
+        ],
+        &[
+            result("forgely.com", AvailabilityStatus::Available),
+            result("anvilo.io", AvailabilityStatus::Available),
+            result("flaky.io", AvailabilityStatus::Unknown),
+        ],
+        Duration::from_secs(2),
+    );
+    session.api_cost_usd = 0.0125;
+
+    assert_eq!(session.rounds.len(), 2);
+    assert_eq!(session.rounds[1].round_number, 2);
+    assert_eq!(session.rounds[1].domains_checked, 3);
+    assert_eq!(session.rounds[1].errors, vec!["flaky.io"]);
+
+    let stats = session.statistics();
+    assert_eq!(stats.total_rounds, 2);
+    assert_eq!(stats.total_checked, 4);
+    assert_eq!(stats.total_available, 2);
+    assert_eq!(stats.total_taken, 1);
+    assert_eq!(stats.best_round, Some(2));
+    assert_eq!(stats.avg_available_per_round, 1.0);
+    assert_eq!(stats.total_api_cost_estimate_usd, 0.0125);
+}
+
+#[test]
+fn test_metrics_snapshot_display() {
+    use domain_forge::types::PerformanceMetrics;
+
+    // Nothing checked yet: no division by zero
+    let empty = PerformanceMetrics::new().get_stats();
+    assert_eq!(empty.error_rate(), 0.0);
+    assert_eq!(empty.domains_per_second(Duration::ZERO), 0.0);
+    assert_eq!(
+        empty.to_string(),
+        "Domains checked: 0 | Errors: 0 (0.00%) | Avg check time: 0ms | API calls: 0 | Tokens used: 0"
+    );
+
+    let metrics = PerformanceMetrics::new();
+    for _ in 0..1234 {
+        metrics.increment_domains_checked();
+    }
+    metrics.add_check_time(1234 * 234);
+    for _ in 0..5 {
+        metrics.increment_api_calls();
+    }
+    metrics.add_prompt_tokens(12_000);
+    metrics.add_completion_tokens(450);
+
+    // Checks but no errors
+    let stats = metrics.get_stats();
+    assert_eq!(stats.error_rate(), 0.0);
+    assert!(stats.to_string().contains("Errors: 0 (0.00%)"));
+
+    for _ in 0..12 {
+        metrics.increment_errors();
+    }
+    let stats = metrics.get_stats();
+    assert_eq!(
+        stats.to_string(),
+        "Domains checked: 1,234 | Errors: 12 (0.97%) | Avg check time: 234ms | API calls: 5 | Tokens used: 12,450"
+    );
+    assert!((stats.domains_per_second(Duration::from_secs(2)) - 617.0).abs() < 1e-9);
+
+    let table = stats.table(Duration::from_secs(2));
+    assert!(table.starts_with("Metrics\n"));
+    assert!(table.contains("Throughput:        617.0 domains/s"));
+    assert_eq!(stats.combined(&empty).domains_checked, 1234);
+}
+
+#[test]
+fn test_domain_result_expiry_helpers() {
+    use chrono::{Duration as ChronoDuration, Utc};
+    use domain_forge::types::DomainResult;
+
+    let mut result = DomainResult::error("forge.com", "not checked");
+    assert_eq!(result.days_until_expiry(), None);
+    assert_eq!(result.registration_age_days(), None);
+    assert!(!result.is_expiring_within(30));
+
+    let now = Utc::now();
+    result.expiration_date = Some(now + ChronoDuration::days(10) + ChronoDuration::hours(1));
+    result.creation_date = Some(now - ChronoDuration::days(400) - ChronoDuration::hours(1));
+    assert_eq!(result.days_until_expiry(), Some(10));
+    assert_eq!(result.registration_age_days(), Some(400));
+    assert!(result.is_expiring_within(10));
+    assert!(!result.is_expiring_within(9));
+
+    // Already expired is not "expiring"
+    result.expiration_date = Some(now - ChronoDuration::days(2));
+    assert_eq!(result.days_until_expiry(), Some(-2));
+    assert!(!result.is_expiring_within(30));
+}
+
+/// Session with ten available domains across four TLDs
+fn filter_session() -> domain_forge::types::DomainSession {
+    use domain_forge::types::{DomainSession, DomainSuggestion};
+
+    let mut session = DomainSession::new();
+    session.available_domains = [
+        ("forgely", "com", 0.9),
+        ("anvilo", "io", 0.8),
+        ("smithy", "ai", 0.4),
+        ("hammr", "com", 0.6),
+        ("tongsly", "xyz", 0.3),
+        ("bellows", "io", 0.7),
+        ("quenchforgeworks", "com", 0.95),
+        ("ingot", "ai", 0.85),
+        ("kilnly", "io", 0.5),
+        ("slagger", "xyz", 0.2),
+    ]
+    .into_iter()
+    .map(|(name, tld, confidence)| DomainSuggestion::new(name, tld, confidence, None::<String>))
+    .collect();
+    session
+}
+
+#[test]
+fn test_session_filter_by_tld() {
+    let session = filter_session();
+    let io: Vec<String> = session.filter_available_by_tld("io").iter().map(|d| d.get_full_domain()).collect();
+    assert_eq!(io, vec!["anvilo.io", "bellows.io", "kilnly.io"]);
+    assert_eq!(session.filter_available_by_tld(".COM").len(), 3);
+    assert!(session.filter_available_by_tld("net").is_empty());
+}
+
+#[test]
+fn test_session_filter_by_min_confidence() {
+    let session = filter_session();
+    let confident = session.filter_by_min_confidence(0.8);
+    assert_eq!(confident.len(), 4);
+    assert!(confident.iter().all(|d| d.confidence >= 0.8));
+    assert_eq!(session.filter_by_min_confidence(0.0).len(), 10);
+    assert!(session.filter_by_min_confidence(1.0).is_empty());
+}
+
+#[test]
+fn test_session_sort_by_confidence() {
+    let mut session = filter_session();
+    session.sort_available_by_confidence();
+    assert_eq!(session.available_domains.len(), 10);
+    assert_eq!(session.available_domains[0].name, "quenchforgeworks");
+    assert_eq!(session.available_domains[9].name, "slagger");
+    assert!(session.available_domains.windows(2).all(|w| w[0].confidence >= w[1].confidence));
+}
+
+#[test]
+fn test_session_take_top_n() {
+    let mut session = filter_session();
+    let expected: Vec<String> = session.ranked_available().iter().take(3).map(|d| d.get_full_domain()).collect();
+
+    let top = session.take_top_n(3);
+    assert_eq!(top.iter().map(|d| d.get_full_domain()).collect::<Vec<_>>(), expected);
+    assert_eq!(session.available_domains.len(), 7);
+    let remaining: Vec<String> = session.available_domains.iter().map(|d| d.get_full_domain()).collect();
+    assert!(expected.iter().all(|domain| !remaining.contains(domain)));
+
+    assert_eq!(session.take_top_n(20).len(), 7);
+    assert!(session.available_domains.is_empty());
+}
+
+#[test]
+fn test_scan_state_json_round_trip() {
+    use domain_forge::snipe::ScanState;
+
+    let mut state = ScanState::new(4, vec!["com".to_string()], 456_976);
+    state.checked_count = 42;
+    state.completed = true;
+
+    let json = serde_json::to_string_pretty(&state).unwrap();
+    let parsed: ScanState = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string_pretty(&parsed).unwrap(), json);
+}
+
+#[test]
+fn test_readable_scan_mode_wiring() {
+    use domain_forge::snipe::{DomainSniper, ReadableGenerator, ScanMode, SnipeConfig};
+
+    let config = SnipeConfig {
+        mode: ScanMode::Readable,
+        tlds: vec!["com".to_string(), "io".to_string()],
+        ..Default::default()
+    };
+    let sniper = DomainSniper::new(config).unwrap();
+
+    let expected = ReadableGenerator::new().total_count() as u64 * 2;
+    assert_eq!(sniper.state().total_combinations, expected);
+    assert_eq!(sniper.state().length, 5);
+}
+
+#[test]
+fn test_config_builders() {
+    use domain_forge::snipe::{ScanMode, SnipeConfig};
+
+    let config = GenerationConfig::builder()
+        .description("AI startup")
+        .count(20)
+        .tlds(["com", "io"])
+        .style(GenerationStyle::Tech)
+        .build()
+        .unwrap();
+    assert_eq!(config.description, "AI startup");
+    assert_eq!(config.count, 20);
+    assert_eq!(config.tlds, vec!["com", "io"]);
+    assert_eq!(config.style, GenerationStyle::Tech);
+    assert!(GenerationConfig::builder().count(5).build().is_err());
+
+    let auto = GenerationConfig::builder().description("nonprofit for shelters").build().unwrap();
+    assert_eq!(auto.tlds, vec!["org", "com"]);
+    let auto = GenerationConfig::builder().description("cloud API").tlds(["auto"]).build().unwrap();
+    assert_eq!(auto.tlds, vec!["com", "io", "dev", "app"]);
+
+    let check = CheckConfig::builder()
+        .concurrent_checks(4)
+        .timeout(Duration::from_secs(5))
+        .enable_whois(false)
+        .build()
+        .unwrap();
+    assert_eq!(check.concurrent_checks, 4);
+    assert!(!check.enable_whois);
+    assert!(CheckConfig::builder().enable_rdap(false).enable_whois(false).build().is_err());
+    assert!(CheckConfig::builder().proxy("http://proxy.corp.example.com:8080").build().is_ok());
+    assert!(CheckConfig::builder().proxy("not a url").build().is_err());
+    assert_eq!(check.rdap_user_agent(), format!("domain-forge/{}", domain_forge::VERSION));
+    let private = CheckConfig::builder()
+        .user_agent("acme-monitor/2.0 (ops@example.com)")
+        .extra_header("Authorization", "Bearer token")
+        .build()
+        .unwrap();
+    assert_eq!(private.rdap_user_agent(), "acme-monitor/2.0 (ops@example.com)");
+    assert!(CheckConfig::builder().extra_header("Bad Header", "x").build().is_err());
+
+    let snipe = SnipeConfig::builder()
+        .mode(ScanMode::Words)
+        .tlds(["ai"])
+        .concurrency(5)
+        .build()
+        .unwrap();
+    assert_eq!(snipe.mode, ScanMode::Words);
+    assert_eq!(snipe.tlds, vec!["ai"]);
+    assert!(SnipeConfig::builder().tlds(Vec::<String>::new()).build().is_err());
+    assert!(SnipeConfig::builder().user_agent("acme-monitor/2.0").build().is_ok());
+    assert!(SnipeConfig::builder().user_agent("bad\nagent").build().is_err());
+}
+
+#[test]
+fn test_openrouter_provider() {
+    let config = LlmConfig {
+        provider: "openrouter".to_string(),
+        model: "anthropic/claude-sonnet-4".to_string(),
+        api_key: "test-key".to_string(),
+        base_url: None,
+        temperature: 0.7,
+        use_structured_output: true,
+        max_tokens: None,
+    };
+    let provider = domain_forge::llm::create_provider(&config).unwrap();
+    assert_eq!(provider.name(), "openrouter");
+    assert_eq!(provider.model(), "anthropic/claude-sonnet-4");
+    assert!(provider.is_ready());
+    assert!(domain_forge::llm::available_providers().contains(&"openrouter"));
+
+    let missing_key = LlmConfig { api_key: String::new(), ..config };
+    assert!(domain_forge::llm::create_provider(&missing_key).is_err());
+}
+
+#[test]
+fn test_generic_provider() {
+    let config = LlmConfig {
+        provider: "generic".to_string(),
+        model: "local-model".to_string(),
+        api_key: String::new(),
+        base_url: Some("http://localhost:1234/v1".to_string()),
+        temperature: 0.7,
+        use_structured_output: true,
+        max_tokens: None,
+    };
+    let provider = domain_forge::llm::create_provider(&config).unwrap();
+    assert_eq!(provider.name(), "generic");
+    assert!(provider.is_ready());
+
+    let missing_url = LlmConfig { base_url: None, ..config };
+    assert!(domain_forge::llm::create_provider(&missing_url).is_err());
+}